@@ -6,40 +6,213 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Decimal, Cw20ReceiveMsg, QueryMsg, MigrateMsg, Addr, ArrayOfBalance, Balance, Config, ArrayOfTupleOfAddrAndUint128, ArrayOfTupleOfAddrAndDecimal, Timestamp, Uint64, ArrayOfTupleOfAddrAndArrayOfAsset, Asset, ArrayOfTupleOfAddrAndToken, Token, Sample } from "./IonFlux.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Decimal, Addr, Timestamp, Uint64, RewardToken, Cw20ReceiveMsg, CircuitBreaker, DynamicFee, RateLimit, TokenCaps, FeeDiscount, LockBoost, Hook, RewardAsset, Referral, SwapLeg, QueryMsg, MigrateMsg, Boolean, PageForBalanceAndAddr, Balance, Config, PageForTupleOfUint64AndDcaScheduleAndUint64, DcaSchedule, ArrayOfFeeDiscount, Fees, PageForAddrAndAddr, ArrayOfTupleOfTimestampAndSnapshot, Snapshot, PageForTupleOfAddrAndHookAndAddr, PageForTupleOfUint64AndRewardAssetAndUint64, PageForTupleOfUint64AndIncentiveAndUint64, Incentive, PageForTupleOfUint64AndLimitOrderAndUint64, LimitOrder, PageForTupleOfAddrAndUint128AndAddr, ArrayOfLockBoost, ArrayOfAddr, PoolStats, TokenStats, PageForTupleOfAddrAndDecimalAndAddr, ArrayOfTupleOfAddrAndDecimal, ArrayOfAsset, Asset, Lock, ProviderApr, ProviderSummary, AssetSummary, PageForTupleOfAddrAndArrayOfAssetAndAddr, PageForSwapAllowanceAndAddr, SwapAllowance, Token, Sample, PageForTupleOfAddrAndTokenCapsAndAddr, PageForTupleOfAddrAndTokenAndAddr } from "./IonFlux.types";
 export interface IonFluxReadOnlyInterface {
   contractAddress: string;
   queryConfig: () => Promise<Config>;
   queryTokensWeight: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfTupleOfAddrAndDecimal>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndDecimalAndAddr>;
+  queryTokenApr: () => Promise<ArrayOfTupleOfAddrAndDecimal>;
+  queryProviderApr: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ProviderApr>;
+  queryPoolStats: () => Promise<PoolStats>;
+  queryHistory: ({
+    from,
+    to,
+    tokenAddr
+  }: {
+    from: Timestamp;
+    to: Timestamp;
+    tokenAddr: string;
+  }) => Promise<ArrayOfTupleOfTimestampAndSnapshot>;
   queryLiquidity: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfTupleOfAddrAndUint128>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndUint128AndAddr>;
   queryProviders: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfTupleOfAddrAndArrayOfAsset>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndArrayOfAssetAndAddr>;
+  queryProvider: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ArrayOfAsset>;
+  queryProviderSummary: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ProviderSummary>;
+  queryOperators: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ArrayOfAddr>;
+  queryAutoCompound: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<Boolean>;
   queryTokens: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfTupleOfAddrAndToken>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndTokenAndAddr>;
+  queryToken: ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }) => Promise<Token>;
   queryBalances: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
+  }: {
+    addressList: string[];
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForBalanceAndAddr>;
+  queryFees: () => Promise<Fees>;
+  querySwapFeeRate: ({
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }) => Promise<Decimal>;
+  querySwapAllowances: ({
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfBalance>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForSwapAllowanceAndAddr>;
+  queryHaltedTokens: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForAddrAndAddr>;
+  queryPriceAverages: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndDecimalAndAddr>;
+  queryTokenCaps: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndTokenCapsAndAddr>;
+  queryFeeDiscounts: () => Promise<ArrayOfFeeDiscount>;
+  queryLockBoosts: () => Promise<ArrayOfLockBoost>;
+  queryIncentives: ({
+    limit,
+    startAfter,
+    tokenAddrTarget
+  }: {
+    limit?: number;
+    startAfter?: number;
+    tokenAddrTarget: string;
+  }) => Promise<PageForTupleOfUint64AndIncentiveAndUint64>;
+  queryIncentiveRewards: ({
+    address,
+    limit,
+    startAfter,
+    tokenAddrTarget
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: number;
+    tokenAddrTarget: string;
+  }) => Promise<PageForTupleOfUint64AndRewardAssetAndUint64>;
+  queryLimitOrdersByOwner: ({
+    limit,
+    owner,
+    startAfter
+  }: {
+    limit?: number;
+    owner: string;
+    startAfter?: number;
+  }) => Promise<PageForTupleOfUint64AndLimitOrderAndUint64>;
+  queryLimitOrdersByPair: ({
+    limit,
+    startAfter,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    limit?: number;
+    startAfter?: number;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }) => Promise<PageForTupleOfUint64AndLimitOrderAndUint64>;
+  queryDcaSchedules: ({
+    limit,
+    owner,
+    startAfter
+  }: {
+    limit?: number;
+    owner?: string;
+    startAfter?: number;
+  }) => Promise<PageForTupleOfUint64AndDcaScheduleAndUint64>;
+  queryHooks: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndHookAndAddr>;
+  queryFeeMultiplier: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<Decimal>;
+  queryProtocolFees: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndUint128AndAddr>;
   queryPrices: ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }) => Promise<ArrayOfTupleOfAddrAndDecimal>;
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<PageForTupleOfAddrAndDecimalAndAddr>;
   queryPricesMocked: ({
     addressList
   }: {
@@ -55,10 +228,35 @@ export class IonFluxQueryClient implements IonFluxReadOnlyInterface {
     this.contractAddress = contractAddress;
     this.queryConfig = this.queryConfig.bind(this);
     this.queryTokensWeight = this.queryTokensWeight.bind(this);
+    this.queryTokenApr = this.queryTokenApr.bind(this);
+    this.queryProviderApr = this.queryProviderApr.bind(this);
+    this.queryPoolStats = this.queryPoolStats.bind(this);
+    this.queryHistory = this.queryHistory.bind(this);
     this.queryLiquidity = this.queryLiquidity.bind(this);
     this.queryProviders = this.queryProviders.bind(this);
+    this.queryProvider = this.queryProvider.bind(this);
+    this.queryProviderSummary = this.queryProviderSummary.bind(this);
+    this.queryOperators = this.queryOperators.bind(this);
+    this.queryAutoCompound = this.queryAutoCompound.bind(this);
     this.queryTokens = this.queryTokens.bind(this);
+    this.queryToken = this.queryToken.bind(this);
     this.queryBalances = this.queryBalances.bind(this);
+    this.queryFees = this.queryFees.bind(this);
+    this.querySwapFeeRate = this.querySwapFeeRate.bind(this);
+    this.querySwapAllowances = this.querySwapAllowances.bind(this);
+    this.queryHaltedTokens = this.queryHaltedTokens.bind(this);
+    this.queryPriceAverages = this.queryPriceAverages.bind(this);
+    this.queryTokenCaps = this.queryTokenCaps.bind(this);
+    this.queryFeeDiscounts = this.queryFeeDiscounts.bind(this);
+    this.queryLockBoosts = this.queryLockBoosts.bind(this);
+    this.queryIncentives = this.queryIncentives.bind(this);
+    this.queryIncentiveRewards = this.queryIncentiveRewards.bind(this);
+    this.queryLimitOrdersByOwner = this.queryLimitOrdersByOwner.bind(this);
+    this.queryLimitOrdersByPair = this.queryLimitOrdersByPair.bind(this);
+    this.queryDcaSchedules = this.queryDcaSchedules.bind(this);
+    this.queryHooks = this.queryHooks.bind(this);
+    this.queryFeeMultiplier = this.queryFeeMultiplier.bind(this);
+    this.queryProtocolFees = this.queryProtocolFees.bind(this);
     this.queryPrices = this.queryPrices.bind(this);
     this.queryPricesMocked = this.queryPricesMocked.bind(this);
   }
@@ -69,68 +267,415 @@ export class IonFluxQueryClient implements IonFluxReadOnlyInterface {
     });
   };
   queryTokensWeight = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfTupleOfAddrAndDecimal> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndDecimalAndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_tokens_weight: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryTokenApr = async (): Promise<ArrayOfTupleOfAddrAndDecimal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_token_apr: {}
+    });
+  };
+  queryProviderApr = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ProviderApr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_provider_apr: {
+        address
+      }
+    });
+  };
+  queryPoolStats = async (): Promise<PoolStats> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_pool_stats: {}
+    });
+  };
+  queryHistory = async ({
+    from,
+    to,
+    tokenAddr
+  }: {
+    from: Timestamp;
+    to: Timestamp;
+    tokenAddr: string;
+  }): Promise<ArrayOfTupleOfTimestampAndSnapshot> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_history: {
+        from,
+        to,
+        token_addr: tokenAddr
       }
     });
   };
   queryLiquidity = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfTupleOfAddrAndUint128> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndUint128AndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_liquidity: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
       }
     });
   };
   queryProviders = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfTupleOfAddrAndArrayOfAsset> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndArrayOfAssetAndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_providers: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryProvider = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ArrayOfAsset> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_provider: {
+        address
+      }
+    });
+  };
+  queryProviderSummary = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ProviderSummary> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_provider_summary: {
+        address
+      }
+    });
+  };
+  queryOperators = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ArrayOfAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_operators: {
+        address
+      }
+    });
+  };
+  queryAutoCompound = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<Boolean> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_auto_compound: {
+        address
       }
     });
   };
   queryTokens = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfTupleOfAddrAndToken> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndTokenAndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_tokens: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryToken = async ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }): Promise<Token> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_token: {
+        token_addr: tokenAddr
       }
     });
   };
   queryBalances = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfBalance> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForBalanceAndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_balances: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryFees = async (): Promise<Fees> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_fees: {}
+    });
+  };
+  querySwapFeeRate = async ({
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }): Promise<Decimal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_swap_fee_rate: {
+        token_in_addr: tokenInAddr,
+        token_out_addr: tokenOutAddr
+      }
+    });
+  };
+  querySwapAllowances = async ({
+    addressList,
+    limit,
+    startAfter
+  }: {
+    addressList: string[];
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForSwapAllowanceAndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_swap_allowances: {
+        address_list: addressList,
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryHaltedTokens = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForAddrAndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_halted_tokens: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryPriceAverages = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndDecimalAndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_price_averages: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryTokenCaps = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndTokenCapsAndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_token_caps: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryFeeDiscounts = async (): Promise<ArrayOfFeeDiscount> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_fee_discounts: {}
+    });
+  };
+  queryLockBoosts = async (): Promise<ArrayOfLockBoost> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_lock_boosts: {}
+    });
+  };
+  queryIncentives = async ({
+    limit,
+    startAfter,
+    tokenAddrTarget
+  }: {
+    limit?: number;
+    startAfter?: number;
+    tokenAddrTarget: string;
+  }): Promise<PageForTupleOfUint64AndIncentiveAndUint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_incentives: {
+        limit,
+        start_after: startAfter,
+        token_addr_target: tokenAddrTarget
+      }
+    });
+  };
+  queryIncentiveRewards = async ({
+    address,
+    limit,
+    startAfter,
+    tokenAddrTarget
+  }: {
+    address: string;
+    limit?: number;
+    startAfter?: number;
+    tokenAddrTarget: string;
+  }): Promise<PageForTupleOfUint64AndRewardAssetAndUint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_incentive_rewards: {
+        address,
+        limit,
+        start_after: startAfter,
+        token_addr_target: tokenAddrTarget
+      }
+    });
+  };
+  queryLimitOrdersByOwner = async ({
+    limit,
+    owner,
+    startAfter
+  }: {
+    limit?: number;
+    owner: string;
+    startAfter?: number;
+  }): Promise<PageForTupleOfUint64AndLimitOrderAndUint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_limit_orders_by_owner: {
+        limit,
+        owner,
+        start_after: startAfter
+      }
+    });
+  };
+  queryLimitOrdersByPair = async ({
+    limit,
+    startAfter,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    limit?: number;
+    startAfter?: number;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }): Promise<PageForTupleOfUint64AndLimitOrderAndUint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_limit_orders_by_pair: {
+        limit,
+        start_after: startAfter,
+        token_in_addr: tokenInAddr,
+        token_out_addr: tokenOutAddr
+      }
+    });
+  };
+  queryDcaSchedules = async ({
+    limit,
+    owner,
+    startAfter
+  }: {
+    limit?: number;
+    owner?: string;
+    startAfter?: number;
+  }): Promise<PageForTupleOfUint64AndDcaScheduleAndUint64> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_dca_schedules: {
+        limit,
+        owner,
+        start_after: startAfter
+      }
+    });
+  };
+  queryHooks = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndHookAndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_hooks: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  queryFeeMultiplier = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<Decimal> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_fee_multiplier: {
+        address
+      }
+    });
+  };
+  queryProtocolFees = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndUint128AndAddr> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      query_protocol_fees: {
+        limit,
+        start_after: startAfter
       }
     });
   };
   queryPrices = async ({
-    addressList
+    addressList,
+    limit,
+    startAfter
   }: {
     addressList: string[];
-  }): Promise<ArrayOfTupleOfAddrAndDecimal> => {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<PageForTupleOfAddrAndDecimalAndAddr> => {
     return this.client.queryContractSmart(this.contractAddress, {
       query_prices: {
-        address_list: addressList
+        address_list: addressList,
+        limit,
+        start_after: startAfter
       }
     });
   };
@@ -160,14 +705,30 @@ export interface IonFluxInterface extends IonFluxReadOnlyInterface {
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   updateConfig: ({
     admin,
+    circuitBreaker,
+    dynamicFee,
+    epoch,
+    keeperTipRate,
+    maxReferralCommissionRate,
     priceAge,
+    protocolFeeShare,
+    rateLimit,
     swapFeeRate,
+    treasury,
     unbondingPeriod,
     window
   }: {
     admin?: string;
+    circuitBreaker?: CircuitBreaker;
+    dynamicFee?: DynamicFee;
+    epoch?: Uint128;
+    keeperTipRate?: Decimal;
+    maxReferralCommissionRate?: Decimal;
     priceAge?: Uint128;
+    protocolFeeShare?: Decimal;
+    rateLimit?: RateLimit;
     swapFeeRate?: Decimal;
+    treasury?: string;
     unbondingPeriod?: Uint128;
     window?: Uint128;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
@@ -180,11 +741,58 @@ export interface IonFluxInterface extends IonFluxReadOnlyInterface {
     symbol: string;
     tokenAddr: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateTokenFee: ({
+    swapFeeRate,
+    tokenAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenAddr: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateTokenCaps: ({
+    tokenAddr,
+    tokenCaps
+  }: {
+    tokenAddr: string;
+    tokenCaps?: TokenCaps;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updatePairFee: ({
+    swapFeeRate,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  resetHalt: ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateFeeDiscounts: ({
+    feeDiscountList
+  }: {
+    feeDiscountList: FeeDiscount[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateLockBoosts: ({
+    lockBoostList
+  }: {
+    lockBoostList: LockBoost[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  updateHook: ({
+    contractAddr,
+    hook
+  }: {
+    contractAddr: string;
+    hook?: Hook;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   unbond: ({
     amount,
+    providerAddr,
     tokenAddr
   }: {
     amount: Uint128;
+    providerAddr?: string;
     tokenAddr: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   withdraw: ({
@@ -194,12 +802,99 @@ export interface IonFluxInterface extends IonFluxReadOnlyInterface {
     amount: Uint128;
     tokenAddr: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  claim: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  createIncentive: ({
+    end,
+    rewardAsset,
+    start,
+    tokenAddrTarget
+  }: {
+    end: Timestamp;
+    rewardAsset: RewardAsset;
+    start: Timestamp;
+    tokenAddrTarget: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  closeIncentive: ({
+    id,
+    tokenAddrTarget
+  }: {
+    id: number;
+    tokenAddrTarget: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelOrder: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  executeOrders: ({
+    idList
+  }: {
+    idList: number[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelDca: ({
+    id
+  }: {
+    id: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  executeDca: ({
+    idList
+  }: {
+    idList: number[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  claim: ({
+    providerAddr,
+    recipient,
+    tokenAddrList
+  }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  batchSwap: ({
+    referral,
+    swapList
+  }: {
+    referral?: Referral;
+    swapList: SwapLeg[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   swapAndClaim: ({
+    providerAddr,
+    recipient,
+    tokenAddrList,
     tokenOutAddr
   }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
     tokenOutAddr: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  compound: ({
+    providerAddr,
+    tokenOutAddr
+  }: {
+    providerAddr?: string;
+    tokenOutAddr?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setAutoCompound: ({
+    isEnabled
+  }: {
+    isEnabled: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  claimProtocolFees: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  transferPosition: ({
+    recipient
+  }: {
+    recipient: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setOperator: ({
+    operator
+  }: {
+    operator: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revokeOperator: ({
+    operator
+  }: {
+    operator: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class IonFluxClient extends IonFluxQueryClient implements IonFluxInterface {
   client: SigningCosmWasmClient;
@@ -214,10 +909,30 @@ export class IonFluxClient extends IonFluxQueryClient implements IonFluxInterfac
     this.receive = this.receive.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
     this.updateToken = this.updateToken.bind(this);
+    this.updateTokenFee = this.updateTokenFee.bind(this);
+    this.updateTokenCaps = this.updateTokenCaps.bind(this);
+    this.updatePairFee = this.updatePairFee.bind(this);
+    this.resetHalt = this.resetHalt.bind(this);
+    this.updateFeeDiscounts = this.updateFeeDiscounts.bind(this);
+    this.updateLockBoosts = this.updateLockBoosts.bind(this);
+    this.updateHook = this.updateHook.bind(this);
     this.unbond = this.unbond.bind(this);
     this.withdraw = this.withdraw.bind(this);
+    this.createIncentive = this.createIncentive.bind(this);
+    this.closeIncentive = this.closeIncentive.bind(this);
+    this.cancelOrder = this.cancelOrder.bind(this);
+    this.executeOrders = this.executeOrders.bind(this);
+    this.cancelDca = this.cancelDca.bind(this);
+    this.executeDca = this.executeDca.bind(this);
     this.claim = this.claim.bind(this);
+    this.batchSwap = this.batchSwap.bind(this);
     this.swapAndClaim = this.swapAndClaim.bind(this);
+    this.compound = this.compound.bind(this);
+    this.setAutoCompound = this.setAutoCompound.bind(this);
+    this.claimProtocolFees = this.claimProtocolFees.bind(this);
+    this.transferPosition = this.transferPosition.bind(this);
+    this.setOperator = this.setOperator.bind(this);
+    this.revokeOperator = this.revokeOperator.bind(this);
   }
 
   receive = async ({
@@ -239,22 +954,46 @@ export class IonFluxClient extends IonFluxQueryClient implements IonFluxInterfac
   };
   updateConfig = async ({
     admin,
+    circuitBreaker,
+    dynamicFee,
+    epoch,
+    keeperTipRate,
+    maxReferralCommissionRate,
     priceAge,
+    protocolFeeShare,
+    rateLimit,
     swapFeeRate,
+    treasury,
     unbondingPeriod,
     window
   }: {
     admin?: string;
+    circuitBreaker?: CircuitBreaker;
+    dynamicFee?: DynamicFee;
+    epoch?: Uint128;
+    keeperTipRate?: Decimal;
+    maxReferralCommissionRate?: Decimal;
     priceAge?: Uint128;
+    protocolFeeShare?: Decimal;
+    rateLimit?: RateLimit;
     swapFeeRate?: Decimal;
+    treasury?: string;
     unbondingPeriod?: Uint128;
     window?: Uint128;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       update_config: {
         admin,
+        circuit_breaker: circuitBreaker,
+        dynamic_fee: dynamicFee,
+        epoch,
+        keeper_tip_rate: keeperTipRate,
+        max_referral_commission_rate: maxReferralCommissionRate,
         price_age: priceAge,
+        protocol_fee_share: protocolFeeShare,
+        rate_limit: rateLimit,
         swap_fee_rate: swapFeeRate,
+        treasury,
         unbonding_period: unbondingPeriod,
         window
       }
@@ -277,16 +1016,111 @@ export class IonFluxClient extends IonFluxQueryClient implements IonFluxInterfac
       }
     }, fee, memo, funds);
   };
+  updateTokenFee = async ({
+    swapFeeRate,
+    tokenAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenAddr: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_token_fee: {
+        swap_fee_rate: swapFeeRate,
+        token_addr: tokenAddr
+      }
+    }, fee, memo, funds);
+  };
+  updateTokenCaps = async ({
+    tokenAddr,
+    tokenCaps
+  }: {
+    tokenAddr: string;
+    tokenCaps?: TokenCaps;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_token_caps: {
+        token_addr: tokenAddr,
+        token_caps: tokenCaps
+      }
+    }, fee, memo, funds);
+  };
+  updatePairFee = async ({
+    swapFeeRate,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_pair_fee: {
+        swap_fee_rate: swapFeeRate,
+        token_in_addr: tokenInAddr,
+        token_out_addr: tokenOutAddr
+      }
+    }, fee, memo, funds);
+  };
+  resetHalt = async ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reset_halt: {
+        token_addr: tokenAddr
+      }
+    }, fee, memo, funds);
+  };
+  updateFeeDiscounts = async ({
+    feeDiscountList
+  }: {
+    feeDiscountList: FeeDiscount[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_fee_discounts: {
+        fee_discount_list: feeDiscountList
+      }
+    }, fee, memo, funds);
+  };
+  updateLockBoosts = async ({
+    lockBoostList
+  }: {
+    lockBoostList: LockBoost[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_lock_boosts: {
+        lock_boost_list: lockBoostList
+      }
+    }, fee, memo, funds);
+  };
+  updateHook = async ({
+    contractAddr,
+    hook
+  }: {
+    contractAddr: string;
+    hook?: Hook;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_hook: {
+        contract_addr: contractAddr,
+        hook
+      }
+    }, fee, memo, funds);
+  };
   unbond = async ({
     amount,
+    providerAddr,
     tokenAddr
   }: {
     amount: Uint128;
+    providerAddr?: string;
     tokenAddr: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       unbond: {
         amount,
+        provider_addr: providerAddr,
         token_addr: tokenAddr
       }
     }, fee, memo, funds);
@@ -305,20 +1139,196 @@ export class IonFluxClient extends IonFluxQueryClient implements IonFluxInterfac
       }
     }, fee, memo, funds);
   };
-  claim = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+  createIncentive = async ({
+    end,
+    rewardAsset,
+    start,
+    tokenAddrTarget
+  }: {
+    end: Timestamp;
+    rewardAsset: RewardAsset;
+    start: Timestamp;
+    tokenAddrTarget: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      create_incentive: {
+        end,
+        reward_asset: rewardAsset,
+        start,
+        token_addr_target: tokenAddrTarget
+      }
+    }, fee, memo, funds);
+  };
+  closeIncentive = async ({
+    id,
+    tokenAddrTarget
+  }: {
+    id: number;
+    tokenAddrTarget: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      close_incentive: {
+        id,
+        token_addr_target: tokenAddrTarget
+      }
+    }, fee, memo, funds);
+  };
+  cancelOrder = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_order: {
+        id
+      }
+    }, fee, memo, funds);
+  };
+  executeOrders = async ({
+    idList
+  }: {
+    idList: number[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_orders: {
+        id_list: idList
+      }
+    }, fee, memo, funds);
+  };
+  cancelDca = async ({
+    id
+  }: {
+    id: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_dca: {
+        id
+      }
+    }, fee, memo, funds);
+  };
+  executeDca = async ({
+    idList
+  }: {
+    idList: number[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      execute_dca: {
+        id_list: idList
+      }
+    }, fee, memo, funds);
+  };
+  claim = async ({
+    providerAddr,
+    recipient,
+    tokenAddrList
+  }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim: {
+        provider_addr: providerAddr,
+        recipient,
+        token_addr_list: tokenAddrList
+      }
+    }, fee, memo, funds);
+  };
+  batchSwap = async ({
+    referral,
+    swapList
+  }: {
+    referral?: Referral;
+    swapList: SwapLeg[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      claim: {}
+      batch_swap: {
+        referral,
+        swap_list: swapList
+      }
     }, fee, memo, funds);
   };
   swapAndClaim = async ({
+    providerAddr,
+    recipient,
+    tokenAddrList,
     tokenOutAddr
   }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
     tokenOutAddr: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       swap_and_claim: {
+        provider_addr: providerAddr,
+        recipient,
+        token_addr_list: tokenAddrList,
         token_out_addr: tokenOutAddr
       }
     }, fee, memo, funds);
   };
+  compound = async ({
+    providerAddr,
+    tokenOutAddr
+  }: {
+    providerAddr?: string;
+    tokenOutAddr?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      compound: {
+        provider_addr: providerAddr,
+        token_out_addr: tokenOutAddr
+      }
+    }, fee, memo, funds);
+  };
+  setAutoCompound = async ({
+    isEnabled
+  }: {
+    isEnabled: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_auto_compound: {
+        is_enabled: isEnabled
+      }
+    }, fee, memo, funds);
+  };
+  claimProtocolFees = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_protocol_fees: {}
+    }, fee, memo, funds);
+  };
+  transferPosition = async ({
+    recipient
+  }: {
+    recipient: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      transfer_position: {
+        recipient
+      }
+    }, fee, memo, funds);
+  };
+  setOperator = async ({
+    operator
+  }: {
+    operator: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_operator: {
+        operator
+      }
+    }, fee, memo, funds);
+  };
+  revokeOperator = async ({
+    operator
+  }: {
+    operator: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      revoke_operator: {
+        operator
+      }
+    }, fee, memo, funds);
+  };
 }
\ No newline at end of file
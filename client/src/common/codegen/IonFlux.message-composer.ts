@@ -8,7 +8,7 @@ import { Coin } from "@cosmjs/amino";
 import { MsgExecuteContractEncodeObject } from "cosmwasm";
 import { MsgExecuteContract } from "cosmjs-types/cosmwasm/wasm/v1/tx";
 import { toUtf8 } from "@cosmjs/encoding";
-import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Decimal, Cw20ReceiveMsg, QueryMsg, MigrateMsg, Addr, ArrayOfBalance, Balance, Config, ArrayOfTupleOfAddrAndUint128, ArrayOfTupleOfAddrAndDecimal, Timestamp, Uint64, ArrayOfTupleOfAddrAndArrayOfAsset, Asset, ArrayOfTupleOfAddrAndToken, Token, Sample } from "./IonFlux.types";
+import { InstantiateMsg, ExecuteMsg, Uint128, Binary, Decimal, Addr, Timestamp, Uint64, RewardToken, Cw20ReceiveMsg, CircuitBreaker, DynamicFee, RateLimit, TokenCaps, FeeDiscount, LockBoost, Hook, RewardAsset, Referral, SwapLeg, QueryMsg, MigrateMsg, Boolean, PageForBalanceAndAddr, Balance, Config, PageForTupleOfUint64AndDcaScheduleAndUint64, DcaSchedule, ArrayOfFeeDiscount, Fees, PageForAddrAndAddr, ArrayOfTupleOfTimestampAndSnapshot, Snapshot, PageForTupleOfAddrAndHookAndAddr, PageForTupleOfUint64AndRewardAssetAndUint64, PageForTupleOfUint64AndIncentiveAndUint64, Incentive, PageForTupleOfUint64AndLimitOrderAndUint64, LimitOrder, PageForTupleOfAddrAndUint128AndAddr, ArrayOfLockBoost, ArrayOfAddr, PoolStats, TokenStats, PageForTupleOfAddrAndDecimalAndAddr, ArrayOfTupleOfAddrAndDecimal, ArrayOfAsset, Asset, Lock, ProviderApr, ProviderSummary, AssetSummary, PageForTupleOfAddrAndArrayOfAssetAndAddr, PageForSwapAllowanceAndAddr, SwapAllowance, Token, Sample, PageForTupleOfAddrAndTokenCapsAndAddr, PageForTupleOfAddrAndTokenAndAddr } from "./IonFlux.types";
 export interface IonFluxMessage {
   contractAddress: string;
   sender: string;
@@ -23,14 +23,30 @@ export interface IonFluxMessage {
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   updateConfig: ({
     admin,
+    circuitBreaker,
+    dynamicFee,
+    epoch,
+    keeperTipRate,
+    maxReferralCommissionRate,
     priceAge,
+    protocolFeeShare,
+    rateLimit,
     swapFeeRate,
+    treasury,
     unbondingPeriod,
     window
   }: {
     admin?: string;
+    circuitBreaker?: CircuitBreaker;
+    dynamicFee?: DynamicFee;
+    epoch?: Uint128;
+    keeperTipRate?: Decimal;
+    maxReferralCommissionRate?: Decimal;
     priceAge?: Uint128;
+    protocolFeeShare?: Decimal;
+    rateLimit?: RateLimit;
     swapFeeRate?: Decimal;
+    treasury?: string;
     unbondingPeriod?: Uint128;
     window?: Uint128;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
@@ -43,11 +59,58 @@ export interface IonFluxMessage {
     symbol: string;
     tokenAddr: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateTokenFee: ({
+    swapFeeRate,
+    tokenAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenAddr: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateTokenCaps: ({
+    tokenAddr,
+    tokenCaps
+  }: {
+    tokenAddr: string;
+    tokenCaps?: TokenCaps;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updatePairFee: ({
+    swapFeeRate,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  resetHalt: ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateFeeDiscounts: ({
+    feeDiscountList
+  }: {
+    feeDiscountList: FeeDiscount[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateLockBoosts: ({
+    lockBoostList
+  }: {
+    lockBoostList: LockBoost[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  updateHook: ({
+    contractAddr,
+    hook
+  }: {
+    contractAddr: string;
+    hook?: Hook;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   unbond: ({
     amount,
+    providerAddr,
     tokenAddr
   }: {
     amount: Uint128;
+    providerAddr?: string;
     tokenAddr: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   withdraw: ({
@@ -57,12 +120,99 @@ export interface IonFluxMessage {
     amount: Uint128;
     tokenAddr: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
-  claim: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  createIncentive: ({
+    end,
+    rewardAsset,
+    start,
+    tokenAddrTarget
+  }: {
+    end: Timestamp;
+    rewardAsset: RewardAsset;
+    start: Timestamp;
+    tokenAddrTarget: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  closeIncentive: ({
+    id,
+    tokenAddrTarget
+  }: {
+    id: number;
+    tokenAddrTarget: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelOrder: ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  executeOrders: ({
+    idList
+  }: {
+    idList: number[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  cancelDca: ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  executeDca: ({
+    idList
+  }: {
+    idList: number[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claim: ({
+    providerAddr,
+    recipient,
+    tokenAddrList
+  }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  batchSwap: ({
+    referral,
+    swapList
+  }: {
+    referral?: Referral;
+    swapList: SwapLeg[];
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
   swapAndClaim: ({
+    providerAddr,
+    recipient,
+    tokenAddrList,
     tokenOutAddr
   }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
     tokenOutAddr: string;
   }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  compound: ({
+    providerAddr,
+    tokenOutAddr
+  }: {
+    providerAddr?: string;
+    tokenOutAddr?: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setAutoCompound: ({
+    isEnabled
+  }: {
+    isEnabled: boolean;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  claimProtocolFees: (funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  transferPosition: ({
+    recipient
+  }: {
+    recipient: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  setOperator: ({
+    operator
+  }: {
+    operator: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
+  revokeOperator: ({
+    operator
+  }: {
+    operator: string;
+  }, funds?: Coin[]) => MsgExecuteContractEncodeObject;
 }
 export class IonFluxMessageComposer implements IonFluxMessage {
   sender: string;
@@ -74,10 +224,30 @@ export class IonFluxMessageComposer implements IonFluxMessage {
     this.receive = this.receive.bind(this);
     this.updateConfig = this.updateConfig.bind(this);
     this.updateToken = this.updateToken.bind(this);
+    this.updateTokenFee = this.updateTokenFee.bind(this);
+    this.updateTokenCaps = this.updateTokenCaps.bind(this);
+    this.updatePairFee = this.updatePairFee.bind(this);
+    this.resetHalt = this.resetHalt.bind(this);
+    this.updateFeeDiscounts = this.updateFeeDiscounts.bind(this);
+    this.updateLockBoosts = this.updateLockBoosts.bind(this);
+    this.updateHook = this.updateHook.bind(this);
     this.unbond = this.unbond.bind(this);
     this.withdraw = this.withdraw.bind(this);
+    this.createIncentive = this.createIncentive.bind(this);
+    this.closeIncentive = this.closeIncentive.bind(this);
+    this.cancelOrder = this.cancelOrder.bind(this);
+    this.executeOrders = this.executeOrders.bind(this);
+    this.cancelDca = this.cancelDca.bind(this);
+    this.executeDca = this.executeDca.bind(this);
     this.claim = this.claim.bind(this);
+    this.batchSwap = this.batchSwap.bind(this);
     this.swapAndClaim = this.swapAndClaim.bind(this);
+    this.compound = this.compound.bind(this);
+    this.setAutoCompound = this.setAutoCompound.bind(this);
+    this.claimProtocolFees = this.claimProtocolFees.bind(this);
+    this.transferPosition = this.transferPosition.bind(this);
+    this.setOperator = this.setOperator.bind(this);
+    this.revokeOperator = this.revokeOperator.bind(this);
   }
 
   receive = ({
@@ -107,14 +277,30 @@ export class IonFluxMessageComposer implements IonFluxMessage {
   };
   updateConfig = ({
     admin,
+    circuitBreaker,
+    dynamicFee,
+    epoch,
+    keeperTipRate,
+    maxReferralCommissionRate,
     priceAge,
+    protocolFeeShare,
+    rateLimit,
     swapFeeRate,
+    treasury,
     unbondingPeriod,
     window
   }: {
     admin?: string;
+    circuitBreaker?: CircuitBreaker;
+    dynamicFee?: DynamicFee;
+    epoch?: Uint128;
+    keeperTipRate?: Decimal;
+    maxReferralCommissionRate?: Decimal;
     priceAge?: Uint128;
+    protocolFeeShare?: Decimal;
+    rateLimit?: RateLimit;
     swapFeeRate?: Decimal;
+    treasury?: string;
     unbondingPeriod?: Uint128;
     window?: Uint128;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
@@ -126,8 +312,16 @@ export class IonFluxMessageComposer implements IonFluxMessage {
         msg: toUtf8(JSON.stringify({
           update_config: {
             admin,
+            circuit_breaker: circuitBreaker,
+            dynamic_fee: dynamicFee,
+            epoch,
+            keeper_tip_rate: keeperTipRate,
+            max_referral_commission_rate: maxReferralCommissionRate,
             price_age: priceAge,
+            protocol_fee_share: protocolFeeShare,
+            rate_limit: rateLimit,
             swap_fee_rate: swapFeeRate,
+            treasury,
             unbonding_period: unbondingPeriod,
             window
           }
@@ -161,11 +355,161 @@ export class IonFluxMessageComposer implements IonFluxMessage {
       })
     };
   };
+  updateTokenFee = ({
+    swapFeeRate,
+    tokenAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenAddr: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_token_fee: {
+            swap_fee_rate: swapFeeRate,
+            token_addr: tokenAddr
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateTokenCaps = ({
+    tokenAddr,
+    tokenCaps
+  }: {
+    tokenAddr: string;
+    tokenCaps?: TokenCaps;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_token_caps: {
+            token_addr: tokenAddr,
+            token_caps: tokenCaps
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updatePairFee = ({
+    swapFeeRate,
+    tokenInAddr,
+    tokenOutAddr
+  }: {
+    swapFeeRate?: Decimal;
+    tokenInAddr: string;
+    tokenOutAddr: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_pair_fee: {
+            swap_fee_rate: swapFeeRate,
+            token_in_addr: tokenInAddr,
+            token_out_addr: tokenOutAddr
+          }
+        })),
+        funds
+      })
+    };
+  };
+  resetHalt = ({
+    tokenAddr
+  }: {
+    tokenAddr: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          reset_halt: {
+            token_addr: tokenAddr
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateFeeDiscounts = ({
+    feeDiscountList
+  }: {
+    feeDiscountList: FeeDiscount[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_fee_discounts: {
+            fee_discount_list: feeDiscountList
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateLockBoosts = ({
+    lockBoostList
+  }: {
+    lockBoostList: LockBoost[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_lock_boosts: {
+            lock_boost_list: lockBoostList
+          }
+        })),
+        funds
+      })
+    };
+  };
+  updateHook = ({
+    contractAddr,
+    hook
+  }: {
+    contractAddr: string;
+    hook?: Hook;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          update_hook: {
+            contract_addr: contractAddr,
+            hook
+          }
+        })),
+        funds
+      })
+    };
+  };
   unbond = ({
     amount,
+    providerAddr,
     tokenAddr
   }: {
     amount: Uint128;
+    providerAddr?: string;
     tokenAddr: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -176,6 +520,7 @@ export class IonFluxMessageComposer implements IonFluxMessage {
         msg: toUtf8(JSON.stringify({
           unbond: {
             amount,
+            provider_addr: providerAddr,
             token_addr: tokenAddr
           }
         })),
@@ -205,22 +550,188 @@ export class IonFluxMessageComposer implements IonFluxMessage {
       })
     };
   };
-  claim = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+  createIncentive = ({
+    end,
+    rewardAsset,
+    start,
+    tokenAddrTarget
+  }: {
+    end: Timestamp;
+    rewardAsset: RewardAsset;
+    start: Timestamp;
+    tokenAddrTarget: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          create_incentive: {
+            end,
+            reward_asset: rewardAsset,
+            start,
+            token_addr_target: tokenAddrTarget
+          }
+        })),
+        funds
+      })
+    };
+  };
+  closeIncentive = ({
+    id,
+    tokenAddrTarget
+  }: {
+    id: number;
+    tokenAddrTarget: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          close_incentive: {
+            id,
+            token_addr_target: tokenAddrTarget
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelOrder = ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_order: {
+            id
+          }
+        })),
+        funds
+      })
+    };
+  };
+  executeOrders = ({
+    idList
+  }: {
+    idList: number[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          execute_orders: {
+            id_list: idList
+          }
+        })),
+        funds
+      })
+    };
+  };
+  cancelDca = ({
+    id
+  }: {
+    id: number;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          cancel_dca: {
+            id
+          }
+        })),
+        funds
+      })
+    };
+  };
+  executeDca = ({
+    idList
+  }: {
+    idList: number[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          execute_dca: {
+            id_list: idList
+          }
+        })),
+        funds
+      })
+    };
+  };
+  claim = ({
+    providerAddr,
+    recipient,
+    tokenAddrList
+  }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
       typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
       value: MsgExecuteContract.fromPartial({
         sender: this.sender,
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
-          claim: {}
+          claim: {
+            provider_addr: providerAddr,
+            recipient,
+            token_addr_list: tokenAddrList
+          }
+        })),
+        funds
+      })
+    };
+  };
+  batchSwap = ({
+    referral,
+    swapList
+  }: {
+    referral?: Referral;
+    swapList: SwapLeg[];
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          batch_swap: {
+            referral,
+            swap_list: swapList
+          }
         })),
         funds
       })
     };
   };
   swapAndClaim = ({
+    providerAddr,
+    recipient,
+    tokenAddrList,
     tokenOutAddr
   }: {
+    providerAddr?: string;
+    recipient?: string;
+    tokenAddrList?: string[];
     tokenOutAddr: string;
   }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
     return {
@@ -230,6 +741,31 @@ export class IonFluxMessageComposer implements IonFluxMessage {
         contract: this.contractAddress,
         msg: toUtf8(JSON.stringify({
           swap_and_claim: {
+            provider_addr: providerAddr,
+            recipient,
+            token_addr_list: tokenAddrList,
+            token_out_addr: tokenOutAddr
+          }
+        })),
+        funds
+      })
+    };
+  };
+  compound = ({
+    providerAddr,
+    tokenOutAddr
+  }: {
+    providerAddr?: string;
+    tokenOutAddr?: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          compound: {
+            provider_addr: providerAddr,
             token_out_addr: tokenOutAddr
           }
         })),
@@ -237,4 +773,93 @@ export class IonFluxMessageComposer implements IonFluxMessage {
       })
     };
   };
+  setAutoCompound = ({
+    isEnabled
+  }: {
+    isEnabled: boolean;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_auto_compound: {
+            is_enabled: isEnabled
+          }
+        })),
+        funds
+      })
+    };
+  };
+  claimProtocolFees = (funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          claim_protocol_fees: {}
+        })),
+        funds
+      })
+    };
+  };
+  transferPosition = ({
+    recipient
+  }: {
+    recipient: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          transfer_position: {
+            recipient
+          }
+        })),
+        funds
+      })
+    };
+  };
+  setOperator = ({
+    operator
+  }: {
+    operator: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          set_operator: {
+            operator
+          }
+        })),
+        funds
+      })
+    };
+  };
+  revokeOperator = ({
+    operator
+  }: {
+    operator: string;
+  }, funds?: Coin[]): MsgExecuteContractEncodeObject => {
+    return {
+      typeUrl: "/cosmwasm.wasm.v1.MsgExecuteContract",
+      value: MsgExecuteContract.fromPartial({
+        sender: this.sender,
+        contract: this.contractAddress,
+        msg: toUtf8(JSON.stringify({
+          revoke_operator: {
+            operator
+          }
+        })),
+        funds
+      })
+    };
+  };
 }
\ No newline at end of file
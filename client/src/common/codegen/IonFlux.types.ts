@@ -10,8 +10,16 @@ export type ExecuteMsg = {
 } | {
   update_config: {
     admin?: string | null;
+    circuit_breaker?: CircuitBreaker | null;
+    dynamic_fee?: DynamicFee | null;
+    epoch?: Uint128 | null;
+    keeper_tip_rate?: Decimal | null;
+    max_referral_commission_rate?: Decimal | null;
     price_age?: Uint128 | null;
+    protocol_fee_share?: Decimal | null;
+    rate_limit?: RateLimit | null;
     swap_fee_rate?: Decimal | null;
+    treasury?: string | null;
     unbonding_period?: Uint128 | null;
     window?: Uint128 | null;
   };
@@ -21,9 +29,43 @@ export type ExecuteMsg = {
     symbol: string;
     token_addr: string;
   };
+} | {
+  update_token_fee: {
+    swap_fee_rate?: Decimal | null;
+    token_addr: string;
+  };
+} | {
+  update_token_caps: {
+    token_addr: string;
+    token_caps?: TokenCaps | null;
+  };
+} | {
+  update_pair_fee: {
+    swap_fee_rate?: Decimal | null;
+    token_in_addr: string;
+    token_out_addr: string;
+  };
+} | {
+  reset_halt: {
+    token_addr: string;
+  };
+} | {
+  update_fee_discounts: {
+    fee_discount_list: FeeDiscount[];
+  };
+} | {
+  update_lock_boosts: {
+    lock_boost_list: LockBoost[];
+  };
+} | {
+  update_hook: {
+    contract_addr: string;
+    hook?: Hook | null;
+  };
 } | {
   unbond: {
     amount: Uint128;
+    provider_addr?: string | null;
     token_addr: string;
   };
 } | {
@@ -32,54 +74,304 @@ export type ExecuteMsg = {
     token_addr: string;
   };
 } | {
-  claim: {};
+  create_incentive: {
+    end: Timestamp;
+    reward_asset: RewardAsset;
+    start: Timestamp;
+    token_addr_target: string;
+  };
+} | {
+  close_incentive: {
+    id: number;
+    token_addr_target: string;
+  };
+} | {
+  cancel_order: {
+    id: number;
+  };
+} | {
+  execute_orders: {
+    id_list: number[];
+  };
+} | {
+  cancel_dca: {
+    id: number;
+  };
+} | {
+  execute_dca: {
+    id_list: number[];
+  };
+} | {
+  claim: {
+    provider_addr?: string | null;
+    recipient?: string | null;
+    token_addr_list?: string[] | null;
+  };
+} | {
+  batch_swap: {
+    referral?: Referral | null;
+    swap_list: SwapLeg[];
+  };
 } | {
   swap_and_claim: {
+    provider_addr?: string | null;
+    recipient?: string | null;
+    token_addr_list?: string[] | null;
     token_out_addr: string;
   };
+} | {
+  compound: {
+    provider_addr?: string | null;
+    token_out_addr?: string | null;
+  };
+} | {
+  set_auto_compound: {
+    is_enabled: boolean;
+  };
+} | {
+  claim_protocol_fees: {};
+} | {
+  transfer_position: {
+    recipient: string;
+  };
+} | {
+  set_operator: {
+    operator: string;
+  };
+} | {
+  revoke_operator: {
+    operator: string;
+  };
 };
 export type Uint128 = string;
 export type Binary = string;
 export type Decimal = string;
+export type Addr = string;
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type RewardToken = {
+  cw20: Addr;
+} | {
+  native: string;
+};
 export interface Cw20ReceiveMsg {
   amount: Uint128;
   msg: Binary;
   sender: string;
 }
+export interface CircuitBreaker {
+  is_enabled: boolean;
+  max_price_deviation: Decimal;
+}
+export interface DynamicFee {
+  is_enabled: boolean;
+  max_fee_rate: Decimal;
+  min_fee_rate: Decimal;
+}
+export interface RateLimit {
+  max_address_volume?: Uint128 | null;
+  max_block_volume?: Uint128 | null;
+}
+export interface TokenCaps {
+  max_deposit?: Uint128 | null;
+  max_swap_out_ratio?: Decimal | null;
+  max_window_outflow?: Uint128 | null;
+}
+export interface FeeDiscount {
+  fee_multiplier: Decimal;
+  min_balance: Uint128;
+  token_addr: Addr;
+}
+export interface LockBoost {
+  duration: Uint128;
+  multiplier: Decimal;
+}
+export interface Hook {
+  is_required: boolean;
+  on_liquidity: boolean;
+  on_swap: boolean;
+}
+export interface RewardAsset {
+  amount: Uint128;
+  token: RewardToken;
+}
+export interface Referral {
+  address: string;
+  commission_rate: Decimal;
+}
+export interface SwapLeg {
+  amount_in: Uint128;
+  min_amount_out: Uint128;
+  token_in_addr: string;
+  token_out_addr: string;
+}
 export type QueryMsg = {
   query_config: {};
 } | {
   query_tokens_weight: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_token_apr: {};
+} | {
+  query_provider_apr: {
+    address: string;
+  };
+} | {
+  query_pool_stats: {};
+} | {
+  query_history: {
+    from: Timestamp;
+    to: Timestamp;
+    token_addr: string;
   };
 } | {
   query_liquidity: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
   };
 } | {
   query_providers: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_provider: {
+    address: string;
+  };
+} | {
+  query_provider_summary: {
+    address: string;
+  };
+} | {
+  query_operators: {
+    address: string;
+  };
+} | {
+  query_auto_compound: {
+    address: string;
   };
 } | {
   query_tokens: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_token: {
+    token_addr: string;
   };
 } | {
   query_balances: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_fees: {};
+} | {
+  query_swap_fee_rate: {
+    token_in_addr: string;
+    token_out_addr: string;
+  };
+} | {
+  query_swap_allowances: {
+    address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_halted_tokens: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_price_averages: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_token_caps: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_fee_discounts: {};
+} | {
+  query_lock_boosts: {};
+} | {
+  query_incentives: {
+    limit?: number | null;
+    start_after?: number | null;
+    token_addr_target: string;
+  };
+} | {
+  query_incentive_rewards: {
+    address: string;
+    limit?: number | null;
+    start_after?: number | null;
+    token_addr_target: string;
+  };
+} | {
+  query_limit_orders_by_owner: {
+    limit?: number | null;
+    owner: string;
+    start_after?: number | null;
+  };
+} | {
+  query_limit_orders_by_pair: {
+    limit?: number | null;
+    start_after?: number | null;
+    token_in_addr: string;
+    token_out_addr: string;
+  };
+} | {
+  query_dca_schedules: {
+    limit?: number | null;
+    owner?: string | null;
+    start_after?: number | null;
+  };
+} | {
+  query_hooks: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  query_fee_multiplier: {
+    address: string;
+  };
+} | {
+  query_protocol_fees: {
+    limit?: number | null;
+    start_after?: string | null;
   };
 } | {
   query_prices: {
     address_list: string[];
+    limit?: number | null;
+    start_after?: string | null;
   };
 } | {
   query_prices_mocked: {
     address_list: string[];
   };
 };
-export type MigrateMsg = string;
-export type Addr = string;
-export type ArrayOfBalance = Balance[];
+export interface MigrateMsg {
+  epoch?: Uint128 | null;
+  keeper_tip_rate?: Decimal | null;
+  max_referral_commission_rate?: Decimal | null;
+  protocol_fee_share?: Decimal | null;
+  treasury?: string | null;
+}
+export type Boolean = boolean;
+export interface PageForBalanceAndAddr {
+  cursor?: Addr | null;
+  list: Balance[];
+}
 export interface Balance {
   amount: Uint128;
   token_addr: Addr;
@@ -87,34 +379,210 @@ export interface Balance {
 export interface Config {
   admin: Addr;
   chain_id_mocked: string;
+  circuit_breaker: CircuitBreaker;
+  dynamic_fee: DynamicFee;
+  epoch: Uint128;
+  keeper_tip_rate: Decimal;
+  max_referral_commission_rate: Decimal;
   price_age: Uint128;
+  protocol_fee_share: Decimal;
+  rate_limit: RateLimit;
   swap_fee_rate: Decimal;
+  treasury: Addr;
   unbonding_period: Uint128;
   window: Uint128;
 }
-export type ArrayOfTupleOfAddrAndUint128 = [Addr, Uint128][];
+export interface PageForTupleOfUint64AndDcaScheduleAndUint64 {
+  cursor?: number | null;
+  list: [number, DcaSchedule][];
+}
+export interface DcaSchedule {
+  amount_in_total: Uint128;
+  amount_out_total: Uint128;
+  amount_per_interval: Uint128;
+  balance: Uint128;
+  executed_count: number;
+  interval: Uint128;
+  next_execution: Timestamp;
+  owner: Addr;
+  slippage: Decimal;
+  token_in_addr: Addr;
+  token_out_addr: Addr;
+}
+export type ArrayOfFeeDiscount = FeeDiscount[];
+export interface Fees {
+  pair_fee_list: [Addr, Addr, Decimal][];
+  swap_fee_rate: Decimal;
+  token_fee_list: [Addr, Decimal][];
+}
+export interface PageForAddrAndAddr {
+  cursor?: Addr | null;
+  list: Addr[];
+}
+export type ArrayOfTupleOfTimestampAndSnapshot = [Timestamp, Snapshot][];
+export interface Snapshot {
+  bonded: Uint128;
+  fees: Uint128;
+  fees_total: Uint128;
+  price?: Decimal | null;
+  swapped_in: Uint128;
+  swapped_in_total: Uint128;
+  swapped_out: Uint128;
+  swapped_out_total: Uint128;
+}
+export interface PageForTupleOfAddrAndHookAndAddr {
+  cursor?: Addr | null;
+  list: [Addr, Hook][];
+}
+export interface PageForTupleOfUint64AndRewardAssetAndUint64 {
+  cursor?: number | null;
+  list: [number, RewardAsset][];
+}
+export interface PageForTupleOfUint64AndIncentiveAndUint64 {
+  cursor?: number | null;
+  list: [number, Incentive][];
+}
+export interface Incentive {
+  bonded_unsettled?: Uint128;
+  creator: Addr;
+  distributed: Uint128;
+  end: Timestamp;
+  is_closed?: boolean;
+  last_update: Timestamp;
+  reward_asset: RewardAsset;
+  reward_per_token: Decimal;
+  start: Timestamp;
+  token_addr_target: Addr;
+}
+export interface PageForTupleOfUint64AndLimitOrderAndUint64 {
+  cursor?: number | null;
+  list: [number, LimitOrder][];
+}
+export interface LimitOrder {
+  amount_in: Uint128;
+  expiry: Timestamp;
+  min_price: Decimal;
+  owner: Addr;
+  token_in_addr: Addr;
+  token_out_addr: Addr;
+}
+export interface PageForTupleOfAddrAndUint128AndAddr {
+  cursor?: Addr | null;
+  list: [Addr, Uint128][];
+}
+export type ArrayOfLockBoost = LockBoost[];
+export type ArrayOfAddr = Addr[];
+export interface PoolStats {
+  bonded_value: Decimal;
+  fees_total_value: Decimal;
+  liquidity_value: Decimal;
+  provider_count: number;
+  requested_value: Decimal;
+  rewards_value: Decimal;
+  swapped_in_total_value: Decimal;
+  swapped_out_total_value: Decimal;
+  token_stats_list: TokenStats[];
+  unbonded_value: Decimal;
+}
+export interface TokenStats {
+  bonded: Uint128;
+  bonded_value: Decimal;
+  fees_total: Uint128;
+  fees_total_value: Decimal;
+  liquidity: Uint128;
+  liquidity_value: Decimal;
+  price: Decimal;
+  provider_count: number;
+  requested: Uint128;
+  requested_value: Decimal;
+  rewards: Uint128;
+  rewards_value: Decimal;
+  swapped_in_total: Uint128;
+  swapped_in_total_value: Decimal;
+  swapped_out_total: Uint128;
+  swapped_out_total_value: Decimal;
+  token_addr: Addr;
+  unbonded: Uint128;
+  unbonded_value: Decimal;
+}
+export interface PageForTupleOfAddrAndDecimalAndAddr {
+  cursor?: Addr | null;
+  list: [Addr, Decimal][];
+}
 export type ArrayOfTupleOfAddrAndDecimal = [Addr, Decimal][];
-export type Timestamp = Uint64;
-export type Uint64 = string;
-export type ArrayOfTupleOfAddrAndArrayOfAsset = [Addr, Asset[]][];
+export type ArrayOfAsset = Asset[];
 export interface Asset {
   bonded: Uint128;
   counter: Timestamp;
+  lock?: Lock | null;
   requested: Uint128;
   rewards: Uint128;
   token_addr: Addr;
   unbonded: Uint128;
 }
-export type ArrayOfTupleOfAddrAndToken = [Addr, Token][];
+export interface Lock {
+  amount: Uint128;
+  end: Timestamp;
+  multiplier: Decimal;
+}
+export interface ProviderApr {
+  address: Addr;
+  apr: Decimal;
+  asset_apr_list: [Addr, Decimal][];
+}
+export interface ProviderSummary {
+  address: Addr;
+  asset_list: AssetSummary[];
+  total_value: Decimal;
+}
+export interface AssetSummary {
+  bonded: Uint128;
+  bonded_value: Decimal;
+  lock?: Lock | null;
+  price: Decimal;
+  requested: Uint128;
+  requested_value: Decimal;
+  rewards: Uint128;
+  rewards_value: Decimal;
+  token_addr: Addr;
+  unbonded: Uint128;
+  unbonded_value: Decimal;
+  unlock_time?: Timestamp | null;
+}
+export interface PageForTupleOfAddrAndArrayOfAssetAndAddr {
+  cursor?: Addr | null;
+  list: [Addr, Asset[]][];
+}
+export interface PageForSwapAllowanceAndAddr {
+  cursor?: Addr | null;
+  list: SwapAllowance[];
+}
+export interface SwapAllowance {
+  address: Addr;
+  address_allowance?: Uint128 | null;
+  block_allowance?: Uint128 | null;
+}
 export interface Token {
   bonded: [Sample[], Uint128];
+  fees_total?: Uint128;
   price_feed_id_str: string;
   requested: [Sample[], Uint128];
+  swap_fee_rate?: Decimal | null;
   swapped_in: [Sample[], Uint128];
+  swapped_in_total?: Uint128;
   swapped_out: [Sample[], Uint128];
+  swapped_out_total?: Uint128;
   symbol: string;
 }
 export interface Sample {
   timestamp: Timestamp;
   value: Uint128;
+}
+export interface PageForTupleOfAddrAndTokenCapsAndAddr {
+  cursor?: Addr | null;
+  list: [Addr, TokenCaps][];
+}
+export interface PageForTupleOfAddrAndTokenAndAddr {
+  cursor?: Addr | null;
+  list: [Addr, Token][];
 }
\ No newline at end of file
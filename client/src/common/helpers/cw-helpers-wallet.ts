@@ -1,4 +1,4 @@
-import { l } from "../utils";
+import { l, queryAllPages } from "../utils";
 import { tokenAddrToSymbolList } from "./general";
 import { getCwClient, fee } from "../signers";
 import { CONTRACT_ADDRESS } from "../config/testnet-config.json";
@@ -126,7 +126,7 @@ async function getCwHelpers(
   }
 
   async function cwClaim() {
-    return await _msgWrapper(composer.claim());
+    return await _msgWrapper(composer.claim({}));
   }
 
   async function cwSwapAndClaim(tokenOutAddr: string) {
@@ -143,7 +143,7 @@ async function getCwHelpers(
 
   async function cwQueryTokensWeight(addressList: string[] = []) {
     try {
-      return await client.queryTokensWeight({ addressList });
+      return await queryAllPages(addressList, client.queryTokensWeight);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -151,7 +151,7 @@ async function getCwHelpers(
 
   async function cwQueryLiquidity(addressList: string[] = []) {
     try {
-      return await client.queryLiquidity({ addressList });
+      return await queryAllPages(addressList, client.queryLiquidity);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -159,7 +159,7 @@ async function getCwHelpers(
 
   async function cwQueryProviders(addressList: string[] = []) {
     try {
-      return await client.queryProviders({ addressList });
+      return await queryAllPages(addressList, client.queryProviders);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -167,7 +167,7 @@ async function getCwHelpers(
 
   async function cwQueryTokens(addressList: string[] = []) {
     try {
-      return await client.queryTokens({ addressList });
+      return await queryAllPages(addressList, client.queryTokens);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -175,7 +175,7 @@ async function getCwHelpers(
 
   async function cwQueryBalances(addressList: string[] = []) {
     try {
-      return await client.queryBalances({ addressList });
+      return await queryAllPages(addressList, client.queryBalances);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -183,7 +183,7 @@ async function getCwHelpers(
 
   async function cwQueryPrices(addressList: string[] = []) {
     try {
-      return await client.queryPrices({ addressList });
+      return await queryAllPages(addressList, client.queryPrices);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -1,4 +1,4 @@
-import { l } from "../utils";
+import { l, queryAllPages } from "../utils";
 import { tokenAddrToSymbolList } from "./general";
 import { init } from "../signers/injective";
 import { RPC, CONTRACT_ADDRESS } from "../config/testnet-config.json";
@@ -122,7 +122,7 @@ async function getCwHelpers(seed: string) {
   }
 
   async function cwClaim() {
-    return await execWrapper(composer.claim());
+    return await execWrapper(composer.claim({}));
   }
 
   async function cwSwapAndClaim(tokenOutAddr: string) {
@@ -139,7 +139,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryTokensWeight(addressList: string[] = []) {
     try {
-      return await queryClient.queryTokensWeight({ addressList });
+      return await queryAllPages(addressList, queryClient.queryTokensWeight);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -147,7 +147,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryLiquidity(addressList: string[] = []) {
     try {
-      return await queryClient.queryLiquidity({ addressList });
+      return await queryAllPages(addressList, queryClient.queryLiquidity);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -155,7 +155,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryProviders(addressList: string[] = []) {
     try {
-      return await queryClient.queryProviders({ addressList });
+      return await queryAllPages(addressList, queryClient.queryProviders);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -163,7 +163,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryTokens(addressList: string[] = []) {
     try {
-      return await queryClient.queryTokens({ addressList });
+      return await queryAllPages(addressList, queryClient.queryTokens);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -171,7 +171,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryBalances(addressList: string[] = []) {
     try {
-      return await queryClient.queryBalances({ addressList });
+      return await queryAllPages(addressList, queryClient.queryBalances);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -179,7 +179,7 @@ async function getCwHelpers(seed: string) {
 
   async function cwQueryPrices(addressList: string[] = []) {
     try {
-      return await queryClient.queryPrices({ addressList });
+      return await queryAllPages(addressList, queryClient.queryPrices);
     } catch (error) {
       l("\n", error, "\n");
     }
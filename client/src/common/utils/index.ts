@@ -56,6 +56,32 @@ async function specifyTimeout(
   ]).finally(() => clearTimeout(timer));
 }
 
+// collects whole list page by page, address list is queried in single request
+async function queryAllPages<T>(
+  addressList: string[],
+  queryPage: (pageArgs: {
+    addressList: string[];
+    startAfter?: string;
+    limit?: number;
+  }) => Promise<{ list: T[]; cursor?: string | null }>,
+  limit: number = 50
+): Promise<T[]> {
+  if (addressList.length) {
+    return (await queryPage({ addressList })).list;
+  }
+
+  let list: T[] = [];
+  let startAfter: string | undefined;
+
+  do {
+    const page = await queryPage({ addressList, startAfter, limit });
+    list = [...list, ...page.list];
+    startAfter = page.cursor || undefined;
+  } while (startAfter);
+
+  return list;
+}
+
 function encrypt(data: string, key: string): string {
   return AES.encrypt(data, key).toString();
 }
@@ -88,6 +114,7 @@ export {
   SEP,
   getLast,
   specifyTimeout,
+  queryAllPages,
   encrypt,
   decrypt,
   fromDecimalToMicro,
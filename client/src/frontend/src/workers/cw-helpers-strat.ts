@@ -1,4 +1,4 @@
-import { l } from "../../../common/utils";
+import { l, queryAllPages } from "../../../common/utils";
 import { tokenAddrToSymbolList } from "../../../common/helpers/general";
 import { initWithKeplr } from "./injective-strat";
 import { getCwClient } from "../../../common/signers";
@@ -135,7 +135,7 @@ async function getCwHelpers(
   }
 
   async function cwClaim() {
-    return await execWrapper(composer.claim());
+    return await execWrapper(composer.claim({}));
   }
 
   async function cwSwapAndClaim(tokenOutAddr: string) {
@@ -152,7 +152,7 @@ async function getCwHelpers(
 
   async function cwQueryTokensWeight(addressList: string[] = []) {
     try {
-      return await client.queryTokensWeight({ addressList });
+      return await queryAllPages(addressList, client.queryTokensWeight);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -160,7 +160,7 @@ async function getCwHelpers(
 
   async function cwQueryLiquidity(addressList: string[] = []) {
     try {
-      return await client.queryLiquidity({ addressList });
+      return await queryAllPages(addressList, client.queryLiquidity);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -168,7 +168,7 @@ async function getCwHelpers(
 
   async function cwQueryProviders(addressList: string[] = []) {
     try {
-      return await client.queryProviders({ addressList });
+      return await queryAllPages(addressList, client.queryProviders);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -176,7 +176,7 @@ async function getCwHelpers(
 
   async function cwQueryTokens(addressList: string[] = []) {
     try {
-      return await client.queryTokens({ addressList });
+      return await queryAllPages(addressList, client.queryTokens);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -184,7 +184,7 @@ async function getCwHelpers(
 
   async function cwQueryBalances(addressList: string[] = []) {
     try {
-      return await client.queryBalances({ addressList });
+      return await queryAllPages(addressList, client.queryBalances);
     } catch (error) {
       l("\n", error, "\n");
     }
@@ -192,7 +192,7 @@ async function getCwHelpers(
 
   async function cwQueryPrices(addressList: string[] = []) {
     try {
-      return await client.queryPrices({ addressList });
+      return await queryAllPages(addressList, client.queryPrices);
     } catch (error) {
       l("\n", error, "\n");
     }
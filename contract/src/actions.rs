@@ -4,3 +4,4 @@ pub mod math;
 pub mod migrate;
 pub mod query;
 pub mod receive;
+pub mod reply;
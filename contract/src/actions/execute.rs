@@ -1,20 +1,174 @@
 use cosmwasm_std::CosmosMsg;
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
-    to_binary, Addr, Decimal, DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg,
+    coins, to_binary, to_vec, Addr, BankMsg, Decimal, Deps, DepsMut, Empty, Env, Event,
+    MessageInfo, Order, Response, StdResult, Storage, SubMsg, Timestamp, Uint128, WasmMsg,
 };
 
-use cw20::Cw20ExecuteMsg;
+use cw20::{AllowanceResponse, Cw20ExecuteMsg, Cw20QueryMsg};
 
 use crate::{
     actions::{
-        math::{calc_sma, u128_to_dec},
-        query::{query_prices, query_prices_mocked},
+        math::{calc_incentive_rewards, calc_reward_per_token, calc_sma, u128_to_dec},
+        query::{
+            query_fee_multiplier, query_prices, query_prices_any, query_prices_mocked,
+            query_swap_fee_rate, query_tokens,
+        },
+        receive::{check_circuit_breaker, get_deposit_room, swap_accepting_prices},
     },
     error::ContractError,
-    state::{Asset, Config, Sample, Token, CONFIG, PROVIDERS, TOKENS},
+    messages::{
+        execute::SwapLeg,
+        hook::{IonFluxHookMsg, LiquidityAction},
+        receive::Referral,
+    },
+    state::{
+        Asset, CircuitBreaker, Config, DcaSchedule, DynamicFee, FeeDiscount, Hook, Incentive,
+        IncentiveReward, LimitOrder, Lock, LockBoost, RateLimit, RewardAsset, RewardToken, Sample,
+        Snapshot, StorageCache, Token, TokenCaps, AUTO_COMPOUND, BONDED_TOTALS, CONFIG,
+        DCA_SCHEDULES, DCA_SCHEDULES_BY_OWNER, ESCROWED, FEE_DISCOUNTS, HALTED_TOKENS, HOOKS,
+        HOOK_REPLY_ID, INCENTIVES, INCENTIVE_COUNTER, INCENTIVE_REWARDS, LAST_EPOCH, LIMIT_ORDERS,
+        LIMIT_ORDERS_BY_OWNER, LIMIT_ORDERS_BY_PAIR, LOCK_BOOSTS, MAX_INCENTIVES_PER_TOKEN,
+        OPERATORS, PAIR_FEES, PRICES, PROTOCOL_FEES, PROVIDERS, SNAPSHOTS, TOKENS, TOKEN_CAPS,
+    },
 };
 
+// events are emitted as wasm-ionflux-{event_type}
+pub fn get_event(event_type: &str) -> Event {
+    Event::new(format!("ionflux-{}", event_type))
+}
+
+// event with provider asset balances after update
+pub fn get_asset_event(event_type: &str, provider_addr: &Addr, asset: &Asset) -> Event {
+    get_event(event_type).add_attributes(vec![
+        ("provider", provider_addr.to_string()),
+        ("token_addr", asset.token_addr.to_string()),
+        ("bonded", asset.bonded.to_string()),
+        ("unbonded", asset.unbonded.to_string()),
+        ("requested", asset.requested.to_string()),
+        ("unlock_time", asset.counter.nanos().to_string()),
+        ("rewards", asset.rewards.to_string()),
+    ])
+}
+
+// old and new values of updated settings are serialized to json
+pub fn to_json_string<T: serde::Serialize>(value: &T) -> StdResult<String> {
+    Ok(String::from_utf8_lossy(&to_vec(value)?).to_string())
+}
+
+// notifications for hook contracts subscribed to message type
+pub fn get_hook_msg_list(
+    storage: &dyn Storage,
+    hook_msg: &IonFluxHookMsg,
+) -> StdResult<Vec<SubMsg>> {
+    let is_swap = matches!(hook_msg, IonFluxHookMsg::Swap { .. });
+    let mut msg_list: Vec<SubMsg> = vec![];
+
+    for (contract_addr, hook) in HOOKS
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(Addr, Hook)>>>()?
+    {
+        if (is_swap && !hook.on_swap) || (!is_swap && !hook.on_liquidity) {
+            continue;
+        }
+
+        let msg = hook_msg.to_owned().into_wasm_msg(&contract_addr)?;
+
+        msg_list.push(if hook.is_required {
+            SubMsg::new(msg)
+        } else {
+            SubMsg::reply_on_error(msg, HOOK_REPLY_ID)
+        });
+    }
+
+    Ok(msg_list)
+}
+
+// transfer msg for cw20 or native reward token
+fn get_reward_msg(
+    reward_token: &RewardToken,
+    recipient_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    let msg: CosmosMsg = match reward_token {
+        RewardToken::Cw20(token_addr) => WasmMsg::Execute {
+            contract_addr: token_addr.to_string(),
+            msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient_addr.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }
+        .into(),
+        RewardToken::Native(denom) => BankMsg::Send {
+            to_address: recipient_addr.to_string(),
+            amount: coins(amount.u128(), denom),
+        }
+        .into(),
+    };
+
+    Ok(msg)
+}
+
+// returns provider address if sender is provider itself or one of its operators
+fn get_provider_addr(
+    deps: Deps,
+    sender: &Addr,
+    provider_addr: Option<String>,
+) -> Result<Addr, ContractError> {
+    let provider_addr = match provider_addr {
+        Some(x) => deps.api.addr_validate(&x)?,
+        None => return Ok(sender.to_owned()),
+    };
+
+    if &provider_addr == sender {
+        return Ok(provider_addr);
+    }
+
+    let operator_list = OPERATORS
+        .load(deps.storage, &provider_addr)
+        .unwrap_or_default();
+
+    if !operator_list.contains(sender) {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    Ok(provider_addr)
+}
+
+// operators can't redirect funds, only provider itself can specify recipient
+fn get_recipient_addr(
+    deps: Deps,
+    sender: &Addr,
+    provider_addr: &Addr,
+    recipient: Option<String>,
+) -> Result<Addr, ContractError> {
+    match recipient {
+        None => Ok(provider_addr.to_owned()),
+        Some(_) if sender != provider_addr => Err(ContractError::Unauthorized {}),
+        Some(x) => Ok(deps.api.addr_validate(&x)?),
+    }
+}
+
+// returns None if all tokens must be claimed
+fn get_token_addr_list(
+    deps: Deps,
+    token_addr_list: Option<Vec<String>>,
+) -> Result<Option<Vec<Addr>>, ContractError> {
+    let token_addr_list = match token_addr_list {
+        None => return Ok(None),
+        Some(x) => x,
+    };
+
+    let mut res: Vec<Addr> = vec![];
+
+    for token_addr in token_addr_list {
+        res.push(deps.api.addr_validate(&token_addr)?);
+    }
+
+    Ok(Some(res))
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn update_config(
     deps: DepsMut,
@@ -25,8 +179,18 @@ pub fn update_config(
     window: Option<Uint128>,
     unbonding_period: Option<Uint128>,
     price_age: Option<Uint128>,
+    protocol_fee_share: Option<Decimal>,
+    treasury: Option<String>,
+    dynamic_fee: Option<DynamicFee>,
+    max_referral_commission_rate: Option<Decimal>,
+    circuit_breaker: Option<CircuitBreaker>,
+    rate_limit: Option<RateLimit>,
+    epoch: Option<Uint128>,
+    keeper_tip_rate: Option<Decimal>,
 ) -> Result<Response, ContractError> {
-    CONFIG.update(
+    let config_old = CONFIG.load(deps.storage)?;
+
+    let config_new = CONFIG.update(
         deps.storage,
         |mut config| -> Result<Config, ContractError> {
             if info.sender != config.admin {
@@ -53,11 +217,72 @@ pub fn update_config(
                 config.price_age = x;
             }
 
+            if let Some(x) = protocol_fee_share {
+                if x > Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.protocol_fee_share = x;
+            }
+
+            if let Some(x) = treasury {
+                config.treasury = deps.api.addr_validate(&x)?;
+            }
+
+            if let Some(x) = dynamic_fee {
+                if x.min_fee_rate > x.max_fee_rate || x.max_fee_rate >= Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.dynamic_fee = x;
+            }
+
+            if let Some(x) = max_referral_commission_rate {
+                if x >= Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.max_referral_commission_rate = x;
+            }
+
+            if let Some(x) = circuit_breaker {
+                if x.max_price_deviation.is_zero() {
+                    Err(ContractError::CapIsOutOfRange {})?;
+                }
+
+                config.circuit_breaker = x;
+            }
+
+            if let Some(x) = rate_limit {
+                config.rate_limit = x;
+            }
+
+            if let Some(x) = epoch {
+                if x.is_zero() {
+                    Err(ContractError::EpochIsOutOfRange {})?;
+                }
+
+                config.epoch = x;
+            }
+
+            if let Some(x) = keeper_tip_rate {
+                if x >= Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.keeper_tip_rate = x;
+            }
+
             Ok(config)
         },
     )?;
 
-    Ok(Response::new().add_attributes(vec![("action", "update_config")]))
+    Ok(Response::new()
+        .add_event(get_event("update_config").add_attributes(vec![
+            ("config_old", to_json_string(&config_old)?),
+            ("config_new", to_json_string(&config_new)?),
+        ]))
+        .add_attributes(vec![("action", "update_config")]))
 }
 
 pub fn update_token(
@@ -75,9 +300,24 @@ pub fn update_token(
     let token_addr = deps.api.addr_validate(&token_addr)?;
 
     // check if token exists or create new one
-    let token = TOKENS
-        .load(deps.storage, &token_addr)
-        .unwrap_or_else(|_| Token::new(&symbol, &price_feed_id_str));
+    let token_old = TOKENS.may_load(deps.storage, &token_addr)?;
+    let token = token_old
+        .clone()
+        .unwrap_or_else(|| Token::new(&symbol, &price_feed_id_str));
+
+    let mut event = get_event("update_token").add_attributes(vec![
+        ("token_addr", token_addr.to_string()),
+        ("is_new", token_old.is_none().to_string()),
+        ("symbol_new", symbol.clone()),
+        ("price_feed_id_str_new", price_feed_id_str.clone()),
+    ]);
+
+    if let Some(x) = token_old {
+        event = event.add_attributes(vec![
+            ("symbol_old", x.symbol),
+            ("price_feed_id_str_old", x.price_feed_id_str),
+        ]);
+    }
 
     TOKENS.save(
         deps.storage,
@@ -89,7 +329,269 @@ pub fn update_token(
         },
     )?;
 
-    Ok(Response::new().add_attributes(vec![("action", "update_token")]))
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_token")]))
+}
+
+pub fn update_token_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_addr: String,
+    swap_fee_rate: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    if swap_fee_rate.is_some_and(|x| x >= Decimal::one()) {
+        Err(ContractError::FeeRateIsOutOfRange {})?;
+    }
+
+    let token_addr = deps.api.addr_validate(&token_addr)?;
+    let token = TOKENS
+        .load(deps.storage, &token_addr)
+        .map_err(|_| ContractError::TokenIsNotFound {})?;
+
+    let event = get_event("update_token_fee").add_attributes(vec![
+        ("token_addr", token_addr.to_string()),
+        ("swap_fee_rate_old", to_json_string(&token.swap_fee_rate)?),
+        ("swap_fee_rate_new", to_json_string(&swap_fee_rate)?),
+    ]);
+
+    TOKENS.save(
+        deps.storage,
+        &token_addr,
+        &Token {
+            swap_fee_rate,
+            ..token
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_token_fee")]))
+}
+
+pub fn update_token_caps(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_addr: String,
+    token_caps: Option<TokenCaps>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let token_addr = deps.api.addr_validate(&token_addr)?;
+
+    // check if token is supported
+    TOKENS
+        .load(deps.storage, &token_addr)
+        .map_err(|_| ContractError::TokenIsNotFound {})?;
+
+    let event = get_event("update_token_caps").add_attributes(vec![
+        ("token_addr", token_addr.to_string()),
+        (
+            "token_caps_old",
+            to_json_string(&TOKEN_CAPS.may_load(deps.storage, &token_addr)?)?,
+        ),
+        ("token_caps_new", to_json_string(&token_caps)?),
+    ]);
+
+    match token_caps {
+        Some(x) if x.max_swap_out_ratio.is_some_and(|y| y > Decimal::one()) => {
+            Err(ContractError::CapIsOutOfRange {})?
+        }
+        Some(x) => TOKEN_CAPS.save(deps.storage, &token_addr, &x)?,
+        None => TOKEN_CAPS.remove(deps.storage, &token_addr),
+    };
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_token_caps")]))
+}
+
+pub fn update_pair_fee(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_in_addr: String,
+    token_out_addr: String,
+    swap_fee_rate: Option<Decimal>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let token_in_addr = deps.api.addr_validate(&token_in_addr)?;
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+
+    if token_in_addr == token_out_addr {
+        Err(ContractError::SameTokens {})?;
+    }
+
+    // check if tokens are supported
+    for token_addr in [&token_in_addr, &token_out_addr] {
+        TOKENS
+            .load(deps.storage, token_addr)
+            .map_err(|_| ContractError::TokenIsNotFound {})?;
+    }
+
+    let event = get_event("update_pair_fee").add_attributes(vec![
+        ("token_in_addr", token_in_addr.to_string()),
+        ("token_out_addr", token_out_addr.to_string()),
+        (
+            "swap_fee_rate_old",
+            to_json_string(&PAIR_FEES.may_load(deps.storage, (&token_in_addr, &token_out_addr))?)?,
+        ),
+        ("swap_fee_rate_new", to_json_string(&swap_fee_rate)?),
+    ]);
+
+    match swap_fee_rate {
+        Some(x) if x >= Decimal::one() => Err(ContractError::FeeRateIsOutOfRange {})?,
+        Some(x) => PAIR_FEES.save(deps.storage, (&token_in_addr, &token_out_addr), &x)?,
+        None => PAIR_FEES.remove(deps.storage, (&token_in_addr, &token_out_addr)),
+    };
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_pair_fee")]))
+}
+
+// resumes swaps in token, price history is cleared to accept current price level
+pub fn reset_halt(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    token_addr: String,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let token_addr = deps.api.addr_validate(&token_addr)?;
+
+    HALTED_TOKENS.remove(deps.storage, &token_addr);
+    PRICES.remove(deps.storage, &token_addr);
+
+    Ok(Response::new()
+        .add_event(get_event("reset_halt").add_attribute("token_addr", token_addr.as_str()))
+        .add_attributes(vec![
+            ("action", "reset_halt"),
+            ("token_addr", token_addr.as_str()),
+        ]))
+}
+
+pub fn update_fee_discounts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    fee_discount_list: Vec<FeeDiscount>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let mut fee_discount_list_checked: Vec<FeeDiscount> = vec![];
+
+    for fee_discount in fee_discount_list {
+        if fee_discount.fee_multiplier > Decimal::one() {
+            Err(ContractError::FeeRateIsOutOfRange {})?;
+        }
+
+        fee_discount_list_checked.push(FeeDiscount {
+            token_addr: deps.api.addr_validate(fee_discount.token_addr.as_str())?,
+            ..fee_discount
+        });
+    }
+
+    let event = get_event("update_fee_discounts").add_attributes(vec![
+        (
+            "fee_discounts_old",
+            to_json_string(&FEE_DISCOUNTS.may_load(deps.storage)?.unwrap_or_default())?,
+        ),
+        (
+            "fee_discounts_new",
+            to_json_string(&fee_discount_list_checked)?,
+        ),
+    ]);
+
+    FEE_DISCOUNTS.save(deps.storage, &fee_discount_list_checked)?;
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_fee_discounts")]))
+}
+
+pub fn update_lock_boosts(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    lock_boost_list: Vec<LockBoost>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    for (i, lock_boost) in lock_boost_list.iter().enumerate() {
+        if lock_boost.duration.is_zero()
+            || lock_boost.multiplier < Decimal::one()
+            || lock_boost_list[..i]
+                .iter()
+                .any(|x| x.duration == lock_boost.duration)
+        {
+            Err(ContractError::LockBoostIsOutOfRange {})?;
+        }
+    }
+
+    let event = get_event("update_lock_boosts").add_attributes(vec![
+        (
+            "lock_boosts_old",
+            to_json_string(&LOCK_BOOSTS.may_load(deps.storage)?.unwrap_or_default())?,
+        ),
+        ("lock_boosts_new", to_json_string(&lock_boost_list)?),
+    ]);
+
+    LOCK_BOOSTS.save(deps.storage, &lock_boost_list)?;
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_lock_boosts")]))
+}
+
+pub fn update_hook(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    contract_addr: String,
+    hook: Option<Hook>,
+) -> Result<Response, ContractError> {
+    if info.sender != CONFIG.load(deps.storage)?.admin {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let contract_addr = deps.api.addr_validate(&contract_addr)?;
+
+    let event = get_event("update_hook").add_attributes(vec![
+        ("contract_addr", contract_addr.to_string()),
+        (
+            "hook_old",
+            to_json_string(&HOOKS.may_load(deps.storage, &contract_addr)?)?,
+        ),
+        ("hook_new", to_json_string(&hook)?),
+    ]);
+
+    match hook {
+        Some(x) => HOOKS.save(deps.storage, &contract_addr, &x)?,
+        None => HOOKS.remove(deps.storage, &contract_addr),
+    };
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "update_hook")]))
 }
 
 pub fn unbond(
@@ -98,8 +600,9 @@ pub fn unbond(
     info: MessageInfo,
     token_addr: String,
     amount: Uint128,
+    provider_addr: Option<String>,
 ) -> Result<Response, ContractError> {
-    let provider_addr = info.sender;
+    let provider_addr = get_provider_addr(deps.as_ref(), &info.sender, provider_addr)?;
     let token_addr = deps.api.addr_validate(&token_addr)?;
     let timestamp = env.block.time;
     let Config {
@@ -123,6 +626,13 @@ pub fn unbond(
         Err(ContractError::FundsAreNotFound {})?;
     }
 
+    update_incentives(
+        deps.storage,
+        timestamp,
+        &token_addr,
+        std::slice::from_ref(&provider_addr),
+    )?;
+
     let mut provider_updated: Vec<Asset> = vec![];
 
     for asset in provider.iter() {
@@ -135,6 +645,7 @@ pub fn unbond(
             mut counter,
             ..
         } = asset;
+        let mut lock = asset.lock.clone();
 
         // update provider token data
         if !asset.requested.is_zero() && (asset.counter <= timestamp) {
@@ -152,6 +663,15 @@ pub fn unbond(
                 .checked_sub(amount)
                 .map_err(|_| ContractError::WithdrawAmountIsExceeded {})?;
 
+            // active lock must stay covered by bonded amount, expired one is removed
+            lock = lock.filter(|x| x.end > timestamp);
+
+            if let Some(x) = &lock {
+                if bonded < x.amount {
+                    Err(ContractError::BondedAmountIsLocked { end: x.end })?;
+                }
+            }
+
             requested = requested
                 .checked_add(amount)
                 .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
@@ -164,6 +684,7 @@ pub fn unbond(
             requested,
             bonded,
             counter,
+            lock,
             ..asset.to_owned()
         });
 
@@ -195,7 +716,28 @@ pub fn unbond(
 
     PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
 
-    Ok(Response::new().add_attributes(vec![("action", "unbond")]))
+    let mut response = Response::new();
+
+    if let Some(asset) = provider_updated.iter().find(|x| x.token_addr == token_addr) {
+        update_bonded_total(deps.storage, &token_addr, Uint128::zero(), amount)?;
+
+        response = response
+            .add_event(
+                get_asset_event("unbond", &provider_addr, asset)
+                    .add_attribute("amount", amount.to_string()),
+            )
+            .add_submessages(get_hook_msg_list(
+                deps.storage,
+                &IonFluxHookMsg::LiquidityChanged {
+                    action: LiquidityAction::Unbond,
+                    provider: provider_addr.clone(),
+                    amount,
+                    asset: asset.to_owned(),
+                },
+            )?);
+    }
+
+    Ok(response.add_attributes(vec![("action", "unbond")]))
 }
 
 pub fn withdraw(
@@ -280,34 +822,1275 @@ pub fn withdraw(
 
     PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
 
+    // removed asset is shown with zero balances
+    let asset_default = Asset::new(&token_addr, &timestamp);
+    let asset = provider_updated
+        .iter()
+        .find(|x| x.token_addr == token_addr)
+        .unwrap_or(&asset_default);
+
+    let hook_msg_list = get_hook_msg_list(
+        deps.storage,
+        &IonFluxHookMsg::LiquidityChanged {
+            action: LiquidityAction::Withdraw,
+            provider: provider_addr.clone(),
+            amount,
+            asset: asset.to_owned(),
+        },
+    )?;
+
     Ok(Response::new()
         .add_messages(msgs)
+        .add_submessages(hook_msg_list)
+        .add_event(
+            get_asset_event("withdraw", &provider_addr, asset)
+                .add_attribute("amount", amount.to_string()),
+        )
         .add_attributes(vec![("action", "withdraw")]))
 }
 
-pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, ContractError> {
-    let provider_addr = info.sender;
-    let timestamp = env.block.time;
+pub fn create_incentive(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_addr_target: String,
+    reward_asset: RewardAsset,
+    start: Timestamp,
+    end: Timestamp,
+) -> Result<Response, ContractError> {
+    let token_addr_target = deps.api.addr_validate(&token_addr_target)?;
 
-    // check if provider exists or return err
-    let provider = PROVIDERS
-        .load(deps.storage, &provider_addr)
-        .map_err(|_| ContractError::ProviderIsNotFound {})?;
+    // check if token is supported
+    TOKENS
+        .load(deps.storage, &token_addr_target)
+        .map_err(|_| ContractError::TokenIsNotFound {})?;
 
-    // check if provider has any funds in the app
-    if provider.is_empty() {
+    // every incentive is updated on bonded amount changes so anyone can fund
+    // limited amount of them, only admin can exceed the limit
+    let incentive_amount = INCENTIVES
+        .prefix(&token_addr_target)
+        .keys(deps.storage, None, None, Order::Ascending)
+        .take(MAX_INCENTIVES_PER_TOKEN)
+        .count();
+
+    if incentive_amount >= MAX_INCENTIVES_PER_TOKEN
+        && info.sender != CONFIG.load(deps.storage)?.admin
+    {
+        Err(ContractError::IncentivesLimitIsReached {})?;
+    }
+
+    if start < env.block.time || start >= end {
+        Err(ContractError::IncentivePeriodIsOutOfRange {})?;
+    }
+
+    if reward_asset.amount.is_zero() {
         Err(ContractError::FundsAreNotFound {})?;
     }
 
     let mut msgs: Vec<CosmosMsg> = vec![];
-    let mut provider_updated: Vec<Asset> = vec![];
 
-    for asset in provider.iter() {
-        let Asset {
-            mut unbonded,
-            mut requested,
-            mut rewards,
-            ..
+    // cw20 rewards are transferred from creator, native ones must be sent with message
+    let reward_token = match reward_asset.token {
+        RewardToken::Cw20(token_addr) => {
+            let token_addr = deps.api.addr_validate(token_addr.as_str())?;
+
+            // pool token balance is used as liquidity
+            if TOKENS.has(deps.storage, &token_addr) {
+                Err(ContractError::RewardTokenIsPoolToken {})?;
+            }
+
+            let cw_transfer_from_msg = Cw20ExecuteMsg::TransferFrom {
+                owner: info.sender.to_string(),
+                recipient: env.contract.address.to_string(),
+                amount: reward_asset.amount,
+            };
+
+            msgs.push(
+                WasmMsg::Execute {
+                    contract_addr: token_addr.to_string(),
+                    msg: to_binary(&cw_transfer_from_msg)?,
+                    funds: vec![],
+                }
+                .into(),
+            );
+
+            RewardToken::Cw20(token_addr)
+        }
+        RewardToken::Native(denom) => {
+            if info.funds != coins(reward_asset.amount.u128(), &denom) {
+                Err(ContractError::FundsAreNotFound {})?;
+            }
+
+            RewardToken::Native(denom)
+        }
+    };
+
+    let id = INCENTIVE_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    INCENTIVE_COUNTER.save(deps.storage, &id)?;
+
+    let incentive = Incentive {
+        creator: info.sender,
+        token_addr_target,
+        reward_asset: RewardAsset {
+            token: reward_token,
+            amount: reward_asset.amount,
+        },
+        start,
+        end,
+        reward_per_token: Decimal::zero(),
+        last_update: start,
+        distributed: Uint128::zero(),
+        is_closed: false,
+        bonded_unsettled: Uint128::zero(),
+    };
+
+    INCENTIVES.save(deps.storage, (&incentive.token_addr_target, id), &incentive)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_event(get_event("create_incentive").add_attributes(vec![
+            ("id", id.to_string()),
+            ("incentive", to_json_string(&incentive)?),
+        ]))
+        .add_attributes(vec![("action", "create_incentive")]))
+}
+
+// anyone can close finished incentive, amount which wasn't streamed to bonded liquidity
+// is refunded to creator, bonded providers settle final rewards on their next update
+pub fn close_incentive(
+    deps: DepsMut,
+    env: Env,
+    _info: MessageInfo,
+    token_addr_target: String,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let token_addr_target = deps.api.addr_validate(&token_addr_target)?;
+    let timestamp = env.block.time;
+
+    let incentive = INCENTIVES
+        .load(deps.storage, (&token_addr_target, id))
+        .map_err(|_| ContractError::IncentiveIsNotFound {})?;
+
+    if timestamp < incentive.end {
+        Err(ContractError::IncentiveIsActive {})?;
+    }
+
+    if incentive.is_closed {
+        Err(ContractError::IncentiveIsNotFound {})?;
+    }
+
+    // checkpoint incentive only, providers settle rewards lazily on their next update
+    update_incentives(deps.storage, timestamp, &token_addr_target, &[])?;
+
+    let bonded_total = BONDED_TOTALS
+        .may_load(deps.storage, &token_addr_target)?
+        .unwrap_or_default();
+    let incentive = Incentive {
+        is_closed: true,
+        bonded_unsettled: bonded_total,
+        ..INCENTIVES.load(deps.storage, (&token_addr_target, id))?
+    };
+
+    if bonded_total.is_zero() {
+        INCENTIVES.remove(deps.storage, (&token_addr_target, id));
+    } else {
+        INCENTIVES.save(deps.storage, (&token_addr_target, id), &incentive)?;
+    }
+
+    let refund = incentive.reward_asset.amount - incentive.distributed;
+    let mut response = Response::new();
+
+    if !refund.is_zero() {
+        response = response.add_message(get_reward_msg(
+            &incentive.reward_asset.token,
+            &incentive.creator,
+            refund,
+        )?);
+    }
+
+    Ok(response
+        .add_event(get_event("close_incentive").add_attributes(vec![
+            ("id", id.to_string()),
+            ("creator", incentive.creator.to_string()),
+            ("distributed", incentive.distributed.to_string()),
+            ("refund", refund.to_string()),
+        ]))
+        .add_attributes(vec![("action", "close_incentive")]))
+}
+
+fn remove_limit_order(storage: &mut dyn Storage, id: u64, order: &LimitOrder) -> StdResult<()> {
+    LIMIT_ORDERS.remove(storage, id);
+    LIMIT_ORDERS_BY_OWNER.remove(storage, (&order.owner, id));
+    LIMIT_ORDERS_BY_PAIR.remove(storage, (&order.token_in_addr, &order.token_out_addr, id));
+    ESCROWED.update(
+        storage,
+        &order.token_in_addr,
+        |some_amount| -> StdResult<Uint128> {
+            Ok(some_amount
+                .unwrap_or_default()
+                .checked_sub(order.amount_in)?)
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn get_cw20_transfer_msg(
+    token_addr: &Addr,
+    recipient_addr: &Addr,
+    amount: Uint128,
+) -> StdResult<CosmosMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: token_addr.to_string(),
+        msg: to_binary(&Cw20ExecuteMsg::Transfer {
+            recipient: recipient_addr.to_string(),
+            amount,
+        })?,
+        funds: vec![],
+    }
+    .into())
+}
+
+pub fn cancel_order(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let order = LIMIT_ORDERS
+        .load(deps.storage, id)
+        .map_err(|_| ContractError::OrderIsNotFound {})?;
+
+    if info.sender != order.owner {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    remove_limit_order(deps.storage, id, &order)?;
+
+    Ok(Response::new()
+        .add_message(get_cw20_transfer_msg(
+            &order.token_in_addr,
+            &order.owner,
+            order.amount_in,
+        )?)
+        .add_event(get_event("cancel_order").add_attributes(vec![
+            ("id", id.to_string()),
+            ("owner", order.owner.to_string()),
+            ("amount_in", order.amount_in.to_string()),
+        ]))
+        .add_attributes(vec![("action", "cancel_order")]))
+}
+
+// anyone can fill orders which price condition holds, executor gets keeper tip from amount_in
+// expired orders are refunded to owners, orders which can't be filled yet are skipped
+pub fn execute_orders(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id_list: Vec<u64>,
+) -> Result<Response, ContractError> {
+    let timestamp = env.block.time;
+    let Config {
+        keeper_tip_rate, ..
+    } = CONFIG.load(deps.storage)?;
+    let price_list =
+        query_prices_any(deps.as_ref(), env.clone()).map_err(|_| ContractError::NoPrices {})?;
+
+    let get_price = |token_addr: &Addr| -> Result<Decimal, ContractError> {
+        price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, price)| *price)
+            .ok_or(ContractError::TokenIsNotFound {})
+    };
+
+    let mut response = Response::new();
+    let mut executed_count: u32 = 0;
+
+    for id in id_list {
+        let order = match LIMIT_ORDERS.may_load(deps.storage, id)? {
+            Some(x) => x,
+            None => continue,
+        };
+
+        if order.expiry <= timestamp {
+            remove_limit_order(deps.storage, id, &order)?;
+            executed_count += 1;
+
+            response = response
+                .add_message(get_cw20_transfer_msg(
+                    &order.token_in_addr,
+                    &order.owner,
+                    order.amount_in,
+                )?)
+                .add_event(get_event("expire_order").add_attributes(vec![
+                    ("id", id.to_string()),
+                    ("owner", order.owner.to_string()),
+                    ("amount_in", order.amount_in.to_string()),
+                ]));
+
+            continue;
+        }
+
+        let token_in_price = get_price(&order.token_in_addr)?;
+        let token_out_price = get_price(&order.token_out_addr)?;
+
+        if token_in_price / token_out_price < order.min_price {
+            continue;
+        }
+
+        let keeper_tip = (keeper_tip_rate * u128_to_dec(order.amount_in)).to_uint_floor();
+
+        // failed order is skipped, its writes are dropped with the cache
+        let mut cache = StorageCache::new(deps.storage);
+        let swap_result = remove_limit_order(&mut cache, id, &order)
+            .map_err(ContractError::from)
+            .and_then(|_| {
+                // escrowed amount_in is swapped as if it was just sent by owner
+                swap_accepting_prices(
+                    DepsMut {
+                        storage: &mut cache,
+                        api: deps.api,
+                        querier: deps.querier,
+                    },
+                    env.clone(),
+                    MessageInfo {
+                        sender: order.token_in_addr.clone(),
+                        funds: vec![],
+                    },
+                    order.owner.to_string(),
+                    order.amount_in - keeper_tip,
+                    order.token_out_addr.to_string(),
+                    token_in_price,
+                    token_out_price,
+                    None,
+                    None,
+                )
+            });
+
+        let (swap_response, amount_out) = match swap_result {
+            Ok(x) => {
+                let change_map = cache.into_changes();
+                StorageCache::commit(deps.storage, change_map);
+                x
+            }
+            Err(error) => {
+                response = response.add_event(get_event("skip_order").add_attributes(vec![
+                    ("id", id.to_string()),
+                    ("owner", order.owner.to_string()),
+                    ("error", error.to_string()),
+                ]));
+
+                continue;
+            }
+        };
+
+        executed_count += 1;
+
+        // tripped circuit breaker refunds the order, keeper tip is returned to owner as well
+        if amount_out.is_zero() {
+            if !keeper_tip.is_zero() {
+                response = response.add_message(get_cw20_transfer_msg(
+                    &order.token_in_addr,
+                    &order.owner,
+                    keeper_tip,
+                )?);
+            }
+
+            response = response
+                .add_submessages(swap_response.messages)
+                .add_events(swap_response.events)
+                .add_event(get_event("refund_order").add_attributes(vec![
+                    ("id", id.to_string()),
+                    ("owner", order.owner.to_string()),
+                    ("amount_in", order.amount_in.to_string()),
+                ]));
+
+            continue;
+        }
+
+        if !keeper_tip.is_zero() {
+            response = response.add_message(get_cw20_transfer_msg(
+                &order.token_in_addr,
+                &info.sender,
+                keeper_tip,
+            )?);
+        }
+
+        response = response
+            .add_submessages(swap_response.messages)
+            .add_events(swap_response.events)
+            .add_event(get_event("execute_order").add_attributes(vec![
+                ("id", id.to_string()),
+                ("owner", order.owner.to_string()),
+                ("keeper", info.sender.to_string()),
+                ("keeper_tip", keeper_tip.to_string()),
+            ]));
+    }
+
+    if executed_count == 0 {
+        Err(ContractError::NothingToExecute {})?;
+    }
+
+    Ok(response.add_attributes(vec![
+        ("action", "execute_orders"),
+        ("executed_count", &executed_count.to_string()),
+    ]))
+}
+
+fn remove_dca(storage: &mut dyn Storage, id: u64, schedule: &DcaSchedule) -> StdResult<()> {
+    DCA_SCHEDULES.remove(storage, id);
+    DCA_SCHEDULES_BY_OWNER.remove(storage, (&schedule.owner, id));
+    ESCROWED.update(
+        storage,
+        &schedule.token_in_addr,
+        |some_amount| -> StdResult<Uint128> {
+            Ok(some_amount
+                .unwrap_or_default()
+                .checked_sub(schedule.balance)?)
+        },
+    )?;
+
+    Ok(())
+}
+
+pub fn cancel_dca(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    let schedule = DCA_SCHEDULES
+        .load(deps.storage, id)
+        .map_err(|_| ContractError::DcaIsNotFound {})?;
+
+    if info.sender != schedule.owner {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    remove_dca(deps.storage, id, &schedule)?;
+
+    Ok(Response::new()
+        .add_message(get_cw20_transfer_msg(
+            &schedule.token_in_addr,
+            &schedule.owner,
+            schedule.balance,
+        )?)
+        .add_event(get_event("cancel_dca").add_attributes(vec![
+            ("id", id.to_string()),
+            ("owner", schedule.owner.to_string()),
+            ("refund", schedule.balance.to_string()),
+        ]))
+        .add_attributes(vec![("action", "cancel_dca")]))
+}
+
+// anyone can execute due swaps, executor gets keeper tip from swapped amount
+// schedules losing more than slippage to swap fee and keeper tip are skipped
+// failed runs and runs halted by circuit breaker are skipped, spent schedules are removed
+pub fn execute_dca(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    id_list: Vec<u64>,
+) -> Result<Response, ContractError> {
+    let timestamp = env.block.time;
+    let Config {
+        keeper_tip_rate, ..
+    } = CONFIG.load(deps.storage)?;
+    let price_list =
+        query_prices_any(deps.as_ref(), env.clone()).map_err(|_| ContractError::NoPrices {})?;
+
+    let get_price = |token_addr: &Addr| -> Result<Decimal, ContractError> {
+        price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, price)| *price)
+            .ok_or(ContractError::TokenIsNotFound {})
+    };
+
+    let mut response = Response::new();
+    let mut executed_count: u32 = 0;
+    let mut is_halted = false;
+
+    for id in id_list {
+        let schedule = match DCA_SCHEDULES.may_load(deps.storage, id)? {
+            Some(x) if x.next_execution <= timestamp => x,
+            _ => continue,
+        };
+
+        // the same fee rate is applied in swap
+        let swap_fee_rate =
+            query_swap_fee_rate(
+                deps.as_ref(),
+                env.clone(),
+                schedule.token_in_addr.to_string(),
+                schedule.token_out_addr.to_string(),
+            )? * query_fee_multiplier(deps.as_ref(), env.clone(), schedule.owner.to_string())?;
+
+        let amount_in = schedule.amount_per_interval.min(schedule.balance);
+        let keeper_tip = (keeper_tip_rate * u128_to_dec(amount_in)).to_uint_floor();
+
+        // keeper tip is taken before swap, so it's a loss along with swap fee
+        let loss = u128_to_dec(keeper_tip) + swap_fee_rate * u128_to_dec(amount_in - keeper_tip);
+
+        if loss > schedule.slippage * u128_to_dec(amount_in) {
+            continue;
+        }
+
+        let token_in_price = get_price(&schedule.token_in_addr)?;
+        let token_out_price = get_price(&schedule.token_out_addr)?;
+
+        // failed run is skipped, its writes are dropped with the cache
+        let mut cache = StorageCache::new(deps.storage);
+        let swap_result = remove_dca(&mut cache, id, &schedule)
+            .map_err(ContractError::from)
+            .and_then(|_| {
+                swap_accepting_prices(
+                    DepsMut {
+                        storage: &mut cache,
+                        api: deps.api,
+                        querier: deps.querier,
+                    },
+                    env.clone(),
+                    MessageInfo {
+                        sender: schedule.token_in_addr.clone(),
+                        funds: vec![],
+                    },
+                    schedule.owner.to_string(),
+                    amount_in - keeper_tip,
+                    schedule.token_out_addr.to_string(),
+                    token_in_price,
+                    token_out_price,
+                    None,
+                    None,
+                )
+            });
+
+        let (swap_response, amount_out) = match swap_result {
+            Ok(x) => {
+                let change_map = cache.into_changes();
+                StorageCache::commit(deps.storage, change_map);
+                x
+            }
+            Err(error) => {
+                response = response.add_event(get_event("skip_dca").add_attributes(vec![
+                    ("id", id.to_string()),
+                    ("owner", schedule.owner.to_string()),
+                    ("error", error.to_string()),
+                ]));
+
+                continue;
+            }
+        };
+
+        // tripped circuit breaker skips the run, refund is dropped and schedule is restored
+        // halt flag is kept, so the call must not fail after it
+        if amount_out.is_zero() {
+            DCA_SCHEDULES.save(deps.storage, id, &schedule)?;
+            DCA_SCHEDULES_BY_OWNER.save(deps.storage, (&schedule.owner, id), &Empty {})?;
+            ESCROWED.update(
+                deps.storage,
+                &schedule.token_in_addr,
+                |some_amount| -> StdResult<Uint128> {
+                    Ok(some_amount.unwrap_or_default() + schedule.balance)
+                },
+            )?;
+            is_halted = true;
+
+            response = response.add_events(swap_response.events).add_event(
+                get_event("skip_dca").add_attributes(vec![
+                    ("id", id.to_string()),
+                    ("owner", schedule.owner.to_string()),
+                    ("error", ContractError::TokenIsHalted {}.to_string()),
+                ]),
+            );
+
+            continue;
+        }
+
+        executed_count += 1;
+
+        if !keeper_tip.is_zero() {
+            response = response.add_message(get_cw20_transfer_msg(
+                &schedule.token_in_addr,
+                &info.sender,
+                keeper_tip,
+            )?);
+        }
+
+        let schedule_updated = DcaSchedule {
+            balance: schedule.balance - amount_in,
+            next_execution: timestamp.plus_nanos(schedule.interval.u128() as u64),
+            executed_count: schedule.executed_count + 1,
+            amount_in_total: schedule.amount_in_total + amount_in,
+            amount_out_total: schedule.amount_out_total + amount_out,
+            ..schedule
+        };
+
+        // escrow is restored for remaining balance
+        if !schedule_updated.balance.is_zero() {
+            DCA_SCHEDULES.save(deps.storage, id, &schedule_updated)?;
+            DCA_SCHEDULES_BY_OWNER.save(deps.storage, (&schedule_updated.owner, id), &Empty {})?;
+            ESCROWED.update(
+                deps.storage,
+                &schedule_updated.token_in_addr,
+                |some_amount| -> StdResult<Uint128> {
+                    Ok(some_amount.unwrap_or_default() + schedule_updated.balance)
+                },
+            )?;
+        }
+
+        response = response
+            .add_submessages(swap_response.messages)
+            .add_events(swap_response.events)
+            .add_event(get_event("execute_dca").add_attributes(vec![
+                ("id", id.to_string()),
+                ("keeper", info.sender.to_string()),
+                ("keeper_tip", keeper_tip.to_string()),
+                ("schedule", to_json_string(&schedule_updated)?),
+            ]));
+    }
+
+    if executed_count == 0 && !is_halted {
+        Err(ContractError::NothingToExecute {})?;
+    }
+
+    Ok(response.add_attributes(vec![
+        ("action", "execute_dca"),
+        ("executed_count", &executed_count.to_string()),
+    ]))
+}
+
+pub fn claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    provider_addr: Option<String>,
+    recipient: Option<String>,
+    token_addr_list: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let provider_addr = get_provider_addr(deps.as_ref(), &info.sender, provider_addr)?;
+    let recipient_addr =
+        get_recipient_addr(deps.as_ref(), &info.sender, &provider_addr, recipient)?;
+    let token_addr_list = get_token_addr_list(deps.as_ref(), token_addr_list)?;
+    let timestamp = env.block.time;
+
+    // check if provider exists or return err
+    let provider = PROVIDERS
+        .load(deps.storage, &provider_addr)
+        .map_err(|_| ContractError::ProviderIsNotFound {})?;
+
+    // check if provider has any funds in the app
+    if provider.is_empty() {
+        Err(ContractError::FundsAreNotFound {})?;
+    }
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+    let mut event_list: Vec<Event> = vec![];
+    let mut provider_updated: Vec<Asset> = vec![];
+
+    for asset in provider.iter() {
+        let Asset {
+            mut unbonded,
+            mut requested,
+            mut rewards,
+            ..
+        } = asset;
+
+        // update provider token data
+        if !asset.requested.is_zero() && (asset.counter <= timestamp) {
+            unbonded = unbonded
+                .checked_add(requested)
+                .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+            requested = Uint128::zero();
+        }
+
+        let is_claimed = token_addr_list
+            .as_ref()
+            .is_none_or(|x| x.contains(&asset.token_addr));
+
+        if !asset.rewards.is_zero() && is_claimed {
+            rewards = Uint128::zero();
+
+            let cw_send_msg = Cw20ExecuteMsg::Transfer {
+                recipient: recipient_addr.to_string(),
+                amount: asset.rewards,
+            };
+
+            let msg = WasmMsg::Execute {
+                contract_addr: asset.token_addr.to_string(),
+                msg: to_binary(&cw_send_msg)?,
+                funds: vec![],
+            };
+
+            let asset_updated = Asset {
+                unbonded,
+                requested,
+                rewards,
+                ..asset.to_owned()
+            };
+
+            msgs.push(msg.into());
+            event_list.push(
+                get_asset_event("claim", &provider_addr, &asset_updated).add_attributes(vec![
+                    ("recipient", recipient_addr.to_string()),
+                    ("amount", asset.rewards.to_string()),
+                ]),
+            );
+            hook_msg_list.append(&mut get_hook_msg_list(
+                deps.storage,
+                &IonFluxHookMsg::LiquidityChanged {
+                    action: LiquidityAction::Claim,
+                    provider: provider_addr.clone(),
+                    amount: asset.rewards,
+                    asset: asset_updated,
+                },
+            )?);
+        };
+
+        // remove asset from list if there are no balances
+        if unbonded.is_zero()
+            && requested.is_zero()
+            && asset.bonded.is_zero()
+            && asset.rewards.is_zero()
+        {
+            continue;
+        }
+
+        provider_updated.push(Asset {
+            unbonded,
+            requested,
+            rewards,
+            ..asset.to_owned()
+        });
+    }
+
+    // claim incentive rewards of selected target tokens
+    for asset in provider.iter().filter(|asset| {
+        token_addr_list
+            .as_ref()
+            .is_none_or(|x| x.contains(&asset.token_addr))
+    }) {
+        update_incentives(
+            deps.storage,
+            timestamp,
+            &asset.token_addr,
+            std::slice::from_ref(&provider_addr),
+        )?;
+    }
+
+    let incentive_reward_list = INCENTIVE_REWARDS
+        .prefix(&provider_addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, IncentiveReward)>>>()?
+        .into_iter()
+        .filter(|(_, x)| {
+            token_addr_list
+                .as_ref()
+                .is_none_or(|y| y.contains(&x.token_addr_target))
+        });
+
+    for (id, incentive_reward) in incentive_reward_list {
+        if INCENTIVES.has(deps.storage, (&incentive_reward.token_addr_target, id)) {
+            INCENTIVE_REWARDS.save(
+                deps.storage,
+                (&provider_addr, id),
+                &IncentiveReward {
+                    amount: Uint128::zero(),
+                    ..incentive_reward.clone()
+                },
+            )?;
+        } else {
+            INCENTIVE_REWARDS.remove(deps.storage, (&provider_addr, id));
+        }
+
+        if incentive_reward.amount.is_zero() {
+            continue;
+        }
+
+        msgs.push(get_reward_msg(
+            &incentive_reward.reward_token,
+            &recipient_addr,
+            incentive_reward.amount,
+        )?);
+        event_list.push(get_event("claim_incentive").add_attributes(vec![
+            ("id", id.to_string()),
+            ("provider", provider_addr.to_string()),
+            ("recipient", recipient_addr.to_string()),
+            ("amount", incentive_reward.amount.to_string()),
+        ]));
+    }
+
+    if msgs.is_empty() {
+        Err(ContractError::NothingToClaim {})?;
+    }
+
+    PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
+        .add_attributes(vec![("action", "claim")]))
+}
+
+// all legs are priced by single price query, every leg must satisfy its min_amount_out
+// leg halted by circuit breaker is refunded
+pub fn batch_swap(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    swap_list: Vec<SwapLeg>,
+    referral: Option<Referral>,
+) -> Result<Response, ContractError> {
+    if swap_list.is_empty() {
+        Err(ContractError::SwapListIsEmpty {})?;
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let price_list = if env.block.chain_id != config.get_chain_id() {
+        query_prices(deps.as_ref(), env.clone(), vec![])
+    } else {
+        query_prices_mocked(deps.as_ref(), env.clone(), vec![])
+    }
+    .map_err(|_| ContractError::NoPrices {})?;
+
+    let get_price = |token_addr: &Addr| -> Result<Decimal, ContractError> {
+        price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, price)| *price)
+            .ok_or(ContractError::TokenIsNotFound {})
+    };
+
+    // pool has cw20 liquidity only, so there is nothing to swap native funds for
+    if !info.funds.is_empty() {
+        Err(ContractError::NativeFundsAreNotAccepted {})?;
+    }
+
+    // allowances are validated for all legs before any swap
+    let mut amount_in_list: Vec<(Addr, Uint128)> = vec![];
+
+    for swap_leg in &swap_list {
+        let token_in_addr = deps.api.addr_validate(&swap_leg.token_in_addr)?;
+
+        match amount_in_list
+            .iter_mut()
+            .find(|(addr, _)| addr == &token_in_addr)
+        {
+            Some((_, amount_in)) => *amount_in += swap_leg.amount_in,
+            None => amount_in_list.push((token_in_addr, swap_leg.amount_in)),
+        }
+    }
+
+    for (token_addr, amount_in) in amount_in_list {
+        let AllowanceResponse { allowance, .. } = deps.querier.query_wasm_smart(
+            &token_addr,
+            &Cw20QueryMsg::Allowance {
+                owner: info.sender.to_string(),
+                spender: env.contract.address.to_string(),
+            },
+        )?;
+
+        if allowance < amount_in {
+            Err(ContractError::AllowanceIsNotEnough {
+                token_addr,
+                amount_in,
+            })?;
+        }
+    }
+
+    // transfers from sender must precede transfers to sender
+    let mut transfer_msg_list: Vec<CosmosMsg> = vec![];
+    let mut response = Response::new();
+
+    for swap_leg in &swap_list {
+        let token_in_addr = deps.api.addr_validate(&swap_leg.token_in_addr)?;
+        let token_out_addr = deps.api.addr_validate(&swap_leg.token_out_addr)?;
+
+        transfer_msg_list.push(
+            WasmMsg::Execute {
+                contract_addr: token_in_addr.to_string(),
+                msg: to_binary(&Cw20ExecuteMsg::TransferFrom {
+                    owner: info.sender.to_string(),
+                    recipient: env.contract.address.to_string(),
+                    amount: swap_leg.amount_in,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        );
+
+        let (swap_response, amount_out) = swap_accepting_prices(
+            deps.branch(),
+            env.clone(),
+            MessageInfo {
+                sender: token_in_addr.clone(),
+                funds: vec![],
+            },
+            info.sender.to_string(),
+            swap_leg.amount_in,
+            token_out_addr.to_string(),
+            get_price(&token_in_addr)?,
+            get_price(&token_out_addr)?,
+            referral.clone(),
+            None,
+        )?;
+
+        // tripped circuit breaker refunds the leg, halt flag must be kept
+        if !amount_out.is_zero() && amount_out < swap_leg.min_amount_out {
+            Err(ContractError::AmountOutIsTooLow {
+                amount_out,
+                min_amount_out: swap_leg.min_amount_out,
+            })?;
+        }
+
+        response = response
+            .add_submessages(swap_response.messages)
+            .add_events(swap_response.events);
+    }
+
+    Ok(Response::new()
+        .add_messages(transfer_msg_list)
+        .add_submessages(response.messages)
+        .add_events(response.events)
+        .add_attributes(vec![
+            ("action", "batch_swap"),
+            ("swap_count", &swap_list.len().to_string()),
+        ]))
+}
+
+pub fn swap_and_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_out_addr: String,
+    provider_addr: Option<String>,
+    recipient: Option<String>,
+    token_addr_list: Option<Vec<String>>,
+) -> Result<Response, ContractError> {
+    let provider_addr = get_provider_addr(deps.as_ref(), &info.sender, provider_addr)?;
+    let recipient_addr =
+        get_recipient_addr(deps.as_ref(), &info.sender, &provider_addr, recipient)?;
+    let token_addr_list = get_token_addr_list(deps.as_ref(), token_addr_list)?;
+
+    let price_list = if env.block.chain_id != CONFIG.load(deps.storage)?.get_chain_id() {
+        query_prices(deps.as_ref(), env.clone(), vec![])
+    } else {
+        query_prices_mocked(deps.as_ref(), env.clone(), vec![])
+    }
+    .map_err(|_| ContractError::NoPrices {})?;
+
+    swap_and_claim_accepting_prices(
+        deps,
+        env,
+        provider_addr,
+        recipient_addr,
+        token_out_addr,
+        token_addr_list,
+        price_list,
+    )
+}
+
+fn swap_and_claim_accepting_prices(
+    deps: DepsMut,
+    env: Env,
+    provider_addr: Addr,
+    recipient_addr: Addr,
+    token_out_addr: String,
+    token_addr_list: Option<Vec<Addr>>,
+    price_list: Vec<(Addr, Decimal)>,
+) -> Result<Response, ContractError> {
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+    let timestamp = env.block.time;
+    let Config {
+        window,
+        circuit_breaker,
+        ..
+    } = CONFIG.load(deps.storage)?;
+
+    let (_, token_out_price) = price_list
+        .iter()
+        .find(|x| x.0 == token_out_addr)
+        .ok_or(ContractError::TokenIsNotFound {})?;
+
+    // check if provider exists or return err
+    let provider = PROVIDERS
+        .load(deps.storage, &provider_addr)
+        .map_err(|_| ContractError::ProviderIsNotFound {})?;
+
+    // check if provider has any funds in the app
+    if provider.is_empty() {
+        Err(ContractError::FundsAreNotFound {})?;
+    }
+
+    let is_claimed = |asset: &Asset| {
+        token_addr_list
+            .as_ref()
+            .is_none_or(|x| x.contains(&asset.token_addr))
+    };
+
+    // tripped circuit breaker keeps rewards unclaimed
+    let event_list = check_conversion(
+        deps.storage,
+        timestamp,
+        window,
+        &circuit_breaker,
+        &provider,
+        is_claimed,
+        &token_out_addr,
+        &price_list,
+    )?;
+
+    if !event_list.is_empty() {
+        return Ok(Response::new()
+            .add_events(event_list)
+            .add_attributes(vec![("action", "swap_and_claim"), ("is_halted", "true")]));
+    }
+
+    let mut token_out_cost = Decimal::zero();
+    let mut provider_updated: Vec<Asset> = vec![];
+
+    for asset in provider.iter() {
+        let Asset {
+            mut unbonded,
+            mut requested,
+            mut rewards,
+            ..
+        } = asset;
+
+        // update provider token data
+        if !asset.requested.is_zero() && (asset.counter <= timestamp) {
+            unbonded = unbonded
+                .checked_add(requested)
+                .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+            requested = Uint128::zero();
+        }
+
+        if !asset.rewards.is_zero() && is_claimed(asset) {
+            rewards = Uint128::zero();
+
+            let (_, token_in_price) = price_list
+                .iter()
+                .find(|x| x.0 == asset.token_addr)
+                .ok_or(ContractError::TokenIsNotFound {})?;
+
+            token_out_cost += token_in_price * u128_to_dec(asset.rewards);
+        };
+
+        // remove asset from list if there are no balances
+        if unbonded.is_zero()
+            && requested.is_zero()
+            && asset.bonded.is_zero()
+            && asset.rewards.is_zero()
+        {
+            continue;
+        }
+
+        provider_updated.push(Asset {
+            unbonded,
+            requested,
+            rewards,
+            ..asset.to_owned()
+        });
+    }
+
+    if token_out_cost.is_zero() {
+        Err(ContractError::NothingToClaim {})?;
+    }
+
+    PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
+
+    let amount_out = (token_out_cost / token_out_price).to_uint_floor();
+
+    let cw_send_msg = Cw20ExecuteMsg::Transfer {
+        recipient: recipient_addr.to_string(),
+        amount: amount_out,
+    };
+
+    let msg = WasmMsg::Execute {
+        contract_addr: token_out_addr.to_string(),
+        msg: to_binary(&cw_send_msg)?,
+        funds: vec![],
+    };
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_event(get_event("swap_and_claim").add_attributes(vec![
+            ("provider", provider_addr.to_string()),
+            ("recipient", recipient_addr.to_string()),
+            ("token_out_addr", token_out_addr.to_string()),
+            ("token_out_price", token_out_price.to_string()),
+            ("rewards_value", token_out_cost.to_string()),
+            ("amount_out", amount_out.to_string()),
+        ]))
+        .add_attributes(vec![("action", "swap_and_claim")]))
+}
+
+// rewards converted to token_out are checked by circuit breaker as swapped tokens
+#[allow(clippy::too_many_arguments)]
+fn check_conversion(
+    storage: &mut dyn Storage,
+    timestamp: Timestamp,
+    window: Uint128,
+    circuit_breaker: &CircuitBreaker,
+    asset_list: &[Asset],
+    is_converted: impl Fn(&Asset) -> bool,
+    token_out_addr: &Addr,
+    price_list: &[(Addr, Decimal)],
+) -> Result<Vec<Event>, ContractError> {
+    let mut token_price_list: Vec<(Addr, Decimal)> = vec![];
+
+    for token_addr in asset_list
+        .iter()
+        .filter(|x| !x.rewards.is_zero() && is_converted(x))
+        .map(|x| &x.token_addr)
+        .chain([token_out_addr])
+    {
+        let (_, price) = price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .ok_or(ContractError::TokenIsNotFound {})?;
+
+        token_price_list.push((token_addr.to_owned(), *price));
+    }
+
+    check_circuit_breaker(
+        storage,
+        timestamp,
+        window,
+        circuit_breaker,
+        &token_price_list,
+    )
+}
+
+pub fn compound(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    token_out_addr: Option<String>,
+    provider_addr: Option<String>,
+) -> Result<Response, ContractError> {
+    let provider_addr = get_provider_addr(deps.as_ref(), &info.sender, provider_addr)?;
+
+    // prices are required only to convert rewards to single token
+    let price_list = match token_out_addr {
+        None => vec![],
+        Some(_) => if env.block.chain_id != CONFIG.load(deps.storage)?.get_chain_id() {
+            query_prices(deps.as_ref(), env.clone(), vec![])
+        } else {
+            query_prices_mocked(deps.as_ref(), env.clone(), vec![])
+        }
+        .map_err(|_| ContractError::NoPrices {})?,
+    };
+
+    compound_accepting_prices(deps, env, provider_addr, token_out_addr, price_list)
+}
+
+fn compound_accepting_prices(
+    deps: DepsMut,
+    env: Env,
+    provider_addr: Addr,
+    token_out_addr: Option<String>,
+    price_list: Vec<(Addr, Decimal)>,
+) -> Result<Response, ContractError> {
+    let token_out_addr = token_out_addr
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+    let timestamp = env.block.time;
+    let Config {
+        window,
+        circuit_breaker,
+        ..
+    } = CONFIG.load(deps.storage)?;
+
+    let token_out_price = match &token_out_addr {
+        None => None,
+        Some(x) => Some(
+            price_list
+                .iter()
+                .find(|(addr, _)| addr == x)
+                .ok_or(ContractError::TokenIsNotFound {})?
+                .1,
+        ),
+    };
+
+    // check if provider exists or return err
+    let provider = PROVIDERS
+        .load(deps.storage, &provider_addr)
+        .map_err(|_| ContractError::ProviderIsNotFound {})?;
+
+    // check if provider has any funds in the app
+    if provider.is_empty() {
+        Err(ContractError::FundsAreNotFound {})?;
+    }
+
+    // tripped circuit breaker keeps rewards uncompounded
+    if let Some(token_out_addr) = &token_out_addr {
+        let event_list = check_conversion(
+            deps.storage,
+            timestamp,
+            window,
+            &circuit_breaker,
+            &provider,
+            |_| true,
+            token_out_addr,
+            &price_list,
+        )?;
+
+        if !event_list.is_empty() {
+            return Ok(Response::new()
+                .add_events(event_list)
+                .add_attributes(vec![("action", "compound"), ("is_halted", "true")]));
+        }
+    }
+
+    let get_price = |token_addr: &Addr| -> Result<Decimal, ContractError> {
+        price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, price)| *price)
+            .ok_or(ContractError::TokenIsNotFound {})
+    };
+
+    // compounded amounts are limited by deposit caps, excess is kept as rewards
+    let (token_out_room, convert_ratio) = match (&token_out_addr, token_out_price) {
+        (Some(token_out_addr), Some(token_out_price)) => {
+            let room = get_deposit_room(deps.as_ref(), env.clone(), token_out_addr)?;
+            let mut cost = Decimal::zero();
+
+            for asset in provider.iter().filter(|x| !x.rewards.is_zero()) {
+                cost += get_price(&asset.token_addr)? * u128_to_dec(asset.rewards);
+            }
+
+            let amount = (cost / token_out_price).to_uint_floor();
+            let ratio = match room {
+                Some(room) if room < amount => Decimal::from_ratio(room, amount),
+                _ => Decimal::one(),
+            };
+
+            (room, ratio)
+        }
+        _ => (None, Decimal::one()),
+    };
+
+    let mut token_out_cost = Decimal::zero();
+    let mut compounded_list: Vec<(Addr, Uint128)> = vec![];
+    let mut provider_updated: Vec<Asset> = vec![];
+
+    for asset in provider.iter() {
+        let Asset {
+            mut unbonded,
+            mut requested,
+            mut bonded,
+            mut rewards,
+            ..
         } = asset;
 
         // update provider token data
@@ -320,20 +2103,26 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Con
         }
 
         if !asset.rewards.is_zero() {
-            rewards = Uint128::zero();
-
-            let cw_send_msg = Cw20ExecuteMsg::Transfer {
-                recipient: provider_addr.to_string(),
-                amount: asset.rewards,
-            };
-
-            let msg = WasmMsg::Execute {
-                contract_addr: asset.token_addr.to_string(),
-                msg: to_binary(&cw_send_msg)?,
-                funds: vec![],
-            };
-
-            msgs.push(msg.into());
+            if token_out_price.is_some() {
+                let converted = (convert_ratio * u128_to_dec(asset.rewards))
+                    .to_uint_ceil()
+                    .min(asset.rewards);
+                rewards = asset.rewards - converted;
+
+                token_out_cost += get_price(&asset.token_addr)? * u128_to_dec(converted);
+            } else {
+                let compounded = get_deposit_room(deps.as_ref(), env.clone(), &asset.token_addr)?
+                    .map_or(asset.rewards, |room| asset.rewards.min(room));
+                rewards = asset.rewards - compounded;
+
+                if !compounded.is_zero() {
+                    bonded = bonded
+                        .checked_add(compounded)
+                        .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+                    compounded_list.push((asset.token_addr.clone(), compounded));
+                }
+            }
         };
 
         // remove asset from list if there are no balances
@@ -348,53 +2137,187 @@ pub fn claim(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, Con
         provider_updated.push(Asset {
             unbonded,
             requested,
+            bonded,
             rewards,
             ..asset.to_owned()
         });
     }
 
-    if msgs.is_empty() {
+    // bond converted rewards as token_out
+    if let (Some(token_out_addr), Some(token_out_price)) = (token_out_addr, token_out_price) {
+        let amount = (token_out_cost / token_out_price).to_uint_floor();
+        let amount = token_out_room.map_or(amount, |room| amount.min(room));
+
+        if !amount.is_zero() {
+            if !provider_updated
+                .iter()
+                .any(|x| x.token_addr == token_out_addr)
+            {
+                provider_updated.push(Asset::new(&token_out_addr, &timestamp));
+            }
+
+            for asset in provider_updated
+                .iter_mut()
+                .filter(|x| x.token_addr == token_out_addr)
+            {
+                asset.bonded = asset
+                    .bonded
+                    .checked_add(amount)
+                    .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+            }
+
+            compounded_list.push((token_out_addr, amount));
+        }
+    }
+
+    if compounded_list.is_empty() {
         Err(ContractError::NothingToClaim {})?;
     }
 
+    for (token_addr, _) in compounded_list.iter() {
+        update_incentives(
+            deps.storage,
+            timestamp,
+            token_addr,
+            std::slice::from_ref(&provider_addr),
+        )?;
+    }
+
     PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
 
+    let mut event_list: Vec<Event> = vec![];
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+
+    // update global token data
+    for (token_addr, amount) in compounded_list {
+        update_bonded_total(deps.storage, &token_addr, amount, Uint128::zero())?;
+
+        if let Some(asset) = provider_updated.iter().find(|x| x.token_addr == token_addr) {
+            event_list.push(
+                get_asset_event("compound", &provider_addr, asset)
+                    .add_attribute("amount", amount.to_string()),
+            );
+            hook_msg_list.append(&mut get_hook_msg_list(
+                deps.storage,
+                &IonFluxHookMsg::LiquidityChanged {
+                    action: LiquidityAction::Compound,
+                    provider: provider_addr.clone(),
+                    amount,
+                    asset: asset.to_owned(),
+                },
+            )?);
+        }
+
+        TOKENS.update(
+            deps.storage,
+            &token_addr,
+            |some_token| -> Result<Token, ContractError> {
+                let token = some_token.ok_or(ContractError::TokenIsNotFound {})?;
+
+                // compound decreases APR
+                Ok(Token {
+                    bonded: calc_sma(&token.bonded.0, &Sample::new(amount, timestamp), window)?,
+                    ..token
+                })
+            },
+        )?;
+    }
+
     Ok(Response::new()
-        .add_messages(msgs)
-        .add_attributes(vec![("action", "claim")]))
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
+        .add_attributes(vec![("action", "compound")]))
 }
 
-pub fn swap_and_claim(
+pub fn set_auto_compound(
     deps: DepsMut,
-    env: Env,
+    _env: Env,
     info: MessageInfo,
-    token_out_addr: String,
+    is_enabled: bool,
 ) -> Result<Response, ContractError> {
-    let price_list = if env.block.chain_id != CONFIG.load(deps.storage)?.get_chain_id() {
-        query_prices(deps.as_ref(), env.clone(), vec![])
+    if is_enabled {
+        AUTO_COMPOUND.save(deps.storage, &info.sender, &is_enabled)?;
     } else {
-        query_prices_mocked(deps.as_ref(), env.clone(), vec![])
+        AUTO_COMPOUND.remove(deps.storage, &info.sender);
     }
-    .map_err(|_| ContractError::NoPrices {})?;
 
-    swap_and_claim_accepting_prices(deps, env, info, token_out_addr, price_list)
+    Ok(Response::new()
+        .add_event(get_event("set_auto_compound").add_attributes(vec![
+            ("provider", info.sender.to_string()),
+            ("is_enabled", is_enabled.to_string()),
+        ]))
+        .add_attributes(vec![("action", "set_auto_compound")]))
 }
 
-fn swap_and_claim_accepting_prices(
+pub fn claim_protocol_fees(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+) -> Result<Response, ContractError> {
+    let Config { treasury, .. } = CONFIG.load(deps.storage)?;
+
+    if info.sender != treasury {
+        Err(ContractError::Unauthorized {})?;
+    }
+
+    let protocol_fee_list: Vec<(Addr, Uint128)> = PROTOCOL_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .flatten()
+        .collect();
+
+    let mut msgs: Vec<CosmosMsg> = vec![];
+    let mut event_list: Vec<Event> = vec![];
+
+    for (token_addr, amount) in protocol_fee_list {
+        PROTOCOL_FEES.remove(deps.storage, &token_addr);
+
+        if amount.is_zero() {
+            continue;
+        }
+
+        event_list.push(get_event("claim_protocol_fees").add_attributes(vec![
+            ("treasury", treasury.to_string()),
+            ("token_addr", token_addr.to_string()),
+            ("amount", amount.to_string()),
+        ]));
+
+        let cw_send_msg = Cw20ExecuteMsg::Transfer {
+            recipient: treasury.to_string(),
+            amount,
+        };
+
+        let msg = WasmMsg::Execute {
+            contract_addr: token_addr.to_string(),
+            msg: to_binary(&cw_send_msg)?,
+            funds: vec![],
+        };
+
+        msgs.push(msg.into());
+    }
+
+    if msgs.is_empty() {
+        Err(ContractError::NothingToClaim {})?;
+    }
+
+    Ok(Response::new()
+        .add_messages(msgs)
+        .add_events(event_list)
+        .add_attributes(vec![("action", "claim_protocol_fees")]))
+}
+
+pub fn transfer_position(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
-    token_out_addr: String,
-    price_list: Vec<(Addr, Decimal)>,
+    recipient: String,
 ) -> Result<Response, ContractError> {
     let provider_addr = info.sender;
-    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+    let recipient_addr = deps.api.addr_validate(&recipient)?;
     let timestamp = env.block.time;
 
-    let (_, token_out_price) = price_list
-        .iter()
-        .find(|x| x.0 == token_out_addr)
-        .ok_or(ContractError::TokenIsNotFound {})?;
+    if provider_addr == recipient_addr {
+        Err(ContractError::SameAddresses {})?;
+    }
 
     // check if provider exists or return err
     let provider = PROVIDERS
@@ -406,14 +2329,57 @@ fn swap_and_claim_accepting_prices(
         Err(ContractError::FundsAreNotFound {})?;
     }
 
-    let mut token_out_cost = Decimal::zero();
-    let mut provider_updated: Vec<Asset> = vec![];
+    // move pending incentive rewards to recipient
+    let recipient = PROVIDERS
+        .load(deps.storage, &recipient_addr)
+        .unwrap_or_default();
 
-    for asset in provider.iter() {
+    let mut target_list: Vec<Addr> = vec![];
+
+    for asset in provider.iter().chain(recipient.iter()) {
+        if !target_list.contains(&asset.token_addr) {
+            target_list.push(asset.token_addr.clone());
+        }
+    }
+
+    for token_addr in target_list {
+        update_incentives(
+            deps.storage,
+            timestamp,
+            &token_addr,
+            &[provider_addr.clone(), recipient_addr.clone()],
+        )?;
+    }
+
+    for (id, incentive_reward) in INCENTIVE_REWARDS
+        .prefix(&provider_addr)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, IncentiveReward)>>>()?
+    {
+        let incentive_reward_recipient = INCENTIVE_REWARDS
+            .may_load(deps.storage, (&recipient_addr, id))?
+            .unwrap_or(IncentiveReward {
+                amount: Uint128::zero(),
+                ..incentive_reward.clone()
+            });
+
+        INCENTIVE_REWARDS.save(
+            deps.storage,
+            (&recipient_addr, id),
+            &IncentiveReward {
+                amount: incentive_reward_recipient.amount + incentive_reward.amount,
+                ..incentive_reward_recipient
+            },
+        )?;
+        INCENTIVE_REWARDS.remove(deps.storage, (&provider_addr, id));
+    }
+
+    let mut recipient_updated: Vec<Asset> = vec![];
+
+    for asset in [recipient, provider.clone()].concat() {
         let Asset {
             mut unbonded,
             mut requested,
-            mut rewards,
             ..
         } = asset;
 
@@ -426,52 +2392,358 @@ fn swap_and_claim_accepting_prices(
             requested = Uint128::zero();
         }
 
-        if !asset.rewards.is_zero() {
-            rewards = Uint128::zero();
-
-            let (_, token_in_price) = price_list
-                .iter()
-                .find(|x| x.0 == asset.token_addr)
-                .ok_or(ContractError::TokenIsNotFound {})?;
-
-            token_out_cost += token_in_price * u128_to_dec(asset.rewards);
+        let asset_updated = match recipient_updated
+            .iter_mut()
+            .find(|x| x.token_addr == asset.token_addr)
+        {
+            Some(x) => x,
+            None => {
+                recipient_updated.push(Asset {
+                    unbonded,
+                    requested,
+                    ..asset
+                });
+
+                continue;
+            }
         };
 
-        // remove asset from list if there are no balances
-        if unbonded.is_zero()
-            && requested.is_zero()
-            && asset.bonded.is_zero()
-            && asset.rewards.is_zero()
+        // merged requested funds will be unbonded at the latest of both counters
+        if !requested.is_zero()
+            && (asset_updated.requested.is_zero() || asset.counter > asset_updated.counter)
         {
-            continue;
+            asset_updated.counter = asset.counter;
         }
 
-        provider_updated.push(Asset {
-            unbonded,
-            requested,
-            rewards,
-            ..asset.to_owned()
+        asset_updated.bonded = asset_updated
+            .bonded
+            .checked_add(asset.bonded)
+            .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+        asset_updated.unbonded = asset_updated
+            .unbonded
+            .checked_add(unbonded)
+            .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+        asset_updated.requested = asset_updated
+            .requested
+            .checked_add(requested)
+            .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+        asset_updated.rewards = asset_updated
+            .rewards
+            .checked_add(asset.rewards)
+            .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+        // merged lock lasts until the latest end with the lowest multiplier
+        let lock_list: Vec<Lock> = [asset_updated.lock.clone(), asset.lock]
+            .into_iter()
+            .flatten()
+            .filter(|x| x.end > timestamp)
+            .collect();
+
+        asset_updated.lock = lock_list.into_iter().reduce(|acc, x| Lock {
+            amount: acc.amount + x.amount,
+            end: acc.end.max(x.end),
+            multiplier: acc.multiplier.min(x.multiplier),
         });
     }
 
-    if token_out_cost.is_zero() {
-        Err(ContractError::NothingToClaim {})?;
+    PROVIDERS.remove(deps.storage, &provider_addr);
+    PROVIDERS.save(deps.storage, &recipient_addr, &recipient_updated)?;
+
+    // sender settings are dropped with position, recipient keeps own operators and auto-compound
+    OPERATORS.remove(deps.storage, &provider_addr);
+    AUTO_COMPOUND.remove(deps.storage, &provider_addr);
+
+    let event_list: Vec<Event> = recipient_updated
+        .iter()
+        .map(|asset| {
+            get_asset_event("transfer_position", &recipient_addr, asset)
+                .add_attribute("sender", provider_addr.to_string())
+        })
+        .collect();
+
+    // bonded amount leaves sender position and is added to recipient one
+    let mut hook_msg_list: Vec<SubMsg> = vec![];
+
+    for asset in provider {
+        hook_msg_list.append(&mut get_hook_msg_list(
+            deps.storage,
+            &IonFluxHookMsg::LiquidityChanged {
+                action: LiquidityAction::TransferOut,
+                provider: provider_addr.clone(),
+                amount: asset.bonded,
+                asset: Asset::new(&asset.token_addr, &timestamp),
+            },
+        )?);
+
+        if let Some(asset_updated) = recipient_updated
+            .iter()
+            .find(|x| x.token_addr == asset.token_addr)
+        {
+            hook_msg_list.append(&mut get_hook_msg_list(
+                deps.storage,
+                &IonFluxHookMsg::LiquidityChanged {
+                    action: LiquidityAction::TransferIn,
+                    provider: recipient_addr.clone(),
+                    amount: asset.bonded,
+                    asset: asset_updated.to_owned(),
+                },
+            )?);
+        }
     }
 
-    PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
+    Ok(Response::new()
+        .add_submessages(hook_msg_list)
+        .add_events(event_list)
+        .add_attributes(vec![("action", "transfer_position")]))
+}
 
-    let cw_send_msg = Cw20ExecuteMsg::Transfer {
-        recipient: provider_addr.to_string(),
-        amount: (token_out_cost / token_out_price).to_uint_floor(),
+pub fn set_operator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response, ContractError> {
+    let operator_addr = deps.api.addr_validate(&operator)?;
+    let event = get_event("set_operator").add_attributes(vec![
+        ("provider", info.sender.to_string()),
+        ("operator", operator_addr.to_string()),
+    ]);
+
+    OPERATORS.update(
+        deps.storage,
+        &info.sender,
+        |some_operator_list| -> Result<Vec<Addr>, ContractError> {
+            let mut operator_list = some_operator_list.unwrap_or_default();
+
+            if operator_list.contains(&operator_addr) {
+                Err(ContractError::OperatorIsAlreadySet {})?;
+            }
+
+            operator_list.push(operator_addr);
+
+            Ok(operator_list)
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_event(event)
+        .add_attributes(vec![("action", "set_operator")]))
+}
+
+pub fn revoke_operator(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    operator: String,
+) -> Result<Response, ContractError> {
+    let operator_addr = deps.api.addr_validate(&operator)?;
+
+    let operator_list: Vec<Addr> = OPERATORS
+        .load(deps.storage, &info.sender)
+        .unwrap_or_default();
+
+    if !operator_list.contains(&operator_addr) {
+        Err(ContractError::OperatorIsNotFound {})?;
+    }
+
+    let operator_list: Vec<Addr> = operator_list
+        .into_iter()
+        .filter(|x| x != &operator_addr)
+        .collect();
+
+    if operator_list.is_empty() {
+        OPERATORS.remove(deps.storage, &info.sender);
+    } else {
+        OPERATORS.save(deps.storage, &info.sender, &operator_list)?;
+    }
+
+    Ok(Response::new()
+        .add_event(get_event("revoke_operator").add_attributes(vec![
+            ("provider", info.sender.to_string()),
+            ("operator", operator_addr.to_string()),
+        ]))
+        .add_attributes(vec![("action", "revoke_operator")]))
+}
+
+// writes token snapshots of last active epoch on first user action of new epoch
+// token state isn't changed between epoch end and the action, price is the first one
+// observed in new epoch as oracle history isn't available
+pub fn update_snapshots(deps: DepsMut, env: &Env) -> Result<Vec<Event>, ContractError> {
+    let Config { epoch, .. } = CONFIG.load(deps.storage)?;
+    let timestamp = env.block.time.nanos() as u128;
+    let epoch_current = (timestamp - timestamp % epoch.u128()) as u64;
+
+    // keys are epoch starts so they stay ordered if epoch is changed by updating config
+    // increased epoch can start before last one, then last one lasts until next boundary
+    let epoch_last = match LAST_EPOCH.may_load(deps.storage)? {
+        Some(x) if x >= epoch_current => return Ok(vec![]),
+        x => x,
     };
 
-    let msg = WasmMsg::Execute {
-        contract_addr: token_out_addr.to_string(),
-        msg: to_binary(&cw_send_msg)?,
-        funds: vec![],
+    LAST_EPOCH.save(deps.storage, &epoch_current)?;
+
+    // nothing is written on first action
+    let epoch_last = match epoch_last {
+        Some(x) => x,
+        None => return Ok(vec![]),
     };
 
-    Ok(Response::new()
-        .add_message(msg)
-        .add_attributes(vec![("action", "swap_and_claim")]))
+    let token_list = query_tokens(deps.as_ref(), env.to_owned(), vec![])?;
+    // oracle failure mustn't block actions
+    let price_list = query_prices_any(deps.as_ref(), env.to_owned()).unwrap_or_default();
+
+    let mut event_list: Vec<Event> = vec![];
+
+    for (token_addr, token) in token_list {
+        let (swapped_in_total, swapped_out_total, fees_total) = SNAPSHOTS
+            .prefix(&token_addr)
+            .range(deps.storage, None, None, Order::Descending)
+            .next()
+            .transpose()?
+            .map_or(
+                (Uint128::zero(), Uint128::zero(), Uint128::zero()),
+                |(_, x)| (x.swapped_in_total, x.swapped_out_total, x.fees_total),
+            );
+
+        let bonded = BONDED_TOTALS
+            .may_load(deps.storage, &token_addr)?
+            .unwrap_or_default();
+
+        let price = price_list
+            .iter()
+            .find(|(addr, _)| addr == &token_addr)
+            .map(|(_, price)| *price);
+
+        let snapshot = Snapshot {
+            swapped_in: token.swapped_in_total - swapped_in_total,
+            swapped_out: token.swapped_out_total - swapped_out_total,
+            fees: token.fees_total - fees_total,
+            bonded,
+            price,
+            swapped_in_total: token.swapped_in_total,
+            swapped_out_total: token.swapped_out_total,
+            fees_total: token.fees_total,
+        };
+
+        event_list.push(get_event("snapshot").add_attributes(vec![
+            ("token_addr", token_addr.to_string()),
+            ("epoch_start", Timestamp::from_nanos(epoch_last).to_string()),
+            ("snapshot", to_json_string(&snapshot)?),
+        ]));
+
+        SNAPSHOTS.save(deps.storage, (&token_addr, epoch_last), &snapshot)?;
+    }
+
+    Ok(event_list)
+}
+
+// checkpoints incentives of target token and accrues rewards of listed providers
+// must be called before bonded amounts of the providers are changed
+pub fn update_incentives(
+    storage: &mut dyn Storage,
+    timestamp: Timestamp,
+    token_addr: &Addr,
+    provider_addr_list: &[Addr],
+) -> Result<(), ContractError> {
+    let incentive_list = INCENTIVES
+        .prefix(token_addr)
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<(u64, Incentive)>>>()?;
+
+    if incentive_list.is_empty() {
+        return Ok(());
+    }
+
+    let bonded_total = BONDED_TOTALS
+        .may_load(storage, token_addr)?
+        .unwrap_or_default();
+
+    let mut bonded_list: Vec<(&Addr, Uint128)> = vec![];
+    for provider_addr in provider_addr_list {
+        let bonded = PROVIDERS
+            .may_load(storage, provider_addr)?
+            .unwrap_or_default()
+            .iter()
+            .filter(|asset| &asset.token_addr == token_addr)
+            .fold(Uint128::zero(), |acc, asset| acc + asset.bonded);
+
+        bonded_list.push((provider_addr, bonded));
+    }
+
+    for (id, incentive) in incentive_list {
+        let mut incentive = incentive;
+
+        // reward per token of closed incentive is final
+        if !incentive.is_closed {
+            let (reward_per_token, amount_streamed) =
+                calc_reward_per_token(&incentive, bonded_total, timestamp)?;
+
+            incentive.reward_per_token = reward_per_token;
+            incentive.last_update = timestamp;
+            incentive.distributed += amount_streamed;
+        }
+
+        for (provider_addr, bonded) in &bonded_list {
+            let incentive_reward = INCENTIVE_REWARDS
+                .may_load(storage, (provider_addr, id))?
+                .unwrap_or(IncentiveReward {
+                    token_addr_target: token_addr.to_owned(),
+                    reward_token: incentive.reward_asset.token.clone(),
+                    reward_per_token_paid: Decimal::zero(),
+                    amount: Uint128::zero(),
+                    is_settled: false,
+                });
+
+            if incentive.is_closed {
+                if incentive_reward.is_settled {
+                    continue;
+                }
+
+                // bonded amount can't be changed between close and settlement
+                incentive.bonded_unsettled = incentive.bonded_unsettled.saturating_sub(*bonded);
+            }
+
+            INCENTIVE_REWARDS.save(
+                storage,
+                (provider_addr, id),
+                &IncentiveReward {
+                    reward_per_token_paid: incentive.reward_per_token,
+                    amount: incentive_reward.amount
+                        + calc_incentive_rewards(
+                            *bonded,
+                            incentive.reward_per_token,
+                            incentive_reward.reward_per_token_paid,
+                        ),
+                    is_settled: incentive.is_closed,
+                    ..incentive_reward
+                },
+            )?;
+        }
+
+        // all bonded providers settled rewards of closed incentive
+        if incentive.is_closed && incentive.bonded_unsettled.is_zero() {
+            INCENTIVES.remove(storage, (token_addr, id));
+        } else {
+            INCENTIVES.save(storage, (token_addr, id), &incentive)?;
+        }
+    }
+
+    Ok(())
+}
+
+// keeps sum of bonded amounts of token, must be called when bonded amount is changed
+pub fn update_bonded_total(
+    storage: &mut dyn Storage,
+    token_addr: &Addr,
+    amount_added: Uint128,
+    amount_removed: Uint128,
+) -> StdResult<()> {
+    BONDED_TOTALS.update(storage, token_addr, |x| -> StdResult<Uint128> {
+        Ok(x.unwrap_or_default() + amount_added - amount_removed)
+    })?;
+
+    Ok(())
 }
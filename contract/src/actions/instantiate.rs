@@ -8,8 +8,8 @@ use crate::{
     state::{Config, Pyth, CONFIG, PYTH},
 };
 
-const CONTRACT_NAME: &str = "crates.io:ion-flux";
-const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const CONTRACT_NAME: &str = "crates.io:ion-flux";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 const PYTH_CONTRACT_ADDR: &str = "inj1z60tg0tekdzcasenhuuwq3htjcd5slmgf7gpez";
 
@@ -17,6 +17,7 @@ pub const SWAP_FEE_RATE: &str = "0.003";
 pub const WINDOW: u128 = 30 * 60 * 1_000_000_000;
 pub const UNBONDING_PERIOD: u128 = 60 * 60 * 1_000_000_000;
 pub const PRICE_AGE: u128 = 8_000_000;
+pub const EPOCH: u128 = 24 * 60 * 60 * 1_000_000_000;
 
 pub fn init(
     deps: DepsMut,
@@ -32,6 +33,7 @@ pub fn init(
             WINDOW,
             UNBONDING_PERIOD,
             PRICE_AGE,
+            EPOCH,
         ),
     )?;
 
@@ -2,7 +2,7 @@ use std::ops::Div;
 
 use cosmwasm_std::{Addr, Decimal, StdError, StdResult, Timestamp, Uint128};
 
-use crate::state::{Asset, Sample, Token};
+use crate::state::{Asset, Incentive, Sample, Token};
 
 pub fn str_to_dec(s: &str) -> Decimal {
     s.to_string().parse::<Decimal>().unwrap()
@@ -201,6 +201,17 @@ pub fn calc_sma(
     Ok((framed_list, sma))
 }
 
+// returns sum of sample values inside window ending at timestamp
+pub fn calc_window_sum(sample_list: &[Sample], timestamp: Timestamp, window: Uint128) -> Uint128 {
+    let boundary_timestamp =
+        Timestamp::from_nanos(timestamp.nanos().saturating_sub(window.u128() as u64));
+
+    sample_list
+        .iter()
+        .filter(|x| x.timestamp > boundary_timestamp)
+        .fold(Uint128::zero(), |acc, x| acc + x.value)
+}
+
 // volume_ratio = (requested + swapped_out) / (bonded + (1 - swap_fee_rate) * swapped_in)
 // all values used as SMA
 pub fn calc_volume_ratio(
@@ -233,34 +244,176 @@ pub fn calc_volume_ratio(
     }
 }
 
-// provider_rewards = provider_power * swap_fee
-// swap_fee = swap_fee_rate * amount_in
+// dynamic_fee_rate = swap_fee_rate * volume_ratio_out / volume_ratio_in
+// swap draining token under outflow pressure pays more, rebalancing swap pays less
+pub fn calc_dynamic_fee_rate(
+    swap_fee_rate: Decimal,
+    volume_ratio_in: Decimal,
+    volume_ratio_out: Decimal,
+    min_fee_rate: Decimal,
+    max_fee_rate: Decimal,
+) -> StdResult<Decimal> {
+    if min_fee_rate > max_fee_rate {
+        Err(StdError::generic_err(
+            "min_fee_rate > max_fee_rate at calc_dynamic_fee_rate",
+        ))?
+    }
+
+    if volume_ratio_in.is_zero() {
+        return Ok(max_fee_rate);
+    }
+
+    let dynamic_fee_rate = swap_fee_rate * volume_ratio_out / volume_ratio_in;
+
+    Ok(dynamic_fee_rate.clamp(min_fee_rate, max_fee_rate))
+}
+
+// price_deviation = |price - price_average| / price_average
+pub fn calc_price_deviation(price: Decimal, price_average: Decimal) -> StdResult<Decimal> {
+    if price_average.is_zero() {
+        Err(StdError::generic_err(
+            "price_average is zero at calc_price_deviation",
+        ))?
+    }
+
+    let price_difference = if price > price_average {
+        price - price_average
+    } else {
+        price_average - price
+    };
+
+    Ok(price_difference / price_average)
+}
+
+// reward_per_token += amount * (min(timestamp, end) - max(last_update, start)) / (end - start) / bonded_total
+// rewards aren't accumulated while nothing is bonded
+// returns updated reward_per_token and amount streamed to bonded tokens
+pub fn calc_reward_per_token(
+    incentive: &Incentive,
+    bonded_total: Uint128,
+    timestamp: Timestamp,
+) -> StdResult<(Decimal, Uint128)> {
+    let Incentive {
+        reward_asset,
+        start,
+        end,
+        reward_per_token,
+        last_update,
+        ..
+    } = incentive;
+
+    if start >= end {
+        Err(StdError::generic_err(
+            "start >= end at calc_reward_per_token",
+        ))?
+    }
+
+    let period_start = last_update.max(start);
+    let period_end = timestamp.min(*end);
+
+    if bonded_total.is_zero() || period_end <= *period_start {
+        return Ok((*reward_per_token, Uint128::zero()));
+    }
+
+    let amount_streamed = reward_asset.amount.multiply_ratio(
+        period_end.nanos() - period_start.nanos(),
+        end.nanos() - start.nanos(),
+    );
+
+    Ok((
+        reward_per_token + u128_to_dec(amount_streamed) / u128_to_dec(bonded_total),
+        amount_streamed,
+    ))
+}
+
+// provider_rewards = bonded * (reward_per_token - reward_per_token_paid)
+pub fn calc_incentive_rewards(
+    bonded: Uint128,
+    reward_per_token: Decimal,
+    reward_per_token_paid: Decimal,
+) -> Uint128 {
+    (u128_to_dec(bonded) * (reward_per_token - reward_per_token_paid)).to_uint_floor()
+}
+
+// token_apr = providers_fee_value * token_weight * (year / window) / (bonded * price)
+// providers_fee_value = (1 - protocol_fee_share) * sum_for_each_token(swap_fee * price)
+// swap_fee = swap_fee_rate * swapped_in / (1 - swap_fee_rate), swapped_in SMA is used as volume per window
+pub fn calc_token_apr(
+    providers_fee_value: Decimal,
+    token_weight: Decimal,
+    window: Uint128,
+    bonded: Uint128,
+    price: Decimal,
+) -> StdResult<Decimal> {
+    const YEAR: u128 = 365 * 24 * 60 * 60 * 1_000_000_000;
+
+    if window.is_zero() {
+        Err(StdError::generic_err("window is zero at calc_token_apr"))?
+    }
+
+    let bonded_value = price * u128_to_dec(bonded);
+
+    if bonded_value.is_zero() {
+        return Ok(Decimal::zero());
+    }
+
+    Ok(providers_fee_value * token_weight * u128_to_dec(YEAR) / u128_to_dec(window) / bonded_value)
+}
+
+// boosted_bonded = bonded - locked + locked * multiplier, expired lock isn't boosted
+pub fn calc_boosted_bonded(asset: &Asset, timestamp: Timestamp) -> Decimal {
+    let bonded = u128_to_dec(asset.bonded);
+
+    match &asset.lock {
+        Some(lock) if lock.end > timestamp => {
+            let locked = u128_to_dec(lock.amount.min(asset.bonded));
+
+            bonded - locked + locked * lock.multiplier
+        }
+        _ => bonded,
+    }
+}
+
+// provider_rewards = provider_power * (1 - protocol_fee_share) * swap_fee
+// swap_fee = fee_rate * amount_in
+// fee_rate is effective rate of the swap, config swap_fee_rate is used in volume_ratio
 // provider_power = sum_for_each_asset(allocation * token_weight)
-// allocation = asset_bonded / sum_for_each_provider(asset_bonded)
+// allocation = asset_boosted_bonded / sum_for_each_provider(asset_boosted_bonded)
 // token_weight = volume_ratio / sum_for_each_token(volume_ratio)
+#[allow(clippy::too_many_arguments)]
 pub fn calc_provider_rewards(
     amount_in: Uint128,
     token_in_price: Decimal,
     token_out_price: Decimal,
     swap_fee_rate: Decimal,
+    fee_rate: Decimal,
+    protocol_fee_share: Decimal,
     provider_list: Vec<(Addr, Vec<Asset>)>,
     token_list: Vec<(Addr, Token)>,
+    timestamp: Timestamp,
 ) -> StdResult<(Vec<(Addr, Uint128)>, Uint128)> {
     let mut provider_rewards_list: Vec<(Addr, Uint128)> = vec![];
 
+    if protocol_fee_share > Decimal::one() {
+        Err(StdError::generic_err(
+            "protocol_fee_share > one at calc_provider_rewards",
+        ))?
+    }
+
     // distribute rewards to providers
-    let swap_fee = swap_fee_rate * u128_to_dec(amount_in);
+    let swap_fee = fee_rate * u128_to_dec(amount_in);
+    let providers_fee = (Decimal::one() - protocol_fee_share) * swap_fee;
 
     // get total values for volume ratio and bonded tokens
     let mut volume_ratio_list: Vec<(Addr, Decimal)> = vec![];
     let mut volume_ratio_sum = Decimal::zero();
-    let mut bonded_total: Vec<(Addr, Uint128)> = vec![];
+    let mut bonded_total: Vec<(Addr, Decimal)> = vec![];
 
     for (token_addr, token) in token_list {
         let bonded_by_all_providers =
             provider_list
                 .iter()
-                .fold(Uint128::zero(), |acc, (_, asset_list)| {
+                .fold(Decimal::zero(), |acc, (_, asset_list)| {
                     let asset_default = Asset::new(&token_addr, &Timestamp::default());
 
                     let asset = asset_list
@@ -268,7 +421,7 @@ pub fn calc_provider_rewards(
                         .find(|x| x.token_addr == token_addr)
                         .unwrap_or(&asset_default);
 
-                    acc + asset.bonded
+                    acc + calc_boosted_bonded(asset, timestamp)
                 });
 
         let volume_ratio = calc_volume_ratio(
@@ -295,7 +448,7 @@ pub fn calc_provider_rewards(
                 continue;
             }
 
-            let bonded_default = (asset.token_addr.clone(), Uint128::zero());
+            let bonded_default = (asset.token_addr.clone(), Decimal::zero());
 
             let (_, bonded_total_amount) = bonded_total
                 .iter()
@@ -305,7 +458,7 @@ pub fn calc_provider_rewards(
             let allocation = if bonded_total_amount.is_zero() {
                 Decimal::one()
             } else {
-                u128_to_dec(asset.bonded) / u128_to_dec(*bonded_total_amount)
+                calc_boosted_bonded(&asset, timestamp) / bonded_total_amount
             };
 
             let volume_ratio_default = (asset.token_addr.clone(), Decimal::zero());
@@ -321,7 +474,7 @@ pub fn calc_provider_rewards(
             provider_power += asset_power;
         }
 
-        let provider_rewards = (provider_power * swap_fee).to_uint_floor();
+        let provider_rewards = (provider_power * providers_fee).to_uint_floor();
         provider_rewards_list.push((provider_addr, provider_rewards));
     }
 
@@ -332,14 +485,53 @@ pub fn calc_provider_rewards(
     Ok((provider_rewards_list, amount_out))
 }
 
+// inverse of amount_out from calc_provider_rewards
+// amount_in = amount_out * token_out_price / (token_in_price * (1 - swap_fee_rate))
+// returns min amount_in providing amount_out
+pub fn calc_amount_in(
+    amount_out: Uint128,
+    token_in_price: Decimal,
+    token_out_price: Decimal,
+    swap_fee_rate: Decimal,
+) -> StdResult<Uint128> {
+    if token_in_price.is_zero() || swap_fee_rate >= Decimal::one() {
+        Err(StdError::generic_err(
+            "token_in_price is zero or swap_fee_rate >= one at calc_amount_in",
+        ))?
+    }
+
+    let calc_amount_out = |amount_in: Uint128| -> StdResult<Uint128> {
+        let amount_in_clean = u128_to_dec(amount_in) - swap_fee_rate * u128_to_dec(amount_in);
+
+        Ok((token_in_price * amount_in_clean)
+            .checked_div(token_out_price)
+            .map_err(|_| StdError::generic_err("token_out_price is zero at calc_amount_in"))?
+            .to_uint_floor())
+    };
+
+    let cost_out = token_out_price * u128_to_dec(amount_out);
+    let mut amount_in =
+        (cost_out / token_in_price / (Decimal::one() - swap_fee_rate)).to_uint_ceil();
+
+    // compensate decimal rounding
+    while calc_amount_out(amount_in)? < amount_out {
+        amount_in += Uint128::one();
+    }
+
+    Ok(amount_in)
+}
+
 #[cfg(test)]
 pub mod test {
     use cosmwasm_std::Decimal;
 
+    use crate::state::{Lock, RewardAsset, RewardToken};
+
     use super::{
-        calc_area, calc_average, calc_provider_rewards, calc_sma, calc_volume_ratio, frame_list,
-        interpolate, str_to_dec, u128_to_dec, Addr, Asset, Sample, StdError, Timestamp, Token,
-        Uint128,
+        calc_amount_in, calc_area, calc_average, calc_dynamic_fee_rate, calc_incentive_rewards,
+        calc_price_deviation, calc_provider_rewards, calc_reward_per_token, calc_sma,
+        calc_token_apr, calc_volume_ratio, calc_window_sum, frame_list, interpolate, str_to_dec,
+        u128_to_dec, Addr, Asset, Incentive, Sample, StdError, Timestamp, Token, Uint128,
     };
 
     use crate::{
@@ -755,6 +947,42 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn calc_window_sum_default() {
+        let sample_list = vec![
+            Sample::new(Uint128::zero(), Timestamp::from_nanos(1000)),
+            Sample::new(Uint128::from(1000u128), Timestamp::from_nanos(1500)),
+            Sample::new(Uint128::from(2000u128), Timestamp::from_nanos(2000)),
+            Sample::new(Uint128::from(3000u128), Timestamp::from_nanos(2500)),
+        ];
+
+        // samples on left boundary are excluded
+        assert_eq!(
+            calc_window_sum(
+                &sample_list,
+                Timestamp::from_nanos(2500),
+                Uint128::from(1000u128)
+            ),
+            Uint128::from(5000u128)
+        );
+        assert_eq!(
+            calc_window_sum(
+                &sample_list,
+                Timestamp::from_nanos(4000),
+                Uint128::from(1000u128)
+            ),
+            Uint128::zero()
+        );
+        assert_eq!(
+            calc_window_sum(
+                &sample_list,
+                Timestamp::from_nanos(500),
+                Uint128::from(1000u128)
+            ),
+            Uint128::from(6000u128)
+        );
+    }
+
     #[test]
     fn calc_volume_ratio_default() {
         let bonded = Uint128::from(1000u128);
@@ -816,6 +1044,203 @@ pub mod test {
         assert_eq!(res.unwrap(), volume_ratio);
     }
 
+    #[test]
+    fn calc_dynamic_fee_rate_default() {
+        let swap_fee_rate = str_to_dec(SWAP_FEE_RATE);
+        let min_fee_rate = str_to_dec("0.001");
+        let max_fee_rate = str_to_dec("0.01");
+
+        // balanced volumes don't change fee
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("0.5"),
+                str_to_dec("0.5"),
+                min_fee_rate,
+                max_fee_rate
+            )
+            .unwrap(),
+            swap_fee_rate
+        );
+
+        // draining token under outflow pressure increases fee
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("0.5"),
+                str_to_dec("1"),
+                min_fee_rate,
+                max_fee_rate
+            )
+            .unwrap(),
+            str_to_dec("0.006")
+        );
+
+        // rebalancing swap decreases fee
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("1"),
+                str_to_dec("0.5"),
+                min_fee_rate,
+                max_fee_rate
+            )
+            .unwrap(),
+            str_to_dec("0.0015")
+        );
+    }
+
+    #[test]
+    fn calc_dynamic_fee_rate_limits() {
+        let swap_fee_rate = str_to_dec(SWAP_FEE_RATE);
+        let min_fee_rate = str_to_dec("0.001");
+        let max_fee_rate = str_to_dec("0.01");
+
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("0.000001"),
+                str_to_dec("1000000"),
+                min_fee_rate,
+                max_fee_rate
+            )
+            .unwrap(),
+            max_fee_rate
+        );
+
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("1000000"),
+                str_to_dec("0.000001"),
+                min_fee_rate,
+                max_fee_rate
+            )
+            .unwrap(),
+            min_fee_rate
+        );
+
+        assert_eq!(
+            calc_dynamic_fee_rate(
+                swap_fee_rate,
+                str_to_dec("1"),
+                str_to_dec("1"),
+                max_fee_rate,
+                min_fee_rate
+            )
+            .unwrap_err(),
+            StdError::generic_err("min_fee_rate > max_fee_rate at calc_dynamic_fee_rate")
+        );
+    }
+
+    #[test]
+    fn calc_price_deviation_default() {
+        assert_eq!(
+            calc_price_deviation(str_to_dec("11"), str_to_dec("10")).unwrap(),
+            str_to_dec("0.1")
+        );
+        assert_eq!(
+            calc_price_deviation(str_to_dec("8"), str_to_dec("10")).unwrap(),
+            str_to_dec("0.2")
+        );
+        assert_eq!(
+            calc_price_deviation(str_to_dec("10"), str_to_dec("10")).unwrap(),
+            Decimal::zero()
+        );
+        assert_eq!(
+            calc_price_deviation(str_to_dec("10"), Decimal::zero()).unwrap_err(),
+            StdError::generic_err("price_average is zero at calc_price_deviation")
+        );
+    }
+
+    #[test]
+    fn calc_reward_per_token_default() {
+        let incentive = Incentive {
+            creator: Addr::unchecked(TOKEN_ADDR_ATOM),
+            token_addr_target: Addr::unchecked(TOKEN_ADDR_ATOM),
+            reward_asset: RewardAsset {
+                token: RewardToken::Native("inj".to_string()),
+                amount: Uint128::from(1_000u128),
+            },
+            start: Timestamp::from_nanos(100),
+            end: Timestamp::from_nanos(200),
+            reward_per_token: Decimal::zero(),
+            last_update: Timestamp::from_nanos(100),
+            distributed: Uint128::zero(),
+            is_closed: false,
+            bonded_unsettled: Uint128::zero(),
+        };
+
+        // half of amount is streamed to 250 bonded tokens
+        let (reward_per_token, amount_streamed) = calc_reward_per_token(
+            &incentive,
+            Uint128::from(250u128),
+            Timestamp::from_nanos(150),
+        )
+        .unwrap();
+        assert_eq!(reward_per_token, u128_to_dec(2u128));
+        assert_eq!(amount_streamed, Uint128::from(500u128));
+
+        // nothing is streamed after end
+        let incentive = Incentive {
+            reward_per_token,
+            last_update: Timestamp::from_nanos(150),
+            ..incentive
+        };
+        assert_eq!(
+            calc_reward_per_token(
+                &incentive,
+                Uint128::from(500u128),
+                Timestamp::from_nanos(300)
+            )
+            .unwrap(),
+            (u128_to_dec(3u128), Uint128::from(500u128))
+        );
+
+        // nothing is streamed without bonded tokens
+        assert_eq!(
+            calc_reward_per_token(&incentive, Uint128::zero(), Timestamp::from_nanos(300)).unwrap(),
+            (u128_to_dec(2u128), Uint128::zero())
+        );
+
+        assert_eq!(
+            calc_incentive_rewards(
+                Uint128::from(100u128),
+                u128_to_dec(3u128),
+                u128_to_dec(2u128)
+            ),
+            Uint128::from(100u128)
+        );
+    }
+
+    #[test]
+    fn calc_token_apr_default() {
+        // 10 per hour -> 87_600 per year
+        assert_eq!(
+            calc_token_apr(
+                u128_to_dec(20u128),
+                str_to_dec("0.5"),
+                Uint128::from(60 * 60 * 1_000_000_000u128),
+                Uint128::from(43_800u128),
+                u128_to_dec(2u128)
+            )
+            .unwrap(),
+            Decimal::one()
+        );
+
+        assert_eq!(
+            calc_token_apr(
+                u128_to_dec(20u128),
+                str_to_dec("0.5"),
+                Uint128::from(60 * 60 * 1_000_000_000u128),
+                Uint128::zero(),
+                u128_to_dec(2u128)
+            )
+            .unwrap(),
+            Decimal::zero()
+        );
+    }
+
     #[test]
     fn calc_provider_rewards_2_providers_2_assets_each() {
         const AMOUNT_IN: u128 = 1_000_000;
@@ -843,6 +1268,7 @@ pub mod test {
                         requested: Uint128::from(0u128),
                         counter: Timestamp::default(),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                     Asset {
                         token_addr: Addr::unchecked(TOKEN_ADDR_LUNA),
@@ -852,6 +1278,7 @@ pub mod test {
                         requested: Uint128::from(0u128),
                         counter: Timestamp::default(),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                 ],
             ),
@@ -867,6 +1294,7 @@ pub mod test {
                         requested: Uint128::from(0u128),
                         counter: Timestamp::default(),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                     Asset {
                         token_addr: Addr::unchecked(TOKEN_ADDR_LUNA),
@@ -877,6 +1305,7 @@ pub mod test {
                         requested: Uint128::from(0u128),
                         counter: Timestamp::default(),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                 ],
             ),
@@ -892,6 +1321,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_ATOM)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -903,6 +1336,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_LUNA)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ];
@@ -912,8 +1349,11 @@ pub mod test {
             token_in_price,
             token_out_price,
             swap_fee_rate,
+            swap_fee_rate,
+            Decimal::zero(),
             provider_list,
             token_list,
+            Timestamp::default(),
         )
         .unwrap();
 
@@ -950,6 +1390,150 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn calc_provider_rewards_lock_boost() {
+        const AMOUNT_IN: u128 = 1_000_000;
+        const BONDED: u128 = 1_000;
+
+        let get_asset = |lock: Option<Lock>| Asset {
+            token_addr: Addr::unchecked(TOKEN_ADDR_ATOM),
+            bonded: Uint128::from(BONDED),
+            unbonded: Uint128::from(0u128),
+            requested: Uint128::from(0u128),
+            counter: Timestamp::default(),
+            rewards: Uint128::from(0u128),
+            lock,
+        };
+
+        // alice locked whole bonded amount with 3x multiplier, bob isn't boosted
+        let provider_list: Vec<(Addr, Vec<Asset>)> = vec![
+            (
+                Addr::unchecked(ADDR_ALICE_INJ),
+                vec![get_asset(Some(Lock {
+                    amount: Uint128::from(BONDED),
+                    end: Timestamp::from_nanos(100),
+                    multiplier: u128_to_dec(3u128),
+                }))],
+            ),
+            (Addr::unchecked(ADDR_BOB_INJ), vec![get_asset(None)]),
+        ];
+
+        let token_list: Vec<(Addr, Token)> = vec![(
+            Addr::unchecked(TOKEN_ADDR_ATOM),
+            Token {
+                symbol: SYMBOL_ATOM.to_string(),
+                price_feed_id_str: PRICE_FEED_ID_STR_ATOM.to_string(),
+                bonded: (vec![], Uint128::from(BONDED)),
+                requested: (vec![], Uint128::from(0u128)),
+                swapped_in: (vec![], Uint128::from(0u128)),
+                swapped_out: (vec![], Uint128::from(0u128)),
+                swap_fee_rate: None,
+                swapped_in_total: Uint128::zero(),
+                swapped_out_total: Uint128::zero(),
+                fees_total: Uint128::zero(),
+            },
+        )];
+
+        let calc_rewards = |timestamp: u64| {
+            calc_provider_rewards(
+                Uint128::from(AMOUNT_IN),
+                str_to_dec(PRICE_ATOM),
+                str_to_dec(PRICE_LUNA),
+                str_to_dec(SWAP_FEE_RATE),
+                str_to_dec(SWAP_FEE_RATE),
+                Decimal::zero(),
+                provider_list.clone(),
+                token_list.clone(),
+                Timestamp::from_nanos(timestamp),
+            )
+            .unwrap()
+            .0
+        };
+
+        // swap_fee = 3_000, allocations are 3/4 and 1/4
+        assert_eq!(
+            calc_rewards(50),
+            vec![
+                (Addr::unchecked(ADDR_ALICE_INJ), Uint128::from(2_250u128)),
+                (Addr::unchecked(ADDR_BOB_INJ), Uint128::from(750u128)),
+            ]
+        );
+
+        // expired lock isn't boosted
+        assert_eq!(
+            calc_rewards(100),
+            vec![
+                (Addr::unchecked(ADDR_ALICE_INJ), Uint128::from(1_500u128)),
+                (Addr::unchecked(ADDR_BOB_INJ), Uint128::from(1_500u128)),
+            ]
+        );
+    }
+
+    #[test]
+    fn calc_provider_rewards_fee_rate() {
+        const AMOUNT_IN: u128 = 1_000_000;
+        const BONDED: u128 = 1_000;
+
+        let asset = Asset {
+            token_addr: Addr::unchecked(TOKEN_ADDR_ATOM),
+            bonded: Uint128::from(BONDED),
+            unbonded: Uint128::from(0u128),
+            requested: Uint128::from(0u128),
+            counter: Timestamp::default(),
+            rewards: Uint128::from(0u128),
+            lock: None,
+        };
+
+        let provider_list: Vec<(Addr, Vec<Asset>)> = vec![
+            (Addr::unchecked(ADDR_ALICE_INJ), vec![asset.clone()]),
+            (Addr::unchecked(ADDR_BOB_INJ), vec![asset]),
+        ];
+
+        let token_list: Vec<(Addr, Token)> = vec![(
+            Addr::unchecked(TOKEN_ADDR_ATOM),
+            Token {
+                symbol: SYMBOL_ATOM.to_string(),
+                price_feed_id_str: PRICE_FEED_ID_STR_ATOM.to_string(),
+                bonded: (vec![], Uint128::from(BONDED)),
+                requested: (vec![], Uint128::from(0u128)),
+                swapped_in: (vec![], Uint128::from(0u128)),
+                swapped_out: (vec![], Uint128::from(0u128)),
+                swap_fee_rate: None,
+                swapped_in_total: Uint128::zero(),
+                swapped_out_total: Uint128::zero(),
+                fees_total: Uint128::zero(),
+            },
+        )];
+
+        // effective rate is doubled by pair fee, weights use config rate
+        let (provider_rewards_list, amount_out) = calc_provider_rewards(
+            Uint128::from(AMOUNT_IN),
+            str_to_dec(PRICE_ATOM),
+            str_to_dec(PRICE_LUNA),
+            str_to_dec(SWAP_FEE_RATE),
+            str_to_dec("0.006"),
+            Decimal::zero(),
+            provider_list,
+            token_list,
+            Timestamp::default(),
+        )
+        .unwrap();
+
+        // swap_fee = 6_000
+        assert_eq!(
+            provider_rewards_list,
+            vec![
+                (Addr::unchecked(ADDR_ALICE_INJ), Uint128::from(3_000u128)),
+                (Addr::unchecked(ADDR_BOB_INJ), Uint128::from(3_000u128)),
+            ]
+        );
+        assert_eq!(
+            amount_out,
+            (str_to_dec(PRICE_ATOM) * u128_to_dec(AMOUNT_IN - 6_000) / str_to_dec(PRICE_LUNA))
+                .to_uint_floor()
+        );
+    }
+
     #[test]
     fn calc_provider_rewards_2_providers_1_asset_each() {
         const AMOUNT_IN: u128 = 1_000_000;
@@ -976,6 +1560,7 @@ pub mod test {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::default(),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 }],
             ),
             (
@@ -989,6 +1574,7 @@ pub mod test {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::default(),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 }],
             ),
         ];
@@ -1003,6 +1589,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_ATOM)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -1014,6 +1604,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_LUNA)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ];
@@ -1023,8 +1617,11 @@ pub mod test {
             token_in_price,
             token_out_price,
             swap_fee_rate,
+            swap_fee_rate,
+            Decimal::zero(),
             provider_list,
             token_list,
+            Timestamp::default(),
         )
         .unwrap();
 
@@ -1087,6 +1684,7 @@ pub mod test {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::default(),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: Addr::unchecked(TOKEN_ADDR_LUNA),
@@ -1096,6 +1694,7 @@ pub mod test {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::default(),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ],
         )];
@@ -1110,6 +1709,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_ATOM)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -1121,6 +1724,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_LUNA)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ];
@@ -1130,8 +1737,11 @@ pub mod test {
             token_in_price,
             token_out_price,
             swap_fee_rate,
+            swap_fee_rate,
+            Decimal::zero(),
             provider_list,
             token_list,
+            Timestamp::default(),
         )
         .unwrap();
 
@@ -1182,6 +1792,7 @@ pub mod test {
                 requested: Uint128::from(0u128),
                 counter: Timestamp::default(),
                 rewards: Uint128::from(0u128),
+                lock: None,
             }],
         )];
 
@@ -1195,6 +1806,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_ATOM)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -1206,6 +1821,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_LUNA)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ];
@@ -1215,8 +1834,11 @@ pub mod test {
             token_in_price,
             token_out_price,
             swap_fee_rate,
+            swap_fee_rate,
+            Decimal::zero(),
             provider_list,
             token_list,
+            Timestamp::default(),
         )
         .unwrap();
 
@@ -1242,6 +1864,78 @@ pub mod test {
         );
     }
 
+    #[test]
+    fn calc_provider_rewards_protocol_fee_share() {
+        const AMOUNT_IN: u128 = 1_000_000;
+        const PROTOCOL_FEE_SHARE: &str = "0.2";
+        const BONDED_VOLUME_ATOM: u128 = 2_000_000;
+
+        let amount_in = Uint128::from(AMOUNT_IN);
+        let token_in_price = str_to_dec(PRICE_ATOM);
+        let token_out_price = str_to_dec(PRICE_LUNA);
+        let swap_fee_rate = str_to_dec(SWAP_FEE_RATE);
+
+        let provider_list: Vec<(Addr, Vec<Asset>)> = vec![(
+            Addr::unchecked(ADDR_ALICE_INJ),
+            vec![Asset {
+                token_addr: Addr::unchecked(TOKEN_ADDR_ATOM),
+                bonded: Uint128::from(AMOUNT_IN),
+                unbonded: Uint128::from(0u128),
+                requested: Uint128::from(0u128),
+                counter: Timestamp::default(),
+                rewards: Uint128::from(0u128),
+                lock: None,
+            }],
+        )];
+
+        let token_list: Vec<(Addr, Token)> = vec![(
+            Addr::unchecked(TOKEN_ADDR_ATOM),
+            Token {
+                symbol: SYMBOL_ATOM.to_string(),
+                price_feed_id_str: PRICE_FEED_ID_STR_ATOM.to_string(),
+                bonded: (vec![], Uint128::from(BONDED_VOLUME_ATOM)),
+                requested: (vec![], Uint128::from(0u128)),
+                swapped_in: (vec![], Uint128::from(0u128)),
+                swapped_out: (vec![], Uint128::from(0u128)),
+                swap_fee_rate: None,
+                swapped_in_total: Uint128::zero(),
+                swapped_out_total: Uint128::zero(),
+                fees_total: Uint128::zero(),
+            },
+        )];
+
+        let (provider_rewards_list, amount_out) = calc_provider_rewards(
+            amount_in,
+            token_in_price,
+            token_out_price,
+            swap_fee_rate,
+            swap_fee_rate,
+            str_to_dec(PROTOCOL_FEE_SHARE),
+            provider_list,
+            token_list,
+            Timestamp::default(),
+        )
+        .unwrap();
+
+        let amount_out_right = ((Decimal::one() - str_to_dec(SWAP_FEE_RATE))
+            * u128_to_dec(AMOUNT_IN)
+            * str_to_dec(PRICE_ATOM)
+            / str_to_dec(PRICE_LUNA))
+        .to_uint_floor();
+
+        // protocol fee doesn't affect amount_out, only providers part of swap fee
+        let alice_rewards = ((Decimal::one() - str_to_dec(PROTOCOL_FEE_SHARE))
+            * str_to_dec(SWAP_FEE_RATE)
+            * u128_to_dec(AMOUNT_IN))
+        .to_uint_floor();
+
+        assert_eq!(amount_out, amount_out_right);
+        assert_eq!(
+            provider_rewards_list,
+            vec![(Addr::unchecked(ADDR_ALICE_INJ), alice_rewards)]
+        );
+    }
+
     #[test]
     fn calc_provider_rewards_no_providers() {
         const AMOUNT_IN: u128 = 1_000_000;
@@ -1267,6 +1961,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_ATOM)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -1278,6 +1976,10 @@ pub mod test {
                     requested: (vec![], Uint128::from(REQUESTED_VOLUME_LUNA)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ];
@@ -1287,8 +1989,11 @@ pub mod test {
             token_in_price,
             token_out_price,
             swap_fee_rate,
+            swap_fee_rate,
+            Decimal::zero(),
             provider_list,
             token_list,
+            Timestamp::default(),
         )
         .unwrap();
 
@@ -1302,5 +2007,53 @@ pub mod test {
         assert_eq!(provider_rewards_list, vec![]);
     }
 
+    #[test]
+    fn calc_amount_in_default() {
+        let token_in_price = str_to_dec(PRICE_ATOM);
+        let token_out_price = str_to_dec(PRICE_LUNA);
+        let swap_fee_rate = str_to_dec(SWAP_FEE_RATE);
+
+        let calc_amount_out = |amount_in: Uint128| -> Uint128 {
+            let (_, amount_out) = calc_provider_rewards(
+                amount_in,
+                token_in_price,
+                token_out_price,
+                swap_fee_rate,
+                swap_fee_rate,
+                Decimal::zero(),
+                vec![],
+                vec![],
+                Timestamp::default(),
+            )
+            .unwrap();
+
+            amount_out
+        };
+
+        for amount_out in [1u128, 498, 1_000, 123_456_789] {
+            let amount_out = Uint128::from(amount_out);
+            let amount_in =
+                calc_amount_in(amount_out, token_in_price, token_out_price, swap_fee_rate).unwrap();
+
+            assert!(calc_amount_out(amount_in) >= amount_out);
+            assert!(calc_amount_out(amount_in - Uint128::one()) < amount_out);
+        }
+    }
+
+    #[test]
+    fn calc_amount_in_zero_out_price() {
+        let res = calc_amount_in(
+            Uint128::from(1_000u128),
+            str_to_dec(PRICE_ATOM),
+            Decimal::zero(),
+            str_to_dec(SWAP_FEE_RATE),
+        );
+
+        assert_eq!(
+            res.unwrap_err(),
+            StdError::generic_err("token_out_price is zero at calc_amount_in")
+        );
+    }
+
     // TODO: add more tests - noisy numbers
 }
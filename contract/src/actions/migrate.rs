@@ -1,12 +1,190 @@
+use cosmwasm_schema::cw_serde;
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{DepsMut, Env, Response};
+use cosmwasm_std::{Addr, Decimal, DepsMut, Env, Order, Response, StdError, Storage, Uint128};
+use cw2::{get_contract_version, set_contract_version, ContractVersion};
+use cw_storage_plus::Item;
+use semver::Version;
 
-use crate::{error::ContractError, messages::migrate::MigrateMsg};
+use crate::{
+    actions::{
+        execute::{get_event, to_json_string},
+        instantiate::{CONTRACT_NAME, CONTRACT_VERSION, EPOCH},
+    },
+    error::ContractError,
+    messages::migrate::MigrateMsg,
+    state::{CircuitBreaker, Config, DynamicFee, RateLimit, BONDED_TOTALS, CONFIG, PROVIDERS},
+};
 
+// config layout of 0.1.x releases, 0.1.0 stored only the required fields,
+// optional ones were added by later 0.1.x releases and are missing in older configs
+#[cw_serde]
+struct ConfigV0_1 {
+    admin: Addr,
+    swap_fee_rate: Decimal,
+    window: Uint128,
+    unbonding_period: Uint128,
+    price_age: Uint128,
+    protocol_fee_share: Option<Decimal>,
+    treasury: Option<Addr>,
+    dynamic_fee: Option<DynamicFee>,
+    max_referral_commission_rate: Option<Decimal>,
+    circuit_breaker: Option<CircuitBreaker>,
+    rate_limit: Option<RateLimit>,
+    epoch: Option<Uint128>,
+    keeper_tip_rate: Option<Decimal>,
+    chain_id_mocked: String,
+}
+
+const CONFIG_V0_1: Item<ConfigV0_1> = Item::new("config");
+
+// Token and Asset fields added after 0.1.0 have serde defaults, bonded totals are derived from providers
 pub fn migrate_contract(
-    _deps: DepsMut,
+    deps: DepsMut,
     _env: Env,
-    _msg: MigrateMsg,
+    msg: MigrateMsg,
 ) -> Result<Response, ContractError> {
-    Ok(Response::default())
+    let ContractVersion { contract, version } = get_contract_version(deps.storage)?;
+
+    if contract != CONTRACT_NAME {
+        Err(ContractError::ContractNameIsWrong { name: contract })?;
+    }
+
+    let version_stored = parse_version(&version)?;
+
+    if version_stored > parse_version(CONTRACT_VERSION)? {
+        Err(ContractError::VersionIsDowngraded {
+            version: version.clone(),
+        })?;
+    }
+
+    // versioned transforms, pre-releases have layout of their release
+    let release_stored = Version::new(
+        version_stored.major,
+        version_stored.minor,
+        version_stored.patch,
+    );
+
+    if release_stored < Version::new(0, 2, 0) {
+        migrate_config_v0_1(deps.storage)?;
+    }
+
+    migrate_bonded_totals(deps.storage)?;
+
+    let config = CONFIG.update(
+        deps.storage,
+        |mut config| -> Result<Config, ContractError> {
+            if let Some(x) = msg.protocol_fee_share {
+                if x > Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.protocol_fee_share = x;
+            }
+
+            if let Some(x) = msg.treasury {
+                config.treasury = deps.api.addr_validate(&x)?;
+            }
+
+            if let Some(x) = msg.max_referral_commission_rate {
+                if x >= Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.max_referral_commission_rate = x;
+            }
+
+            if let Some(x) = msg.epoch {
+                if x.is_zero() {
+                    Err(ContractError::EpochIsOutOfRange {})?;
+                }
+
+                config.epoch = x;
+            }
+
+            if let Some(x) = msg.keeper_tip_rate {
+                if x >= Decimal::one() {
+                    Err(ContractError::FeeRateIsOutOfRange {})?;
+                }
+
+                config.keeper_tip_rate = x;
+            }
+
+            Ok(config)
+        },
+    )?;
+
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    Ok(Response::new()
+        .add_event(get_event("migrate").add_attributes(vec![
+            ("version_old", version),
+            ("version_new", CONTRACT_VERSION.to_string()),
+            ("config", to_json_string(&config)?),
+        ]))
+        .add_attributes(vec![("action", "migrate")]))
+}
+
+// missing config fields get instantiation defaults
+fn migrate_config_v0_1(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let config_old = CONFIG_V0_1.load(storage)?;
+    let mut config = Config::new(
+        &config_old.admin,
+        &config_old.swap_fee_rate.to_string(),
+        config_old.window.u128(),
+        config_old.unbonding_period.u128(),
+        config_old.price_age.u128(),
+        EPOCH,
+    );
+
+    config.protocol_fee_share = config_old
+        .protocol_fee_share
+        .unwrap_or(config.protocol_fee_share);
+    config.treasury = config_old.treasury.unwrap_or(config.treasury);
+    config.dynamic_fee = config_old.dynamic_fee.unwrap_or(config.dynamic_fee);
+    config.max_referral_commission_rate = config_old
+        .max_referral_commission_rate
+        .unwrap_or(config.max_referral_commission_rate);
+    config.circuit_breaker = config_old.circuit_breaker.unwrap_or(config.circuit_breaker);
+    config.rate_limit = config_old.rate_limit.unwrap_or(config.rate_limit);
+    config.epoch = config_old.epoch.unwrap_or(config.epoch);
+    config.keeper_tip_rate = config_old.keeper_tip_rate.unwrap_or(config.keeper_tip_rate);
+
+    CONFIG.save(storage, &config)?;
+
+    Ok(())
+}
+
+// bonded totals are recalculated from providers, it's the only place where all of them are ranged
+fn migrate_bonded_totals(storage: &mut dyn Storage) -> Result<(), ContractError> {
+    let mut bonded_total_list: Vec<(Addr, Uint128)> = vec![];
+
+    for item in PROVIDERS.range(storage, None, None, Order::Ascending) {
+        let (_, asset_list) = item?;
+
+        for asset in asset_list {
+            match bonded_total_list
+                .iter_mut()
+                .find(|(token_addr, _)| token_addr == &asset.token_addr)
+            {
+                Some((_, bonded_total)) => *bonded_total += asset.bonded,
+                None => bonded_total_list.push((asset.token_addr, asset.bonded)),
+            }
+        }
+    }
+
+    for (token_addr, bonded_total) in bonded_total_list {
+        BONDED_TOTALS.save(storage, &token_addr, &bonded_total)?;
+    }
+
+    Ok(())
+}
+
+// semver string, pre-release and build metadata are supported
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    Version::parse(version).map_err(|_| {
+        ContractError::Std(StdError::generic_err(format!(
+            "invalid version {} at parse_version",
+            version
+        )))
+    })
 }
@@ -3,15 +3,33 @@ use cosmwasm_std::{
     Addr, Decimal, Deps, Env, Order, QuerierWrapper, StdError, StdResult, Timestamp, Uint128,
 };
 use cw20::{BalanceResponse, Cw20QueryMsg};
+use cw_storage_plus::{Bound, Map};
 use pyth_sdk_cw::{query_price_feed, Price, PriceIdentifier};
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
-    actions::math::{calc_volume_ratio, u128_to_dec},
+    actions::math::{
+        calc_dynamic_fee_rate, calc_incentive_rewards, calc_reward_per_token, calc_token_apr,
+        calc_volume_ratio, calc_window_sum, u128_to_dec,
+    },
     error::{to_std_err, ContractError},
-    messages::response::Balance,
-    state::{Asset, Config, Pyth, Token, CONFIG, PROVIDERS, PYTH, TOKENS},
+    messages::response::{
+        AssetSummary, Balance, Fees, Page, PoolStats, ProviderApr, ProviderSummary, SwapAllowance,
+        TokenStats,
+    },
+    state::{
+        Asset, Config, DcaSchedule, FeeDiscount, Hook, Incentive, LimitOrder, LockBoost, Pyth,
+        RewardAsset, Sample, Snapshot, Token, TokenCaps, AUTO_COMPOUND, BLOCK_SWAP_VOLUME,
+        BONDED_TOTALS, CONFIG, DCA_SCHEDULES, DCA_SCHEDULES_BY_OWNER, ESCROWED, FEE_DISCOUNTS,
+        HALTED_TOKENS, HOOKS, INCENTIVES, INCENTIVE_REWARDS, LIMIT_ORDERS, LIMIT_ORDERS_BY_OWNER,
+        LIMIT_ORDERS_BY_PAIR, LOCK_BOOSTS, OPERATORS, PAIR_FEES, PRICES, PROTOCOL_FEES, PROVIDERS,
+        PYTH, SNAPSHOTS, SWAP_VOLUMES, TOKENS, TOKEN_CAPS, TOKEN_SWAP_VOLUMES,
+    },
 };
 
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+
 pub fn query_config(deps: Deps, _env: Env) -> StdResult<Config> {
     CONFIG.load(deps.storage)
 }
@@ -49,7 +67,646 @@ pub fn query_tokens_weight(
     Ok(token_weight_list)
 }
 
-// token_liquidity = token_balance - sum_for_each_provider(asset_unbonded + asset_rewards)
+// estimates annual yield of bonded tokens using sum of swapped_in during window as volume per window
+pub fn query_token_apr(deps: Deps, env: Env) -> StdResult<Vec<(Addr, Decimal)>> {
+    let Config {
+        swap_fee_rate,
+        window,
+        protocol_fee_share,
+        ..
+    } = CONFIG.load(deps.storage)?;
+    let token_list = query_tokens(deps, env.clone(), vec![])?;
+    let token_weight_list = query_tokens_weight(deps, env.clone(), vec![])?;
+    let provider_list = query_providers(deps, env.clone(), vec![])?;
+    let price_list = query_prices_any(deps, env.clone())?;
+
+    let get_price = |token_addr: &Addr| -> StdResult<Decimal> {
+        price_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, price)| *price)
+            .ok_or_else(|| to_std_err(ContractError::NoPrices {}))
+    };
+
+    let mut fee_value = Decimal::zero();
+
+    for (token_addr, token) in &token_list {
+        let (swapped_in_list, _) = TOKEN_SWAP_VOLUMES
+            .may_load(deps.storage, token_addr)?
+            .unwrap_or_default();
+        let swapped_in = calc_window_sum(&swapped_in_list, env.block.time, window);
+
+        let swap_fee_rate = token.swap_fee_rate.unwrap_or(swap_fee_rate);
+        let swap_fee = swap_fee_rate * u128_to_dec(swapped_in) / (Decimal::one() - swap_fee_rate);
+
+        fee_value += swap_fee * get_price(token_addr)?;
+    }
+
+    let providers_fee_value = (Decimal::one() - protocol_fee_share) * fee_value;
+    let mut token_apr_list: Vec<(Addr, Decimal)> = vec![];
+
+    for (token_addr, token_weight) in token_weight_list {
+        let bonded = provider_list
+            .iter()
+            .flat_map(|(_, asset_list)| asset_list)
+            .filter(|asset| asset.token_addr == token_addr)
+            .fold(Uint128::zero(), |acc, asset| acc + asset.bonded);
+
+        let token_apr = calc_token_apr(
+            providers_fee_value,
+            token_weight,
+            window,
+            bonded,
+            get_price(&token_addr)?,
+        )?;
+
+        token_apr_list.push((token_addr, token_apr));
+    }
+
+    Ok(token_apr_list)
+}
+
+// provider_apr = sum_for_each_asset(token_apr * bonded_value) / sum_for_each_asset(bonded_value)
+pub fn query_provider_apr(deps: Deps, env: Env, address: String) -> StdResult<ProviderApr> {
+    let address = deps.api.addr_validate(&address)?;
+    let asset_list = PROVIDERS.load(deps.storage, &address)?;
+    let token_apr_list = query_token_apr(deps, env.clone())?;
+    let price_list = query_prices_any(deps, env)?;
+
+    let mut asset_apr_list: Vec<(Addr, Decimal)> = vec![];
+    let mut apr_value = Decimal::zero();
+    let mut bonded_value_total = Decimal::zero();
+
+    for asset in asset_list {
+        let token_apr = token_apr_list
+            .iter()
+            .find(|(addr, _)| addr == &asset.token_addr)
+            .map(|(_, apr)| *apr)
+            .unwrap_or_default();
+        let price = price_list
+            .iter()
+            .find(|(addr, _)| addr == &asset.token_addr)
+            .map(|(_, price)| *price)
+            .ok_or_else(|| to_std_err(ContractError::NoPrices {}))?;
+        let bonded_value = price * u128_to_dec(asset.bonded);
+
+        apr_value += token_apr * bonded_value;
+        bonded_value_total += bonded_value;
+        asset_apr_list.push((asset.token_addr, token_apr));
+    }
+
+    let apr = if bonded_value_total.is_zero() {
+        Decimal::zero()
+    } else {
+        apr_value / bonded_value_total
+    };
+
+    Ok(ProviderApr {
+        address,
+        asset_apr_list,
+        apr,
+    })
+}
+
+// returns pool-wide token amounts, swap counters and their values at oracle prices
+pub fn query_pool_stats(deps: Deps, env: Env) -> StdResult<PoolStats> {
+    let token_list = query_tokens(deps, env.clone(), vec![])?;
+    let provider_list = query_providers(deps, env.clone(), vec![])?;
+    let liquidity_list = query_liquidity(deps, env.clone(), vec![])?;
+    let price_list = query_prices_any(deps, env)?;
+
+    let mut pool_stats = PoolStats {
+        token_stats_list: vec![],
+        provider_count: provider_list.len() as u32,
+        liquidity_value: Decimal::zero(),
+        bonded_value: Decimal::zero(),
+        requested_value: Decimal::zero(),
+        unbonded_value: Decimal::zero(),
+        rewards_value: Decimal::zero(),
+        swapped_in_total_value: Decimal::zero(),
+        swapped_out_total_value: Decimal::zero(),
+        fees_total_value: Decimal::zero(),
+    };
+
+    for (token_addr, token) in token_list {
+        let price = price_list
+            .iter()
+            .find(|(addr, _)| addr == &token_addr)
+            .map(|(_, price)| *price)
+            .ok_or_else(|| to_std_err(ContractError::NoPrices {}))?;
+        let liquidity = liquidity_list
+            .iter()
+            .find(|(addr, _)| addr == &token_addr)
+            .map(|(_, liquidity)| *liquidity)
+            .unwrap_or_default();
+
+        let mut asset_total = Asset::new(&token_addr, &Timestamp::default());
+        let mut provider_count: u32 = 0;
+
+        for asset in provider_list
+            .iter()
+            .flat_map(|(_, asset_list)| asset_list)
+            .filter(|asset| asset.token_addr == token_addr)
+        {
+            asset_total.bonded += asset.bonded;
+            asset_total.requested += asset.requested;
+            asset_total.unbonded += asset.unbonded;
+            asset_total.rewards += asset.rewards;
+
+            if !(asset.bonded + asset.requested + asset.unbonded + asset.rewards).is_zero() {
+                provider_count += 1;
+            }
+        }
+
+        let get_value = |amount: Uint128| price * u128_to_dec(amount);
+
+        let token_stats = TokenStats {
+            token_addr,
+            price,
+            liquidity,
+            liquidity_value: get_value(liquidity),
+            bonded: asset_total.bonded,
+            bonded_value: get_value(asset_total.bonded),
+            requested: asset_total.requested,
+            requested_value: get_value(asset_total.requested),
+            unbonded: asset_total.unbonded,
+            unbonded_value: get_value(asset_total.unbonded),
+            rewards: asset_total.rewards,
+            rewards_value: get_value(asset_total.rewards),
+            provider_count,
+            swapped_in_total: token.swapped_in_total,
+            swapped_in_total_value: get_value(token.swapped_in_total),
+            swapped_out_total: token.swapped_out_total,
+            swapped_out_total_value: get_value(token.swapped_out_total),
+            fees_total: token.fees_total,
+            fees_total_value: get_value(token.fees_total),
+        };
+
+        pool_stats.liquidity_value += token_stats.liquidity_value;
+        pool_stats.bonded_value += token_stats.bonded_value;
+        pool_stats.requested_value += token_stats.requested_value;
+        pool_stats.unbonded_value += token_stats.unbonded_value;
+        pool_stats.rewards_value += token_stats.rewards_value;
+        pool_stats.swapped_in_total_value += token_stats.swapped_in_total_value;
+        pool_stats.swapped_out_total_value += token_stats.swapped_out_total_value;
+        pool_stats.fees_total_value += token_stats.fees_total_value;
+        pool_stats.token_stats_list.push(token_stats);
+    }
+
+    Ok(pool_stats)
+}
+
+// returns stored snapshots of token for epochs started in range [from, to]
+pub fn query_history(
+    deps: Deps,
+    _env: Env,
+    token_addr: String,
+    from: Timestamp,
+    to: Timestamp,
+) -> StdResult<Vec<(Timestamp, Snapshot)>> {
+    let token_addr = deps.api.addr_validate(&token_addr)?;
+
+    SNAPSHOTS
+        .prefix(&token_addr)
+        .range(
+            deps.storage,
+            Some(Bound::inclusive(from.nanos())),
+            Some(Bound::inclusive(to.nanos())),
+            Order::Ascending,
+        )
+        .map(|x| x.map(|(epoch_start, snapshot)| (Timestamp::from_nanos(epoch_start), snapshot)))
+        .collect()
+}
+
+pub fn query_fees(deps: Deps, env: Env) -> StdResult<Fees> {
+    let Config { swap_fee_rate, .. } = CONFIG.load(deps.storage)?;
+
+    let token_fee_list: Vec<(Addr, Decimal)> = query_tokens(deps, env, vec![])?
+        .into_iter()
+        .filter_map(|(addr, token)| token.swap_fee_rate.map(|x| (addr, x)))
+        .collect();
+
+    let pair_fee_list: Vec<(Addr, Addr, Decimal)> = PAIR_FEES
+        .range(deps.storage, None, None, Order::Ascending)
+        .flatten()
+        .map(|((token_in_addr, token_out_addr), x)| (token_in_addr, token_out_addr, x))
+        .collect();
+
+    Ok(Fees {
+        swap_fee_rate,
+        token_fee_list,
+        pair_fee_list,
+    })
+}
+
+// returns swap fee rate applied to swap token_in -> token_out
+// the most specific rate is used: pair fee, token_in fee, token_out fee, config fee
+pub fn query_swap_fee_rate(
+    deps: Deps,
+    env: Env,
+    token_in_addr: String,
+    token_out_addr: String,
+) -> StdResult<Decimal> {
+    let Config {
+        swap_fee_rate: swap_fee_rate_default,
+        dynamic_fee,
+        ..
+    } = CONFIG.load(deps.storage)?;
+
+    let token_in_addr = deps.api.addr_validate(&token_in_addr)?;
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+    let token_list = query_tokens(
+        deps,
+        env,
+        vec![token_in_addr.to_string(), token_out_addr.to_string()],
+    )?;
+
+    let token_fee = |addr: &Addr| -> Option<Decimal> {
+        token_list
+            .iter()
+            .find(|(token_addr, _)| token_addr == addr)
+            .and_then(|(_, token)| token.swap_fee_rate)
+    };
+
+    // pair fee is set for specific pair explicitly, it isn't limited by dynamic fee range
+    if let Some(pair_fee_rate) =
+        PAIR_FEES.may_load(deps.storage, (&token_in_addr, &token_out_addr))?
+    {
+        return Ok(pair_fee_rate);
+    }
+
+    let swap_fee_rate = token_fee(&token_in_addr)
+        .or_else(|| token_fee(&token_out_addr))
+        .unwrap_or(swap_fee_rate_default);
+
+    if !dynamic_fee.is_enabled {
+        return Ok(swap_fee_rate);
+    }
+
+    let mut volume_ratio_in = Decimal::zero();
+    let mut volume_ratio_out = Decimal::zero();
+
+    for (token_addr, token) in token_list {
+        let volume_ratio = calc_volume_ratio(
+            token.bonded.1,
+            token.requested.1,
+            token.swapped_in.1,
+            token.swapped_out.1,
+            swap_fee_rate_default,
+        )?;
+
+        if token_addr == token_out_addr {
+            volume_ratio_out = volume_ratio;
+        } else {
+            volume_ratio_in = volume_ratio;
+        }
+    }
+
+    calc_dynamic_fee_rate(
+        swap_fee_rate,
+        volume_ratio_in,
+        volume_ratio_out,
+        dynamic_fee.min_fee_rate,
+        dynamic_fee.max_fee_rate,
+    )
+}
+
+// address_allowance = max_address_volume - sum_for_window(address_swapped_value)
+// block_allowance = max_block_volume - block_swapped_value
+pub fn query_swap_allowances(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<SwapAllowance>> {
+    let Config {
+        window, rate_limit, ..
+    } = CONFIG.load(deps.storage)?;
+    check_address_list(&address_list, &start_after, limit)?;
+
+    let Page {
+        list: volume_list,
+        cursor,
+    } = if address_list.is_empty() {
+        query_page(deps, SWAP_VOLUMES, start_after, limit)?
+    } else {
+        let mut volume_list: Vec<(Addr, Vec<Sample>)> = vec![];
+
+        for address in address_list {
+            let address = deps.api.addr_validate(&address)?;
+            let sample_list = SWAP_VOLUMES
+                .may_load(deps.storage, &address)?
+                .unwrap_or_default();
+
+            volume_list.push((address, sample_list));
+        }
+
+        Page {
+            list: volume_list,
+            cursor: None,
+        }
+    };
+
+    let block_volume = match BLOCK_SWAP_VOLUME.may_load(deps.storage)? {
+        Some((height, volume)) if height == env.block.height => volume,
+        _ => Uint128::zero(),
+    };
+    let block_allowance = rate_limit
+        .max_block_volume
+        .map(|x| x.saturating_sub(block_volume));
+
+    let mut allowance_list: Vec<SwapAllowance> = vec![];
+
+    for (address, sample_list) in volume_list {
+        let address_volume = calc_window_sum(&sample_list, env.block.time, window);
+
+        allowance_list.push(SwapAllowance {
+            address,
+            address_allowance: rate_limit
+                .max_address_volume
+                .map(|x| x.saturating_sub(address_volume)),
+            block_allowance,
+        });
+    }
+
+    Ok(Page {
+        list: allowance_list,
+        cursor,
+    })
+}
+
+pub fn query_halted_tokens(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<Addr>> {
+    let Page { list, cursor } = query_page(deps, HALTED_TOKENS, start_after, limit)?;
+
+    Ok(Page {
+        list: list.into_iter().map(|(token_addr, _)| token_addr).collect(),
+        cursor,
+    })
+}
+
+// returns price sma values collected by circuit breaker
+pub fn query_price_averages(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Decimal)>> {
+    let Page { list, cursor } = query_page(deps, PRICES, start_after, limit)?;
+
+    Ok(Page {
+        list: list
+            .into_iter()
+            .map(|(token_addr, (_, price_average))| (token_addr, Decimal::new(price_average)))
+            .collect(),
+        cursor,
+    })
+}
+
+pub fn query_token_caps(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, TokenCaps)>> {
+    query_page(deps, TOKEN_CAPS, start_after, limit)
+}
+
+pub fn query_limit_orders_by_owner(
+    deps: Deps,
+    _env: Env,
+    owner: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Page<(u64, LimitOrder), u64>> {
+    let owner = deps.api.addr_validate(&owner)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let id_list = LIMIT_ORDERS_BY_OWNER
+        .prefix(&owner)
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    query_id_page(deps, LIMIT_ORDERS, id_list, limit)
+}
+
+pub fn query_limit_orders_by_pair(
+    deps: Deps,
+    _env: Env,
+    token_in_addr: String,
+    token_out_addr: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Page<(u64, LimitOrder), u64>> {
+    let token_in_addr = deps.api.addr_validate(&token_in_addr)?;
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let id_list = LIMIT_ORDERS_BY_PAIR
+        .prefix((&token_in_addr, &token_out_addr))
+        .keys(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    query_id_page(deps, LIMIT_ORDERS, id_list, limit)
+}
+
+// returns programs of target token which aren't closed yet
+pub fn query_incentives(
+    deps: Deps,
+    _env: Env,
+    token_addr_target: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Page<(u64, Incentive), u64>> {
+    let token_addr_target = deps.api.addr_validate(&token_addr_target)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    // closed incentives are kept only to settle rewards of providers
+    let list = INCENTIVES
+        .prefix(&token_addr_target)
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .filter(|item| item.as_ref().map_or(true, |(_, x)| !x.is_closed))
+        .take(limit)
+        .collect::<StdResult<Vec<(u64, Incentive)>>>()?;
+
+    let cursor = if list.len() == limit {
+        list.last().map(|(id, _)| *id)
+    } else {
+        None
+    };
+
+    Ok(Page { list, cursor })
+}
+
+// returns pending incentive rewards of provider for target token streamed up to current time
+pub fn query_incentive_rewards(
+    deps: Deps,
+    env: Env,
+    address: String,
+    token_addr_target: String,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Page<(u64, RewardAsset), u64>> {
+    let address = deps.api.addr_validate(&address)?;
+    let token_addr_target = deps.api.addr_validate(&token_addr_target)?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    // active incentives and closed ones with unclaimed rewards are both ordered by id
+    let mut id_list = INCENTIVES
+        .prefix(&token_addr_target)
+        .keys(deps.storage, start.clone(), None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<u64>>>()?;
+
+    for item in INCENTIVE_REWARDS
+        .prefix(&address)
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|item| match item {
+            Ok((id, x)) => {
+                x.token_addr_target == token_addr_target
+                    && !x.amount.is_zero()
+                    && !INCENTIVES.has(deps.storage, (&token_addr_target, *id))
+            }
+            Err(_) => true,
+        })
+        .take(limit)
+    {
+        id_list.push(item?.0);
+    }
+
+    id_list.sort_unstable();
+    id_list.truncate(limit);
+
+    let cursor = if id_list.len() == limit {
+        id_list.last().copied()
+    } else {
+        None
+    };
+
+    let bonded_total = query_bonded_total(deps, env.clone(), &token_addr_target)?;
+    let bonded = PROVIDERS
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default()
+        .iter()
+        .filter(|asset| asset.token_addr == token_addr_target)
+        .fold(Uint128::zero(), |acc, asset| acc + asset.bonded);
+
+    let mut list: Vec<(u64, RewardAsset)> = vec![];
+
+    for id in id_list {
+        let incentive_reward = INCENTIVE_REWARDS.may_load(deps.storage, (&address, id))?;
+
+        let (token, amount) = match INCENTIVES.may_load(deps.storage, (&token_addr_target, id))? {
+            Some(incentive) => {
+                let (reward_per_token, _) =
+                    calc_reward_per_token(&incentive, bonded_total, env.block.time)?;
+                let (reward_per_token_paid, rewards) = incentive_reward
+                    .map_or((Decimal::zero(), Uint128::zero()), |x| {
+                        (x.reward_per_token_paid, x.amount)
+                    });
+
+                (
+                    incentive.reward_asset.token,
+                    rewards
+                        + calc_incentive_rewards(bonded, reward_per_token, reward_per_token_paid),
+                )
+            }
+            // rewards of closed incentives
+            None => match incentive_reward {
+                Some(x) => (x.reward_token, x.amount),
+                None => continue,
+            },
+        };
+
+        if !amount.is_zero() {
+            list.push((id, RewardAsset { token, amount }));
+        }
+    }
+
+    Ok(Page { list, cursor })
+}
+
+pub fn query_hooks(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Hook)>> {
+    query_page(deps, HOOKS, start_after, limit)
+}
+
+pub fn query_fee_discounts(deps: Deps, _env: Env) -> StdResult<Vec<FeeDiscount>> {
+    Ok(FEE_DISCOUNTS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+pub fn query_lock_boosts(deps: Deps, _env: Env) -> StdResult<Vec<LockBoost>> {
+    Ok(LOCK_BOOSTS.may_load(deps.storage)?.unwrap_or_default())
+}
+
+// returns the lowest fee multiplier among discount tiers reached by address holdings
+pub fn query_fee_multiplier(deps: Deps, env: Env, address: String) -> StdResult<Decimal> {
+    let address = deps.api.addr_validate(&address)?;
+    let fee_discount_list = query_fee_discounts(deps, env)?;
+
+    let mut fee_multiplier = Decimal::one();
+    let mut balance_list: Vec<(Addr, Uint128)> = vec![];
+
+    for fee_discount in fee_discount_list {
+        let balance = match balance_list
+            .iter()
+            .find(|(token_addr, _)| token_addr == &fee_discount.token_addr)
+        {
+            Some((_, balance)) => *balance,
+            None => {
+                let msg = Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                };
+
+                let balance = QuerierWrapper::query_wasm_smart::<BalanceResponse>(
+                    &deps.querier,
+                    fee_discount.token_addr.clone(),
+                    &msg,
+                )?
+                .balance;
+
+                balance_list.push((fee_discount.token_addr.clone(), balance));
+
+                balance
+            }
+        };
+
+        if balance >= fee_discount.min_balance && fee_discount.fee_multiplier < fee_multiplier {
+            fee_multiplier = fee_discount.fee_multiplier;
+        }
+    }
+
+    Ok(fee_multiplier)
+}
+
+// token_liquidity = token_balance - sum_for_each_provider(asset_unbonded + asset_rewards) - protocol_fees - escrowed
 pub fn query_liquidity(
     deps: Deps,
     env: Env,
@@ -85,48 +742,274 @@ pub fn query_liquidity(
                 acc + asset.unbonded + asset.rewards
             });
 
-        liquidity_list.push((token_addr, balance - illiquid_funds));
+        let protocol_fees = PROTOCOL_FEES
+            .load(deps.storage, &token_addr)
+            .unwrap_or_default();
+
+        let escrowed = ESCROWED.load(deps.storage, &token_addr).unwrap_or_default();
+
+        // obligations can exceed balance if token was drained, liquidity is zero then
+        liquidity_list.push((
+            token_addr,
+            balance
+                .saturating_sub(illiquid_funds)
+                .saturating_sub(protocol_fees)
+                .saturating_sub(escrowed),
+        ));
     }
 
     Ok(liquidity_list)
 }
 
+// returns all providers or providers from address_list
 pub fn query_providers(
     deps: Deps,
     _env: Env,
     address_list: Vec<String>,
 ) -> StdResult<Vec<(Addr, Vec<Asset>)>> {
-    let mut res: Vec<(Addr, Vec<Asset>)> = vec![];
+    if address_list.is_empty() {
+        return PROVIDERS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect();
+    }
 
-    for (addr, provider) in PROVIDERS
-        .range(deps.storage, None, None, Order::Ascending)
-        .flatten()
-    {
-        if address_list.is_empty() || address_list.contains(&addr.to_string()) {
-            res.push((addr, provider));
+    query_list(deps, PROVIDERS, address_list)
+}
+
+// returns amount of token bonded by all providers
+pub fn query_bonded_total(deps: Deps, _env: Env, token_addr: &Addr) -> StdResult<Uint128> {
+    Ok(BONDED_TOTALS
+        .may_load(deps.storage, token_addr)?
+        .unwrap_or_default())
+}
+
+// returns providers from address_list or page of all providers
+pub fn query_providers_page(
+    deps: Deps,
+    _env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Vec<Asset>)>> {
+    check_address_list(&address_list, &start_after, limit)?;
+
+    if !address_list.is_empty() {
+        return Ok(Page {
+            list: query_list(deps, PROVIDERS, address_list)?,
+            cursor: None,
+        });
+    }
+
+    query_page(deps, PROVIDERS, start_after, limit)
+}
+
+pub fn query_provider(deps: Deps, _env: Env, address: String) -> StdResult<Vec<Asset>> {
+    PROVIDERS.load(deps.storage, &deps.api.addr_validate(&address)?)
+}
+
+// returns provider assets with settled matured requests and their values at oracle prices
+pub fn query_provider_summary(deps: Deps, env: Env, address: String) -> StdResult<ProviderSummary> {
+    let address = deps.api.addr_validate(&address)?;
+    let asset_list = PROVIDERS.load(deps.storage, &address)?;
+
+    let price_list = query_prices_any(deps, env.clone())?;
+
+    let mut asset_summary_list: Vec<AssetSummary> = vec![];
+    let mut total_value = Decimal::zero();
+
+    for asset in asset_list {
+        let Asset {
+            token_addr,
+            bonded,
+            mut unbonded,
+            mut requested,
+            counter,
+            rewards,
+            lock,
+        } = asset;
+
+        let (_, price) = price_list
+            .iter()
+            .find(|(addr, _)| addr == &token_addr)
+            .ok_or_else(|| to_std_err(ContractError::NoPrices {}))?;
+
+        if !requested.is_zero() && (counter <= env.block.time) {
+            unbonded += requested;
+            requested = Uint128::zero();
         }
+
+        let unlock_time = if requested.is_zero() {
+            None
+        } else {
+            Some(counter)
+        };
+
+        let value = |amount: Uint128| *price * u128_to_dec(amount);
+
+        let asset_summary = AssetSummary {
+            token_addr,
+            price: *price,
+            bonded,
+            bonded_value: value(bonded),
+            requested,
+            requested_value: value(requested),
+            unlock_time,
+            unbonded,
+            unbonded_value: value(unbonded),
+            rewards,
+            rewards_value: value(rewards),
+            lock: lock.filter(|x| x.end > env.block.time),
+        };
+
+        total_value += asset_summary.bonded_value
+            + asset_summary.requested_value
+            + asset_summary.unbonded_value
+            + asset_summary.rewards_value;
+
+        asset_summary_list.push(asset_summary);
     }
 
-    Ok(res)
+    Ok(ProviderSummary {
+        address,
+        asset_list: asset_summary_list,
+        total_value,
+    })
+}
+
+pub fn query_operators(deps: Deps, _env: Env, address: String) -> StdResult<Vec<Addr>> {
+    let provider_addr = deps.api.addr_validate(&address)?;
+
+    Ok(OPERATORS
+        .load(deps.storage, &provider_addr)
+        .unwrap_or_default())
+}
+
+pub fn query_auto_compound(deps: Deps, _env: Env, address: String) -> StdResult<bool> {
+    let provider_addr = deps.api.addr_validate(&address)?;
+
+    Ok(AUTO_COMPOUND
+        .load(deps.storage, &provider_addr)
+        .unwrap_or_default())
 }
 
+// returns all tokens or tokens from address_list
 pub fn query_tokens(
     deps: Deps,
     _env: Env,
     address_list: Vec<String>,
 ) -> StdResult<Vec<(Addr, Token)>> {
-    let mut res: Vec<(Addr, Token)> = vec![];
+    if address_list.is_empty() {
+        return TOKENS
+            .range(deps.storage, None, None, Order::Ascending)
+            .collect();
+    }
 
-    for (addr, token) in TOKENS
-        .range(deps.storage, None, None, Order::Ascending)
-        .flatten()
-    {
-        if address_list.is_empty() || address_list.contains(&addr.to_string()) {
-            res.push((addr, token));
-        }
+    query_list(deps, TOKENS, address_list)
+}
+
+// returns tokens from address_list or page of all tokens
+pub fn query_tokens_page(
+    deps: Deps,
+    _env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Token)>> {
+    check_address_list(&address_list, &start_after, limit)?;
+
+    if !address_list.is_empty() {
+        return Ok(Page {
+            list: query_list(deps, TOKENS, address_list)?,
+            cursor: None,
+        });
     }
 
-    Ok(res)
+    query_page(deps, TOKENS, start_after, limit)
+}
+
+// applies token query to page of tokens selected by address_list or pagination args
+fn query_token_page<T>(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    query: impl Fn(Deps, Env, Vec<String>) -> StdResult<Vec<T>>,
+) -> StdResult<Page<T>> {
+    let Page { list, cursor } =
+        query_tokens_page(deps, env.clone(), address_list, start_after, limit)?;
+
+    // empty address_list means all tokens for token queries
+    if list.is_empty() {
+        return Ok(Page {
+            list: vec![],
+            cursor,
+        });
+    }
+
+    let address_list = list.into_iter().map(|(addr, _)| addr.to_string()).collect();
+
+    Ok(Page {
+        list: query(deps, env, address_list)?,
+        cursor,
+    })
+}
+
+// weights are normalized over all tokens to be consistent between pages
+pub fn query_tokens_weight_page(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Decimal)>> {
+    query_token_page(
+        deps,
+        env,
+        address_list,
+        start_after,
+        limit,
+        |deps, env, address_list| {
+            Ok(query_tokens_weight(deps, env, vec![])?
+                .into_iter()
+                .filter(|(addr, _)| address_list.contains(&addr.to_string()))
+                .collect())
+        },
+    )
+}
+
+pub fn query_liquidity_page(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Uint128)>> {
+    query_token_page(deps, env, address_list, start_after, limit, query_liquidity)
+}
+
+pub fn query_balances_page(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<Balance>> {
+    query_token_page(deps, env, address_list, start_after, limit, query_balances)
+}
+
+pub fn query_prices_page(
+    deps: Deps,
+    env: Env,
+    address_list: Vec<String>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Decimal)>> {
+    query_token_page(deps, env, address_list, start_after, limit, query_prices)
+}
+
+pub fn query_token(deps: Deps, _env: Env, token_addr: String) -> StdResult<Token> {
+    TOKENS.load(deps.storage, &deps.api.addr_validate(&token_addr)?)
 }
 
 pub fn query_balances(deps: Deps, env: Env, address_list: Vec<String>) -> StdResult<Vec<Balance>> {
@@ -150,6 +1033,15 @@ pub fn query_balances(deps: Deps, env: Env, address_list: Vec<String>) -> StdRes
     Ok(response_list)
 }
 
+pub fn query_protocol_fees(
+    deps: Deps,
+    _env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, Uint128)>> {
+    query_page(deps, PROTOCOL_FEES, start_after, limit)
+}
+
 pub fn query_prices(
     deps: Deps,
     env: Env,
@@ -182,6 +1074,15 @@ pub fn query_prices(
     Ok(price_list)
 }
 
+// returns mocked prices for test chain and oracle prices otherwise
+pub fn query_prices_any(deps: Deps, env: Env) -> StdResult<Vec<(Addr, Decimal)>> {
+    if env.block.chain_id != CONFIG.load(deps.storage)?.get_chain_id() {
+        query_prices(deps, env, vec![])
+    } else {
+        query_prices_mocked(deps, env, vec![])
+    }
+}
+
 // for unit tests
 pub fn query_prices_mocked(
     deps: Deps,
@@ -207,3 +1108,113 @@ pub fn query_prices_mocked(
 
     Ok(price_list_mocked[..max_index].to_vec())
 }
+
+// address_list selects items explicitly, so pagination args can't be applied to it
+fn check_address_list(
+    address_list: &[String],
+    start_after: &Option<String>,
+    limit: Option<u32>,
+) -> StdResult<()> {
+    if !address_list.is_empty() && (start_after.is_some() || limit.is_some()) {
+        Err(to_std_err(ContractError::AddressListIsPaginated {}))?;
+    }
+
+    Ok(())
+}
+
+// loads map value for each address, missing ones are skipped
+fn query_list<T: Serialize + DeserializeOwned>(
+    deps: Deps,
+    map: Map<&Addr, T>,
+    address_list: Vec<String>,
+) -> StdResult<Vec<(Addr, T)>> {
+    let mut res: Vec<(Addr, T)> = vec![];
+
+    for address in address_list {
+        let address = deps.api.addr_validate(&address)?;
+
+        if let Some(value) = map.may_load(deps.storage, &address)? {
+            res.push((address, value));
+        }
+    }
+
+    Ok(res)
+}
+
+// returns up to limit map values with keys greater than start_after
+fn query_page<T: Serialize + DeserializeOwned>(
+    deps: Deps,
+    map: Map<&Addr, T>,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Page<(Addr, T)>> {
+    let start_after = start_after
+        .map(|x| deps.api.addr_validate(&x))
+        .transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+
+    let list = map
+        .range(
+            deps.storage,
+            start_after.as_ref().map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .collect::<StdResult<Vec<(Addr, T)>>>()?;
+
+    let cursor = if list.len() == limit {
+        list.last().map(|(addr, _)| addr.to_owned())
+    } else {
+        None
+    };
+
+    Ok(Page { list, cursor })
+}
+
+pub fn query_dca_schedules(
+    deps: Deps,
+    _env: Env,
+    owner: Option<String>,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> StdResult<Page<(u64, DcaSchedule), u64>> {
+    let owner = owner.map(|x| deps.api.addr_validate(&x)).transpose()?;
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let id_list = match owner {
+        Some(owner) => DCA_SCHEDULES_BY_OWNER
+            .prefix(&owner)
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<u64>>>()?,
+        None => DCA_SCHEDULES
+            .keys(deps.storage, start, None, Order::Ascending)
+            .take(limit)
+            .collect::<StdResult<Vec<u64>>>()?,
+    };
+
+    query_id_page(deps, DCA_SCHEDULES, id_list, limit)
+}
+
+// loads map values for ids taken from index, cursor is set if the page is full
+fn query_id_page<T: Serialize + DeserializeOwned>(
+    deps: Deps,
+    map: Map<u64, T>,
+    id_list: Vec<u64>,
+    limit: usize,
+) -> StdResult<Page<(u64, T), u64>> {
+    let cursor = if id_list.len() == limit {
+        id_list.last().copied()
+    } else {
+        None
+    };
+
+    let list = id_list
+        .into_iter()
+        .map(|id| Ok((id, map.load(deps.storage, id)?)))
+        .collect::<StdResult<Vec<(u64, T)>>>()?;
+
+    Ok(Page { list, cursor })
+}
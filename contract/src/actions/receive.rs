@@ -1,15 +1,39 @@
 #[cfg(not(feature = "library"))]
-use cosmwasm_std::{to_binary, Decimal, DepsMut, Env, MessageInfo, Response, Uint128, WasmMsg};
+use cosmwasm_std::{
+    to_binary, Addr, Decimal, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order, Response,
+    StdResult, Storage, SubMsg, Timestamp, Uint128, WasmMsg,
+};
 
 use cw20::Cw20ExecuteMsg;
 
 use crate::{
     actions::{
-        math::{calc_provider_rewards, calc_sma, u128_to_dec},
-        query::{query_prices, query_prices_mocked, query_providers, query_tokens},
+        execute::{
+            get_asset_event, get_cw20_transfer_msg, get_event, get_hook_msg_list, to_json_string,
+            update_bonded_total, update_incentives,
+        },
+        math::{
+            calc_amount_in, calc_price_deviation, calc_provider_rewards, calc_sma, calc_window_sum,
+            u128_to_dec,
+        },
+        query::{
+            query_bonded_total, query_fee_multiplier, query_liquidity, query_prices,
+            query_prices_mocked, query_providers, query_swap_fee_rate, query_tokens,
+        },
     },
     error::ContractError,
-    state::{Asset, Config, Sample, Token, CONFIG, PROVIDERS, TOKENS},
+    messages::{
+        hook::{IonFluxHookMsg, LiquidityAction},
+        receive::Referral,
+    },
+    state::{
+        Asset, CircuitBreaker, Config, DcaSchedule, LimitOrder, Lock, LockBoost, RateLimit, Sample,
+        Token, AUTO_COMPOUND, BLOCK_SWAP_VOLUME, CONFIG, DCA_COUNTER, DCA_SCHEDULES,
+        DCA_SCHEDULES_BY_OWNER, ESCROWED, HALTED_TOKENS, LIMIT_ORDERS, LIMIT_ORDERS_BY_OWNER,
+        LIMIT_ORDERS_BY_PAIR, LIMIT_ORDER_COUNTER, LOCK_BOOSTS, PRICES, PROTOCOL_FEES, PROVIDERS,
+        SWAP_VOLUMES, SWAP_VOLUMES_BY_TIME, SWAP_VOLUMES_PRUNE_LIMIT, TOKENS, TOKEN_CAPS,
+        TOKEN_SWAP_VOLUMES,
+    },
 };
 
 pub fn deposit(
@@ -18,6 +42,7 @@ pub fn deposit(
     info: MessageInfo,
     sender: String,
     amount: Uint128,
+    lock_duration: Option<Uint128>,
 ) -> Result<Response, ContractError> {
     let provider_addr = deps.api.addr_validate(&sender)?;
     let token_addr = info.sender;
@@ -29,6 +54,30 @@ pub fn deposit(
         .load(deps.storage, &token_addr)
         .map_err(|_| ContractError::TokenIsNotFound {})?;
 
+    let lock_boost = match lock_duration {
+        None => None,
+        Some(duration) => Some(
+            LOCK_BOOSTS
+                .may_load(deps.storage)?
+                .unwrap_or_default()
+                .into_iter()
+                .find(|x| x.duration == duration)
+                .ok_or(ContractError::LockDurationIsNotFound {})?,
+        ),
+    };
+
+    // check deposit cap
+    if let Some(amount_max) = TOKEN_CAPS
+        .may_load(deps.storage, &token_addr)?
+        .and_then(|x| x.max_deposit)
+    {
+        let bonded_total = query_bonded_total(deps.as_ref(), env.clone(), &token_addr)?;
+
+        if bonded_total + amount > amount_max {
+            Err(ContractError::DepositCapIsExceeded { amount_max })?;
+        }
+    }
+
     // check if provider exists or create new one
     let mut provider = PROVIDERS
         .load(deps.storage, &provider_addr)
@@ -50,6 +99,7 @@ pub fn deposit(
             mut bonded,
             ..
         } = asset;
+        let mut lock = asset.lock.clone();
 
         // update provider token data
         if !asset.requested.is_zero() && (asset.counter <= timestamp) {
@@ -66,12 +116,39 @@ pub fn deposit(
             bonded = bonded
                 .checked_add(amount)
                 .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+
+            // locked deposit relocks active locked amount until new end with new multiplier
+            if let Some(LockBoost {
+                duration,
+                multiplier,
+            }) = &lock_boost
+            {
+                let end = timestamp.plus_nanos(duration.u128() as u64);
+
+                let locked = match &asset.lock {
+                    Some(x) if x.end > timestamp => {
+                        if x.end > end {
+                            Err(ContractError::LockIsShortened {})?;
+                        }
+
+                        x.amount
+                    }
+                    _ => Uint128::zero(),
+                };
+
+                lock = Some(Lock {
+                    amount: locked + amount,
+                    end,
+                    multiplier: *multiplier,
+                });
+            }
         };
 
         provider_updated.push(Asset {
             unbonded,
             requested,
             bonded,
+            lock,
             ..asset.to_owned()
         });
 
@@ -101,9 +178,49 @@ pub fn deposit(
         )?;
     }
 
+    update_incentives(
+        deps.storage,
+        timestamp,
+        &token_addr,
+        std::slice::from_ref(&provider_addr),
+    )?;
+
     PROVIDERS.save(deps.storage, &provider_addr, &provider_updated)?;
+    update_bonded_total(deps.storage, &token_addr, amount, Uint128::zero())?;
 
-    Ok(Response::new().add_attributes(vec![("action", "deposit")]))
+    let mut response = Response::new();
+
+    if let Some(asset) = provider_updated.iter().find(|x| x.token_addr == token_addr) {
+        response = response
+            .add_event(
+                get_asset_event("deposit", &provider_addr, asset)
+                    .add_attribute("amount", amount.to_string()),
+            )
+            .add_submessages(get_hook_msg_list(
+                deps.storage,
+                &IonFluxHookMsg::LiquidityChanged {
+                    action: LiquidityAction::Deposit,
+                    provider: provider_addr.clone(),
+                    amount,
+                    asset: asset.to_owned(),
+                },
+            )?);
+    }
+
+    Ok(response.add_attributes(vec![("action", "deposit")]))
+}
+
+// returns amount which can be bonded until deposit cap is reached, None if there is no cap
+pub fn get_deposit_room(deps: Deps, env: Env, token_addr: &Addr) -> StdResult<Option<Uint128>> {
+    match TOKEN_CAPS
+        .may_load(deps.storage, token_addr)?
+        .and_then(|x| x.max_deposit)
+    {
+        Some(amount_max) => Ok(Some(
+            amount_max.saturating_sub(query_bonded_total(deps, env, token_addr)?),
+        )),
+        None => Ok(None),
+    }
 }
 
 pub fn swap(
@@ -113,6 +230,7 @@ pub fn swap(
     sender: String,
     amount_in: Uint128,
     token_out_addr: String,
+    referral: Option<Referral>,
 ) -> Result<Response, ContractError> {
     let token_in_addr = info.sender.clone();
     let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
@@ -143,11 +261,121 @@ pub fn swap(
         token_out_addr.to_string(),
         *token_in_price,
         *token_out_price,
+        referral,
+        None,
     )
+    .map(|(response, _)| response)
+}
+
+// sent amount is max amount_in, required amount_in is calculated from prices and fees
+// excess of amount_in is refunded to sender
+#[allow(clippy::too_many_arguments)]
+pub fn swap_exact_out(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    amount_in_max: Uint128,
+    token_out_addr: String,
+    amount_out: Uint128,
+    referral: Option<Referral>,
+) -> Result<Response, ContractError> {
+    let token_in_addr = info.sender.clone();
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+    let user_addr = deps.api.addr_validate(&sender)?;
+    let config = CONFIG.load(deps.storage)?;
+
+    let price_list = if env.block.chain_id != config.get_chain_id() {
+        query_prices(deps.as_ref(), env.clone(), vec![])
+    } else {
+        query_prices_mocked(deps.as_ref(), env.clone(), vec![])
+    }
+    .map_err(|_| ContractError::NoPrices {})?;
+
+    let (_, token_in_price) = price_list
+        .iter()
+        .find(|(addr, _price)| addr == &token_in_addr)
+        .ok_or(ContractError::TokenIsNotFound {})?;
+
+    let (_, token_out_price) = price_list
+        .iter()
+        .find(|(addr, _price)| addr == &token_out_addr)
+        .ok_or(ContractError::TokenIsNotFound {})?;
+
+    // the same fee rate is applied in swap
+    let swap_fee_rate =
+        query_swap_fee_rate(
+            deps.as_ref(),
+            env.clone(),
+            token_in_addr.to_string(),
+            token_out_addr.to_string(),
+        )? * query_fee_multiplier(deps.as_ref(), env.clone(), user_addr.to_string())?;
+
+    let amount_in_net =
+        calc_amount_in(amount_out, *token_in_price, *token_out_price, swap_fee_rate)?;
+
+    // referral commission is taken from amount_in before swap fee
+    let amount_in = match &referral {
+        Some(Referral {
+            commission_rate, ..
+        }) => {
+            if commission_rate > &config.max_referral_commission_rate {
+                Err(ContractError::ReferralCommissionIsTooHigh {})?;
+            }
+
+            let calc_amount_in_net = |amount_in: Uint128| {
+                amount_in - (commission_rate * u128_to_dec(amount_in)).to_uint_floor()
+            };
+
+            // floor is used as amount_in_net is already rounded up
+            let mut amount_in =
+                (u128_to_dec(amount_in_net) / (Decimal::one() - commission_rate)).to_uint_floor();
+
+            // compensate decimal rounding
+            while calc_amount_in_net(amount_in) < amount_in_net {
+                amount_in += Uint128::one();
+            }
+
+            amount_in
+        }
+        None => amount_in_net,
+    };
+
+    if amount_in > amount_in_max {
+        Err(ContractError::AmountInIsTooHigh {
+            amount_in,
+            amount_in_max,
+        })?;
+    }
+
+    let (response, _) = swap_accepting_prices(
+        deps,
+        env,
+        info,
+        sender,
+        amount_in,
+        token_out_addr.to_string(),
+        *token_in_price,
+        *token_out_price,
+        referral,
+        Some(amount_out),
+    )?;
+
+    let refund = amount_in_max - amount_in;
+
+    if refund.is_zero() {
+        return Ok(response);
+    }
+
+    Ok(response
+        .add_message(get_cw20_transfer_msg(&token_in_addr, &user_addr, refund)?)
+        .add_attributes(vec![("refund", refund.to_string())]))
 }
 
+// returns response and amount_out, amount_out is zero if amount_in is refunded by circuit breaker
+// amount_out is limited by amount_out_max, rounding excess stays in pool
 #[allow(clippy::too_many_arguments)]
-fn swap_accepting_prices(
+pub fn swap_accepting_prices(
     deps: DepsMut,
     env: Env,
     info: MessageInfo,
@@ -156,7 +384,9 @@ fn swap_accepting_prices(
     token_out_addr: String,
     token_in_price: Decimal,
     token_out_price: Decimal,
-) -> Result<Response, ContractError> {
+    referral: Option<Referral>,
+    amount_out_max: Option<Uint128>,
+) -> Result<(Response, Uint128), ContractError> {
     let token_in_addr = info.sender;
     let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
 
@@ -169,27 +399,201 @@ fn swap_accepting_prices(
     let Config {
         swap_fee_rate,
         window,
+        protocol_fee_share,
+        max_referral_commission_rate,
+        circuit_breaker,
+        rate_limit,
         ..
     } = CONFIG.load(deps.storage)?;
+
+    // tripped circuit breaker refunds amount_in instead of failing to keep halt flag stored
+    let event_list = check_circuit_breaker(
+        deps.storage,
+        timestamp,
+        window,
+        &circuit_breaker,
+        &[
+            (token_in_addr.clone(), token_in_price),
+            (token_out_addr.clone(), token_out_price),
+        ],
+    )?;
+
+    if !event_list.is_empty() {
+        let cw_send_msg = Cw20ExecuteMsg::Transfer {
+            recipient: user_addr.to_string(),
+            amount: amount_in,
+        };
+
+        let msg = WasmMsg::Execute {
+            contract_addr: token_in_addr.to_string(),
+            msg: to_binary(&cw_send_msg)?,
+            funds: vec![],
+        };
+
+        return Ok((
+            Response::new()
+                .add_message(msg)
+                .add_events(event_list)
+                .add_attributes(vec![("action", "swap"), ("is_halted", "true")]),
+            Uint128::zero(),
+        ));
+    }
+
+    // check swapped value at oracle prices
+    update_swap_volumes(
+        deps.storage,
+        env.block.height,
+        timestamp,
+        window,
+        &rate_limit,
+        &user_addr,
+        (token_in_price * u128_to_dec(amount_in)).to_uint_floor(),
+    )?;
+
+    // apply discount based on user holdings
+    let fee_rate = query_swap_fee_rate(
+        deps.as_ref(),
+        env.clone(),
+        token_in_addr.to_string(),
+        token_out_addr.to_string(),
+    )? * query_fee_multiplier(deps.as_ref(), env.clone(), user_addr.to_string())?;
     let provider_list = query_providers(deps.as_ref(), env.clone(), vec![])?;
-    let token_list = query_tokens(deps.as_ref(), env, vec![])?;
+    let token_list = query_tokens(deps.as_ref(), env.clone(), vec![])?;
+
+    // take referral commission before swap fee
+    let amount_in_gross = amount_in;
+    let (referral_addr, referral_commission) = match referral {
+        Some(Referral {
+            address,
+            commission_rate,
+        }) => {
+            if commission_rate > max_referral_commission_rate {
+                Err(ContractError::ReferralCommissionIsTooHigh {})?;
+            }
+
+            (
+                Some(deps.api.addr_validate(&address)?),
+                (commission_rate * u128_to_dec(amount_in)).to_uint_floor(),
+            )
+        }
+        None => (None, Uint128::zero()),
+    };
+    let amount_in = amount_in - referral_commission;
 
     // distribute rewards to providers
-    let swap_fee = swap_fee_rate * u128_to_dec(amount_in);
+    let swap_fee = fee_rate * u128_to_dec(amount_in);
     let amount_in_clean = amount_in - swap_fee.to_uint_ceil();
+    let protocol_fee = (protocol_fee_share * swap_fee).to_uint_floor();
 
     let (provider_rewards_list, amount_out) = calc_provider_rewards(
         amount_in,
         token_in_price,
         token_out_price,
         swap_fee_rate,
+        fee_rate,
+        protocol_fee_share,
         provider_list,
         token_list,
+        timestamp,
     )?;
+    let amount_out = amount_out_max.map_or(amount_out, |x| amount_out.min(x));
+
+    // check swap caps
+    if let Some(token_caps) = TOKEN_CAPS.may_load(deps.storage, &token_out_addr)? {
+        if let Some(max_swap_out_ratio) = token_caps.max_swap_out_ratio {
+            let liquidity =
+                query_liquidity(deps.as_ref(), env.clone(), vec![token_out_addr.to_string()])?
+                    .into_iter()
+                    .fold(Uint128::zero(), |acc, (_, x)| acc + x);
+            let amount_max = (max_swap_out_ratio * u128_to_dec(liquidity)).to_uint_floor();
+
+            if amount_out > amount_max {
+                Err(ContractError::SwapCapIsExceeded { amount_max })?;
+            }
+        }
+
+        // net_outflow = sum_for_window(swapped_out) + amount_out - sum_for_window(swapped_in)
+        if let Some(amount_max) = token_caps.max_window_outflow {
+            let (swapped_in, swapped_out) = TOKEN_SWAP_VOLUMES
+                .may_load(deps.storage, &token_out_addr)?
+                .unwrap_or_default();
+
+            let outflow = calc_window_sum(&swapped_out, timestamp, window) + amount_out;
+            let inflow = calc_window_sum(&swapped_in, timestamp, window);
+
+            if outflow.saturating_sub(inflow) > amount_max {
+                Err(ContractError::OutflowCapIsExceeded { amount_max })?;
+            }
+        }
+    }
+
+    // accumulate protocol part of swap fee
+    if !protocol_fee.is_zero() {
+        PROTOCOL_FEES.update(
+            deps.storage,
+            &token_in_addr,
+            |some_fees| -> Result<Uint128, ContractError> {
+                some_fees
+                    .unwrap_or_default()
+                    .checked_add(protocol_fee)
+                    .map_err(|e| ContractError::CustomError { val: e.to_string() })
+            },
+        )?;
+    }
+
+    // auto-compounded rewards change bonded amounts
+    let compounding_addr_list: Vec<Addr> = provider_rewards_list
+        .iter()
+        .filter(|(addr, rewards)| {
+            !rewards.is_zero() && AUTO_COMPOUND.load(deps.storage, addr).unwrap_or_default()
+        })
+        .map(|(addr, _)| addr.to_owned())
+        .collect();
+
+    if !compounding_addr_list.is_empty() {
+        update_incentives(
+            deps.storage,
+            timestamp,
+            &token_in_addr,
+            &compounding_addr_list,
+        )?;
+    }
+
+    // auto-compound is limited by deposit cap, excess is kept as rewards
+    let mut deposit_room = if compounding_addr_list.is_empty() {
+        None
+    } else {
+        get_deposit_room(deps.as_ref(), env.clone(), &token_in_addr)?
+    };
+
+    let mut compounded = Uint128::zero();
+    let mut rewards_total = Uint128::zero();
+    let mut provider_count: u32 = 0;
+    let mut liquidity_hook_msg_list: Vec<SubMsg> = vec![];
 
     // update assets for each provider
     for (provider_addr, provider_rewards) in provider_rewards_list {
-        PROVIDERS.update(
+        if !provider_rewards.is_zero() {
+            rewards_total += provider_rewards;
+            provider_count += 1;
+        }
+
+        let is_auto_compound = AUTO_COMPOUND
+            .load(deps.storage, &provider_addr)
+            .unwrap_or_default();
+
+        let provider_compounded = match (is_auto_compound, deposit_room.as_mut()) {
+            (false, _) => Uint128::zero(),
+            (true, None) => provider_rewards,
+            (true, Some(room)) => {
+                let amount = provider_rewards.min(*room);
+                *room -= amount;
+                amount
+            }
+        };
+        compounded += provider_compounded;
+
+        let asset_list = PROVIDERS.update(
             deps.storage,
             &provider_addr,
             |some_asset_list| -> Result<Vec<Asset>, ContractError> {
@@ -210,37 +614,76 @@ fn swap_accepting_prices(
                     list_updated.push(asset);
                 }
 
-                // update rewards
-                if let Some(asset_in) = list_updated
+                // update rewards or bond them if auto-compound is enabled
+                if !list_updated
+                    .iter()
+                    .any(|asset| asset.token_addr == token_in_addr)
+                {
+                    list_updated.push(Asset::new(&token_in_addr, &timestamp));
+                }
+
+                for asset_in in list_updated
                     .iter_mut()
-                    .find(|asset| asset.token_addr == token_in_addr)
+                    .filter(|asset| asset.token_addr == token_in_addr)
                 {
-                    asset_in.rewards = provider_rewards;
-                } else {
-                    let mut asset_in = Asset::new(&token_in_addr, &timestamp);
-                    asset_in.rewards = provider_rewards;
-                    list_updated.push(asset_in);
+                    if is_auto_compound {
+                        asset_in.bonded = asset_in
+                            .bonded
+                            .checked_add(provider_compounded)
+                            .map_err(|e| ContractError::CustomError { val: e.to_string() })?;
+                        asset_in.rewards += provider_rewards - provider_compounded;
+                    } else {
+                        asset_in.rewards = provider_rewards;
+                    }
                 }
 
                 Ok(list_updated)
             },
         )?;
+
+        if !provider_compounded.is_zero() {
+            if let Some(asset) = asset_list.iter().find(|x| x.token_addr == token_in_addr) {
+                liquidity_hook_msg_list.append(&mut get_hook_msg_list(
+                    deps.storage,
+                    &IonFluxHookMsg::LiquidityChanged {
+                        action: LiquidityAction::Compound,
+                        provider: provider_addr.clone(),
+                        amount: provider_compounded,
+                        asset: asset.to_owned(),
+                    },
+                )?);
+            }
+        }
+    }
+
+    if !compounded.is_zero() {
+        update_bonded_total(deps.storage, &token_in_addr, compounded, Uint128::zero())?;
     }
 
     // update sma values
-    // swapped_in
+    // swapped_in, bonded
     TOKENS.update(
         deps.storage,
         &token_in_addr,
         |some_token| -> Result<Token, ContractError> {
             let token = some_token.ok_or(ContractError::TokenIsNotFound {})?;
 
+            // compound decreases APR
+            let bonded = if compounded.is_zero() {
+                token.bonded.clone()
+            } else {
+                calc_sma(&token.bonded.0, &Sample::new(compounded, timestamp), window)?
+            };
+
             Ok(Token {
+                bonded,
                 swapped_in: calc_sma(
                     &token.swapped_in.0,
                     &Sample::new(amount_in_clean, timestamp),
                     window,
                 )?,
+                swapped_in_total: token.swapped_in_total + amount_in,
+                fees_total: token.fees_total + (amount_in - amount_in_clean),
                 ..token
             })
         },
@@ -259,11 +702,30 @@ fn swap_accepting_prices(
                     &Sample::new(amount_out, timestamp),
                     window,
                 )?,
+                swapped_out_total: token.swapped_out_total + amount_out,
                 ..token
             })
         },
     )?;
 
+    // sma samples are averaged, window sums use swapped amounts
+    update_token_swap_volumes(
+        deps.storage,
+        timestamp,
+        window,
+        &token_in_addr,
+        amount_in_clean,
+        Uint128::zero(),
+    )?;
+    update_token_swap_volumes(
+        deps.storage,
+        timestamp,
+        window,
+        &token_out_addr,
+        Uint128::zero(),
+        amount_out,
+    )?;
+
     // send token
     let cw_send_msg = Cw20ExecuteMsg::Transfer {
         recipient: user_addr.to_string(),
@@ -276,7 +738,417 @@ fn swap_accepting_prices(
         funds: vec![],
     };
 
+    let mut swap_event = get_event("swap").add_attributes(vec![
+        ("user", user_addr.to_string()),
+        ("token_in_addr", token_in_addr.to_string()),
+        ("token_out_addr", token_out_addr.to_string()),
+        ("amount_in", amount_in_gross.to_string()),
+        ("swap_fee_rate", fee_rate.to_string()),
+        ("fee", (amount_in - amount_in_clean).to_string()),
+        ("protocol_fee", protocol_fee.to_string()),
+        ("amount_out", amount_out.to_string()),
+        ("token_in_price", token_in_price.to_string()),
+        ("token_out_price", token_out_price.to_string()),
+    ]);
+
+    let rewards_event = get_event("rewards_distributed").add_attributes(vec![
+        ("token_addr", token_in_addr.to_string()),
+        ("provider_count", provider_count.to_string()),
+        ("rewards", rewards_total.to_string()),
+        ("compounded", compounded.to_string()),
+    ]);
+
+    let mut response = Response::new().add_message(msg).add_attributes(vec![
+        ("action", "swap"),
+        ("swap_fee_rate", &fee_rate.to_string()),
+    ]);
+
+    // send referral commission
+    if let Some(addr) = referral_addr {
+        if !referral_commission.is_zero() {
+            let cw_send_msg = Cw20ExecuteMsg::Transfer {
+                recipient: addr.to_string(),
+                amount: referral_commission,
+            };
+
+            response = response.add_message(WasmMsg::Execute {
+                contract_addr: token_in_addr.to_string(),
+                msg: to_binary(&cw_send_msg)?,
+                funds: vec![],
+            });
+        }
+
+        response = response.add_attributes(vec![
+            ("referral", addr.to_string()),
+            ("referral_commission", referral_commission.to_string()),
+        ]);
+        swap_event = swap_event.add_attributes(vec![
+            ("referral", addr.to_string()),
+            ("referral_commission", referral_commission.to_string()),
+        ]);
+    }
+
+    let hook_msg_list = get_hook_msg_list(
+        deps.storage,
+        &IonFluxHookMsg::Swap {
+            user: user_addr,
+            token_in_addr,
+            token_out_addr,
+            amount_in: amount_in_gross,
+            fee: amount_in - amount_in_clean,
+            amount_out,
+        },
+    )?;
+
+    Ok((
+        response
+            .add_submessages(liquidity_hook_msg_list)
+            .add_submessages(hook_msg_list)
+            .add_events(vec![swap_event, rewards_event]),
+        amount_out,
+    ))
+}
+
+// amount_in is escrowed until order is executed, cancelled or expired
+#[allow(clippy::too_many_arguments)]
+pub fn place_limit_order(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    amount_in: Uint128,
+    token_out_addr: String,
+    min_price: Decimal,
+    expiry: Timestamp,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&sender)?;
+    let token_in_addr = info.sender;
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+
+    if token_in_addr == token_out_addr {
+        Err(ContractError::SameTokens {})?
+    }
+
+    // check if tokens are supported
+    for token_addr in [&token_in_addr, &token_out_addr] {
+        TOKENS
+            .load(deps.storage, token_addr)
+            .map_err(|_| ContractError::TokenIsNotFound {})?;
+    }
+
+    if expiry <= env.block.time {
+        Err(ContractError::OrderExpiryIsOutOfRange {})?;
+    }
+
+    let id = LIMIT_ORDER_COUNTER
+        .may_load(deps.storage)?
+        .unwrap_or_default()
+        + 1;
+    LIMIT_ORDER_COUNTER.save(deps.storage, &id)?;
+
+    let order = LimitOrder {
+        owner,
+        token_in_addr,
+        token_out_addr,
+        amount_in,
+        min_price,
+        expiry,
+    };
+
+    LIMIT_ORDERS.save(deps.storage, id, &order)?;
+    LIMIT_ORDERS_BY_OWNER.save(deps.storage, (&order.owner, id), &Empty {})?;
+    LIMIT_ORDERS_BY_PAIR.save(
+        deps.storage,
+        (&order.token_in_addr, &order.token_out_addr, id),
+        &Empty {},
+    )?;
+    ESCROWED.update(
+        deps.storage,
+        &order.token_in_addr,
+        |some_amount| -> StdResult<Uint128> { Ok(some_amount.unwrap_or_default() + amount_in) },
+    )?;
+
     Ok(Response::new()
-        .add_message(msg)
-        .add_attributes(vec![("action", "swap")]))
+        .add_event(get_event("place_limit_order").add_attributes(vec![
+            ("id", id.to_string()),
+            ("owner", order.owner.to_string()),
+            ("token_in_addr", order.token_in_addr.to_string()),
+            ("token_out_addr", order.token_out_addr.to_string()),
+            ("amount_in", amount_in.to_string()),
+            ("min_price", min_price.to_string()),
+            ("expiry", expiry.to_string()),
+        ]))
+        .add_attributes(vec![("action", "place_limit_order")]))
+}
+
+// balance is escrowed until it's spent by keepers or refunded on cancel
+#[allow(clippy::too_many_arguments)]
+pub fn create_dca(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    sender: String,
+    amount_in: Uint128,
+    token_out_addr: String,
+    amount_per_interval: Uint128,
+    interval: Uint128,
+    slippage: Decimal,
+) -> Result<Response, ContractError> {
+    let owner = deps.api.addr_validate(&sender)?;
+    let token_in_addr = info.sender;
+    let token_out_addr = deps.api.addr_validate(&token_out_addr)?;
+
+    if token_in_addr == token_out_addr {
+        Err(ContractError::SameTokens {})?
+    }
+
+    // check if tokens are supported
+    for token_addr in [&token_in_addr, &token_out_addr] {
+        TOKENS
+            .load(deps.storage, token_addr)
+            .map_err(|_| ContractError::TokenIsNotFound {})?;
+    }
+
+    if amount_per_interval.is_zero()
+        || amount_per_interval > amount_in
+        || interval.is_zero()
+        || slippage >= Decimal::one()
+    {
+        Err(ContractError::DcaIsOutOfRange {})?;
+    }
+
+    let id = DCA_COUNTER.may_load(deps.storage)?.unwrap_or_default() + 1;
+    DCA_COUNTER.save(deps.storage, &id)?;
+
+    // first swap is due immediately
+    let schedule = DcaSchedule {
+        owner,
+        token_in_addr,
+        token_out_addr,
+        amount_per_interval,
+        interval,
+        slippage,
+        balance: amount_in,
+        next_execution: env.block.time,
+        executed_count: 0,
+        amount_in_total: Uint128::zero(),
+        amount_out_total: Uint128::zero(),
+    };
+
+    DCA_SCHEDULES.save(deps.storage, id, &schedule)?;
+    DCA_SCHEDULES_BY_OWNER.save(deps.storage, (&schedule.owner, id), &Empty {})?;
+    ESCROWED.update(
+        deps.storage,
+        &schedule.token_in_addr,
+        |some_amount| -> StdResult<Uint128> { Ok(some_amount.unwrap_or_default() + amount_in) },
+    )?;
+
+    Ok(Response::new()
+        .add_event(get_event("create_dca").add_attributes(vec![
+            ("id", id.to_string()),
+            ("schedule", to_json_string(&schedule)?),
+        ]))
+        .add_attributes(vec![("action", "create_dca")]))
+}
+
+// checks price deviation of each token if circuit breaker is enabled
+// returns events of tripped breakers, caller must not fail after trip to keep halt flag stored
+pub fn check_circuit_breaker(
+    storage: &mut dyn Storage,
+    timestamp: Timestamp,
+    window: Uint128,
+    circuit_breaker: &CircuitBreaker,
+    token_price_list: &[(Addr, Decimal)],
+) -> Result<Vec<Event>, ContractError> {
+    let mut event_list: Vec<Event> = vec![];
+
+    if !circuit_breaker.is_enabled {
+        return Ok(event_list);
+    }
+
+    let mut checked_list: Vec<&Addr> = vec![];
+
+    for (token_addr, price) in token_price_list {
+        if checked_list.contains(&token_addr) {
+            continue;
+        }
+
+        checked_list.push(token_addr);
+
+        if let Some(event) = check_price_deviation(
+            storage,
+            timestamp,
+            window,
+            circuit_breaker,
+            token_addr,
+            *price,
+        )? {
+            event_list.push(event);
+        }
+    }
+
+    Ok(event_list)
+}
+
+// updates price sma of token and returns event if circuit breaker is tripped
+// halted token is released when its price returns to range of price sma
+pub fn check_price_deviation(
+    storage: &mut dyn Storage,
+    timestamp: Timestamp,
+    window: Uint128,
+    circuit_breaker: &CircuitBreaker,
+    token_addr: &Addr,
+    price: Decimal,
+) -> Result<Option<Event>, ContractError> {
+    let (price_list, price_average) = PRICES.may_load(storage, token_addr)?.unwrap_or_default();
+    let price_average = Decimal::new(price_average);
+
+    let is_deviated = !price_average.is_zero()
+        && calc_price_deviation(price, price_average)? > circuit_breaker.max_price_deviation;
+    let is_halted = HALTED_TOKENS.has(storage, token_addr);
+
+    if is_deviated {
+        if is_halted {
+            Err(ContractError::TokenIsHalted {})?;
+        }
+
+        HALTED_TOKENS.save(storage, token_addr, &true)?;
+
+        return Ok(Some(get_event("circuit_breaker").add_attributes(vec![
+            ("token_addr", token_addr.to_string()),
+            ("price", price.to_string()),
+            ("price_average", price_average.to_string()),
+        ])));
+    }
+
+    if is_halted {
+        HALTED_TOKENS.remove(storage, token_addr);
+    }
+
+    // empty history is filled with current price on whole window to avoid zero boundary sample
+    let price_list = if price_list.is_empty() {
+        vec![Sample::new(
+            price.atomics(),
+            timestamp.minus_nanos(window.u128() as u64),
+        )]
+    } else {
+        price_list
+    };
+
+    PRICES.save(
+        storage,
+        token_addr,
+        &calc_sma(
+            &price_list,
+            &Sample::new(price.atomics(), timestamp),
+            window,
+        )?,
+    )?;
+
+    Ok(None)
+}
+
+// appends swapped amounts of token dropping expired ones
+fn update_token_swap_volumes(
+    storage: &mut dyn Storage,
+    timestamp: Timestamp,
+    window: Uint128,
+    token_addr: &Addr,
+    swapped_in: Uint128,
+    swapped_out: Uint128,
+) -> StdResult<()> {
+    let boundary_timestamp =
+        Timestamp::from_nanos(timestamp.nanos().saturating_sub(window.u128() as u64));
+
+    let update = |sample_list: Vec<Sample>, value: Uint128| -> Vec<Sample> {
+        let mut sample_list: Vec<Sample> = sample_list
+            .into_iter()
+            .filter(|x| x.timestamp > boundary_timestamp)
+            .collect();
+
+        if !value.is_zero() {
+            sample_list.push(Sample::new(value, timestamp));
+        }
+
+        sample_list
+    };
+
+    let (swapped_in_list, swapped_out_list) = TOKEN_SWAP_VOLUMES
+        .may_load(storage, token_addr)?
+        .unwrap_or_default();
+
+    TOKEN_SWAP_VOLUMES.save(
+        storage,
+        token_addr,
+        &(
+            update(swapped_in_list, swapped_in),
+            update(swapped_out_list, swapped_out),
+        ),
+    )
+}
+
+// updates swapped value counters dropping expired ones and checks rate limits
+fn update_swap_volumes(
+    storage: &mut dyn Storage,
+    height: u64,
+    timestamp: Timestamp,
+    window: Uint128,
+    rate_limit: &RateLimit,
+    user_addr: &Addr,
+    value: Uint128,
+) -> Result<(), ContractError> {
+    let boundary_timestamp =
+        Timestamp::from_nanos(timestamp.nanos().saturating_sub(window.u128() as u64));
+
+    if let Some(amount_max) = rate_limit.max_address_volume {
+        let sample_list_stored = SWAP_VOLUMES
+            .may_load(storage, user_addr)?
+            .unwrap_or_default();
+
+        // index entry is keyed by last sample
+        if let Some(x) = sample_list_stored.last() {
+            SWAP_VOLUMES_BY_TIME.remove(storage, (x.timestamp.nanos(), user_addr));
+        }
+
+        let mut sample_list: Vec<Sample> = sample_list_stored
+            .into_iter()
+            .filter(|x| x.timestamp > boundary_timestamp)
+            .collect();
+
+        if calc_window_sum(&sample_list, timestamp, window) + value > amount_max {
+            Err(ContractError::AddressVolumeIsExceeded { amount_max })?;
+        }
+
+        sample_list.push(Sample::new(value, timestamp));
+        SWAP_VOLUMES.save(storage, user_addr, &sample_list)?;
+        SWAP_VOLUMES_BY_TIME.save(storage, (timestamp.nanos(), user_addr), &Empty {})?;
+    }
+
+    // drop volumes of addresses which didn't swap during window
+    let expired_list = SWAP_VOLUMES_BY_TIME
+        .keys(storage, None, None, Order::Ascending)
+        .take(SWAP_VOLUMES_PRUNE_LIMIT)
+        .collect::<StdResult<Vec<(u64, Addr)>>>()?
+        .into_iter()
+        .filter(|(last_timestamp, _)| *last_timestamp <= boundary_timestamp.nanos());
+
+    for (last_timestamp, address) in expired_list {
+        SWAP_VOLUMES.remove(storage, &address);
+        SWAP_VOLUMES_BY_TIME.remove(storage, (last_timestamp, &address));
+    }
+
+    if let Some(amount_max) = rate_limit.max_block_volume {
+        let block_volume = match BLOCK_SWAP_VOLUME.may_load(storage)? {
+            Some((block_height, volume)) if block_height == height => volume,
+            _ => Uint128::zero(),
+        } + value;
+
+        if block_volume > amount_max {
+            Err(ContractError::BlockVolumeIsExceeded { amount_max })?;
+        }
+
+        BLOCK_SWAP_VOLUME.save(storage, &(height, block_volume))?;
+    }
+
+    Ok(())
 }
@@ -0,0 +1,14 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::{DepsMut, Env, Reply, Response, SubMsgResult};
+
+use crate::{actions::execute::get_event, error::ContractError, state::HOOK_REPLY_ID};
+
+// failed best-effort hook notification doesn't revert action
+pub fn handle_reply(_deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, ContractError> {
+    match (reply.id, reply.result) {
+        (HOOK_REPLY_ID, SubMsgResult::Err(error)) => Ok(Response::new()
+            .add_event(get_event("hook_failed").add_attribute("error", error))
+            .add_attributes(vec![("action", "hook_failed")])),
+        _ => Err(ContractError::UnknownReply { id: reply.id }),
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::{
-    entry_point, from_binary, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    entry_point, from_binary, to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response,
     StdResult,
 };
 
@@ -8,14 +8,28 @@ use cw20::Cw20ReceiveMsg;
 
 use crate::{
     actions::{
-        execute::{claim, swap_and_claim, unbond, update_config, update_token, withdraw},
+        execute::{
+            batch_swap, cancel_dca, cancel_order, claim, claim_protocol_fees, close_incentive,
+            compound, create_incentive, execute_dca, execute_orders, reset_halt, revoke_operator,
+            set_auto_compound, set_operator, swap_and_claim, transfer_position, unbond,
+            update_config, update_fee_discounts, update_hook, update_lock_boosts, update_pair_fee,
+            update_snapshots, update_token, update_token_caps, update_token_fee, withdraw,
+        },
         instantiate::init,
         migrate::migrate_contract,
         query::{
-            query_balances, query_config, query_liquidity, query_prices, query_prices_mocked,
-            query_providers, query_tokens, query_tokens_weight,
+            query_auto_compound, query_balances_page, query_config, query_dca_schedules,
+            query_fee_discounts, query_fee_multiplier, query_fees, query_halted_tokens,
+            query_history, query_hooks, query_incentive_rewards, query_incentives,
+            query_limit_orders_by_owner, query_limit_orders_by_pair, query_liquidity_page,
+            query_lock_boosts, query_operators, query_pool_stats, query_price_averages,
+            query_prices_mocked, query_prices_page, query_protocol_fees, query_provider,
+            query_provider_apr, query_provider_summary, query_providers_page,
+            query_swap_allowances, query_swap_fee_rate, query_token, query_token_apr,
+            query_token_caps, query_tokens_page, query_tokens_weight_page,
         },
-        receive::{deposit, swap},
+        receive::{create_dca, deposit, place_limit_order, swap, swap_exact_out},
+        reply::handle_reply,
     },
     error::ContractError,
     messages::{
@@ -38,12 +52,30 @@ pub fn instantiate(
 /// Exposes all the execute functions available in the contract
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn execute(
-    deps: DepsMut,
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, ContractError> {
-    match msg {
+    // snapshots are written only before user actions which can change token state
+    let snapshot_event_list = if matches!(
+        msg,
+        ExecuteMsg::Receive(_)
+            | ExecuteMsg::Unbond { .. }
+            | ExecuteMsg::Withdraw { .. }
+            | ExecuteMsg::ExecuteOrders { .. }
+            | ExecuteMsg::ExecuteDca { .. }
+            | ExecuteMsg::Claim { .. }
+            | ExecuteMsg::BatchSwap { .. }
+            | ExecuteMsg::SwapAndClaim { .. }
+            | ExecuteMsg::Compound { .. }
+    ) {
+        update_snapshots(deps.branch(), &env)?
+    } else {
+        vec![]
+    };
+
+    let response = match msg {
         ExecuteMsg::Receive(msg) => receive(deps, env, info, msg),
         ExecuteMsg::UpdateConfig {
             admin,
@@ -51,6 +83,14 @@ pub fn execute(
             window,
             unbonding_period,
             price_age,
+            protocol_fee_share,
+            treasury,
+            dynamic_fee,
+            max_referral_commission_rate,
+            circuit_breaker,
+            rate_limit,
+            epoch,
+            keeper_tip_rate,
         } => update_config(
             deps,
             env,
@@ -60,21 +100,110 @@ pub fn execute(
             window,
             unbonding_period,
             price_age,
+            protocol_fee_share,
+            treasury,
+            dynamic_fee,
+            max_referral_commission_rate,
+            circuit_breaker,
+            rate_limit,
+            epoch,
+            keeper_tip_rate,
         ),
         ExecuteMsg::UpdateToken {
             token_addr,
             symbol,
             price_feed_id_str,
         } => update_token(deps, env, info, token_addr, symbol, price_feed_id_str),
-        ExecuteMsg::Unbond { token_addr, amount } => unbond(deps, env, info, token_addr, amount),
+        ExecuteMsg::UpdateTokenFee {
+            token_addr,
+            swap_fee_rate,
+        } => update_token_fee(deps, env, info, token_addr, swap_fee_rate),
+        ExecuteMsg::UpdateTokenCaps {
+            token_addr,
+            token_caps,
+        } => update_token_caps(deps, env, info, token_addr, token_caps),
+        ExecuteMsg::UpdatePairFee {
+            token_in_addr,
+            token_out_addr,
+            swap_fee_rate,
+        } => update_pair_fee(
+            deps,
+            env,
+            info,
+            token_in_addr,
+            token_out_addr,
+            swap_fee_rate,
+        ),
+        ExecuteMsg::ResetHalt { token_addr } => reset_halt(deps, env, info, token_addr),
+        ExecuteMsg::UpdateFeeDiscounts { fee_discount_list } => {
+            update_fee_discounts(deps, env, info, fee_discount_list)
+        }
+        ExecuteMsg::UpdateLockBoosts { lock_boost_list } => {
+            update_lock_boosts(deps, env, info, lock_boost_list)
+        }
+        ExecuteMsg::UpdateHook {
+            contract_addr,
+            hook,
+        } => update_hook(deps, env, info, contract_addr, hook),
+        ExecuteMsg::Unbond {
+            token_addr,
+            amount,
+            provider_addr,
+        } => unbond(deps, env, info, token_addr, amount, provider_addr),
         ExecuteMsg::Withdraw { token_addr, amount } => {
             withdraw(deps, env, info, token_addr, amount)
         }
-        ExecuteMsg::Claim {} => claim(deps, env, info),
-        ExecuteMsg::SwapAndClaim { token_out_addr } => {
-            swap_and_claim(deps, env, info, token_out_addr)
+        ExecuteMsg::CreateIncentive {
+            token_addr_target,
+            reward_asset,
+            start,
+            end,
+        } => create_incentive(deps, env, info, token_addr_target, reward_asset, start, end),
+        ExecuteMsg::CloseIncentive {
+            token_addr_target,
+            id,
+        } => close_incentive(deps, env, info, token_addr_target, id),
+        ExecuteMsg::CancelOrder { id } => cancel_order(deps, env, info, id),
+        ExecuteMsg::ExecuteOrders { id_list } => execute_orders(deps, env, info, id_list),
+        ExecuteMsg::CancelDca { id } => cancel_dca(deps, env, info, id),
+        ExecuteMsg::ExecuteDca { id_list } => execute_dca(deps, env, info, id_list),
+        ExecuteMsg::Claim {
+            provider_addr,
+            recipient,
+            token_addr_list,
+        } => claim(deps, env, info, provider_addr, recipient, token_addr_list),
+        ExecuteMsg::BatchSwap {
+            swap_list,
+            referral,
+        } => batch_swap(deps, env, info, swap_list, referral),
+        ExecuteMsg::SwapAndClaim {
+            token_out_addr,
+            provider_addr,
+            recipient,
+            token_addr_list,
+        } => swap_and_claim(
+            deps,
+            env,
+            info,
+            token_out_addr,
+            provider_addr,
+            recipient,
+            token_addr_list,
+        ),
+        ExecuteMsg::Compound {
+            token_out_addr,
+            provider_addr,
+        } => compound(deps, env, info, token_out_addr, provider_addr),
+        ExecuteMsg::SetAutoCompound { is_enabled } => {
+            set_auto_compound(deps, env, info, is_enabled)
         }
-    }
+        ExecuteMsg::ClaimProtocolFees {} => claim_protocol_fees(deps, env, info),
+        ExecuteMsg::TransferPosition { recipient } => transfer_position(deps, env, info, recipient),
+        ExecuteMsg::SetOperator { operator } => set_operator(deps, env, info, operator),
+        ExecuteMsg::RevokeOperator { operator } => revoke_operator(deps, env, info, operator),
+    }?;
+
+    Ok(response.add_events(snapshot_event_list))
 }
 
 /// Exposes all the receive functions available in the contract
@@ -91,10 +220,57 @@ pub fn receive(
     } = wrapper;
 
     match from_binary(&msg)? {
-        ReceiveMsg::Deposit {} => deposit(deps, env, info, sender, amount),
-        ReceiveMsg::Swap { token_out_addr } => {
-            swap(deps, env, info, sender, amount, token_out_addr)
+        ReceiveMsg::Deposit { lock_duration } => {
+            deposit(deps, env, info, sender, amount, lock_duration)
         }
+        ReceiveMsg::Swap {
+            token_out_addr,
+            referral,
+        } => swap(deps, env, info, sender, amount, token_out_addr, referral),
+        ReceiveMsg::SwapExactOut {
+            token_out_addr,
+            amount_out,
+            referral,
+        } => swap_exact_out(
+            deps,
+            env,
+            info,
+            sender,
+            amount,
+            token_out_addr,
+            amount_out,
+            referral,
+        ),
+        ReceiveMsg::PlaceLimitOrder {
+            token_out_addr,
+            min_price,
+            expiry,
+        } => place_limit_order(
+            deps,
+            env,
+            info,
+            sender,
+            amount,
+            token_out_addr,
+            min_price,
+            expiry,
+        ),
+        ReceiveMsg::CreateDca {
+            token_out_addr,
+            amount_per_interval,
+            interval,
+            slippage,
+        } => create_dca(
+            deps,
+            env,
+            info,
+            sender,
+            amount,
+            token_out_addr,
+            amount_per_interval,
+            interval,
+            slippage,
+        ),
     }
 }
 
@@ -103,30 +279,197 @@ pub fn receive(
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
         QueryMsg::QueryConfig {} => to_binary(&query_config(deps, env)?),
-        QueryMsg::QueryTokensWeight { address_list } => {
-            to_binary(&query_tokens_weight(deps, env, address_list)?)
+        QueryMsg::QueryTokensWeight {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens_weight_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryTokenApr {} => to_binary(&query_token_apr(deps, env)?),
+        QueryMsg::QueryProviderApr { address } => {
+            to_binary(&query_provider_apr(deps, env, address)?)
         }
-        QueryMsg::QueryLiquidity { address_list } => {
-            to_binary(&query_liquidity(deps, env, address_list)?)
+        QueryMsg::QueryPoolStats {} => to_binary(&query_pool_stats(deps, env)?),
+        QueryMsg::QueryHistory {
+            token_addr,
+            from,
+            to,
+        } => to_binary(&query_history(deps, env, token_addr, from, to)?),
+        QueryMsg::QueryLiquidity {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_liquidity_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryProviders {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_providers_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryProvider { address } => to_binary(&query_provider(deps, env, address)?),
+        QueryMsg::QueryProviderSummary { address } => {
+            to_binary(&query_provider_summary(deps, env, address)?)
         }
-        QueryMsg::QueryProviders { address_list } => {
-            to_binary(&query_providers(deps, env, address_list)?)
+        QueryMsg::QueryOperators { address } => to_binary(&query_operators(deps, env, address)?),
+        QueryMsg::QueryAutoCompound { address } => {
+            to_binary(&query_auto_compound(deps, env, address)?)
         }
-        QueryMsg::QueryTokens { address_list } => {
-            to_binary(&query_tokens(deps, env, address_list)?)
+        QueryMsg::QueryTokens {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_tokens_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryToken { token_addr } => to_binary(&query_token(deps, env, token_addr)?),
+        QueryMsg::QueryBalances {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_balances_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryFees {} => to_binary(&query_fees(deps, env)?),
+        QueryMsg::QuerySwapFeeRate {
+            token_in_addr,
+            token_out_addr,
+        } => to_binary(&query_swap_fee_rate(
+            deps,
+            env,
+            token_in_addr,
+            token_out_addr,
+        )?),
+        QueryMsg::QuerySwapAllowances {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_swap_allowances(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryHaltedTokens { start_after, limit } => {
+            to_binary(&query_halted_tokens(deps, env, start_after, limit)?)
         }
-        QueryMsg::QueryBalances { address_list } => {
-            to_binary(&query_balances(deps, env, address_list)?)
+        QueryMsg::QueryPriceAverages { start_after, limit } => {
+            to_binary(&query_price_averages(deps, env, start_after, limit)?)
         }
-        QueryMsg::QueryPrices { address_list } => {
-            to_binary(&query_prices(deps, env, address_list)?)
+        QueryMsg::QueryTokenCaps { start_after, limit } => {
+            to_binary(&query_token_caps(deps, env, start_after, limit)?)
         }
+        QueryMsg::QueryFeeDiscounts {} => to_binary(&query_fee_discounts(deps, env)?),
+        QueryMsg::QueryLockBoosts {} => to_binary(&query_lock_boosts(deps, env)?),
+        QueryMsg::QueryDcaSchedules {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_dca_schedules(deps, env, owner, start_after, limit)?),
+        QueryMsg::QueryLimitOrdersByOwner {
+            owner,
+            start_after,
+            limit,
+        } => to_binary(&query_limit_orders_by_owner(
+            deps,
+            env,
+            owner,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryLimitOrdersByPair {
+            token_in_addr,
+            token_out_addr,
+            start_after,
+            limit,
+        } => to_binary(&query_limit_orders_by_pair(
+            deps,
+            env,
+            token_in_addr,
+            token_out_addr,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryIncentives {
+            token_addr_target,
+            start_after,
+            limit,
+        } => to_binary(&query_incentives(
+            deps,
+            env,
+            token_addr_target,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryIncentiveRewards {
+            address,
+            token_addr_target,
+            start_after,
+            limit,
+        } => to_binary(&query_incentive_rewards(
+            deps,
+            env,
+            address,
+            token_addr_target,
+            start_after,
+            limit,
+        )?),
+        QueryMsg::QueryHooks { start_after, limit } => {
+            to_binary(&query_hooks(deps, env, start_after, limit)?)
+        }
+        QueryMsg::QueryFeeMultiplier { address } => {
+            to_binary(&query_fee_multiplier(deps, env, address)?)
+        }
+        QueryMsg::QueryProtocolFees { start_after, limit } => {
+            to_binary(&query_protocol_fees(deps, env, start_after, limit)?)
+        }
+        QueryMsg::QueryPrices {
+            address_list,
+            start_after,
+            limit,
+        } => to_binary(&query_prices_page(
+            deps,
+            env,
+            address_list,
+            start_after,
+            limit,
+        )?),
         QueryMsg::QueryPricesMocked { address_list } => {
             to_binary(&query_prices_mocked(deps, env, address_list)?)
         }
     }
 }
 
+/// Handles results of submessages
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, env: Env, reply: Reply) -> Result<Response, ContractError> {
+    handle_reply(deps, env, reply)
+}
+
 /// Used for contract migration
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn migrate(deps: DepsMut, env: Env, msg: MigrateMsg) -> Result<Response, ContractError> {
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, StdError, Timestamp, Uint128};
 use thiserror::Error;
 
 pub fn from_std_err(std_error: StdError) -> ContractError {
@@ -49,4 +49,121 @@ pub enum ContractError {
 
     #[error("There is nothing to claim!")]
     NothingToClaim {},
+
+    #[error("Can not transfer position to the same address!")]
+    SameAddresses {},
+
+    #[error("Operator is already set!")]
+    OperatorIsAlreadySet {},
+
+    #[error("Operator is not found!")]
+    OperatorIsNotFound {},
+
+    #[error("Fee rate is out of range!")]
+    FeeRateIsOutOfRange {},
+
+    #[error("Referral commission rate exceeds maximum!")]
+    ReferralCommissionIsTooHigh {},
+
+    #[error("Token is halted by circuit breaker!")]
+    TokenIsHalted {},
+
+    #[error("Swap value exceeds max address volume {amount_max} per window!")]
+    AddressVolumeIsExceeded { amount_max: Uint128 },
+
+    #[error("Swap value exceeds max volume {amount_max} per block!")]
+    BlockVolumeIsExceeded { amount_max: Uint128 },
+
+    #[error("Incentive period is out of range!")]
+    IncentivePeriodIsOutOfRange {},
+
+    #[error("Incentive is not found!")]
+    IncentiveIsNotFound {},
+
+    #[error("Incentive is not finished yet!")]
+    IncentiveIsActive {},
+
+    #[error("Incentives limit of target token is reached!")]
+    IncentivesLimitIsReached {},
+
+    #[error("Reward token can not be pool token!")]
+    RewardTokenIsPoolToken {},
+
+    #[error("Lock boost is out of range!")]
+    LockBoostIsOutOfRange {},
+
+    #[error("Lock duration is not supported!")]
+    LockDurationIsNotFound {},
+
+    #[error("Lock can not be shortened!")]
+    LockIsShortened {},
+
+    #[error("Bonded amount is locked until {end}!")]
+    BondedAmountIsLocked { end: Timestamp },
+
+    #[error("Order is not found!")]
+    OrderIsNotFound {},
+
+    #[error("Order expiry is out of range!")]
+    OrderExpiryIsOutOfRange {},
+
+    #[error("There are no orders to execute!")]
+    NothingToExecute {},
+
+    #[error("Swap amount out {amount_out} is less than min amount out {min_amount_out}!")]
+    AmountOutIsTooLow {
+        amount_out: Uint128,
+        min_amount_out: Uint128,
+    },
+
+    #[error("Swap amount in {amount_in} exceeds max amount in {amount_in_max}!")]
+    AmountInIsTooHigh {
+        amount_in: Uint128,
+        amount_in_max: Uint128,
+    },
+
+    #[error("Swap list is empty!")]
+    SwapListIsEmpty {},
+
+    #[error("Address list can not be combined with pagination!")]
+    AddressListIsPaginated {},
+
+    #[error("Allowance of {token_addr} is less than swap amount in {amount_in}!")]
+    AllowanceIsNotEnough {
+        token_addr: Addr,
+        amount_in: Uint128,
+    },
+
+    #[error("Native funds can not be swapped, pool tokens are cw20!")]
+    NativeFundsAreNotAccepted {},
+
+    #[error("DCA schedule is not found!")]
+    DcaIsNotFound {},
+
+    #[error("DCA schedule parameters are out of range!")]
+    DcaIsOutOfRange {},
+
+    #[error("Contract {name} can not be migrated!")]
+    ContractNameIsWrong { name: String },
+
+    #[error("Contract version {version} is newer than current one!")]
+    VersionIsDowngraded { version: String },
+
+    #[error("Unknown reply id {id}!")]
+    UnknownReply { id: u64 },
+
+    #[error("Epoch is out of range!")]
+    EpochIsOutOfRange {},
+
+    #[error("Cap is out of range!")]
+    CapIsOutOfRange {},
+
+    #[error("Swap amount exceeds max single swap amount {amount_max} of token liquidity!")]
+    SwapCapIsExceeded { amount_max: Uint128 },
+
+    #[error("Swap amount exceeds max token outflow {amount_max} per window!")]
+    OutflowCapIsExceeded { amount_max: Uint128 },
+
+    #[error("Deposit amount exceeds max token total deposit {amount_max}!")]
+    DepositCapIsExceeded { amount_max: Uint128 },
 }
@@ -2,9 +2,17 @@ use cosmwasm_schema::cw_serde;
 
 use cw20::Cw20ReceiveMsg;
 
-use cosmwasm_std::{Decimal, Uint128};
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
+use crate::{
+    messages::receive::Referral,
+    state::{
+        CircuitBreaker, DynamicFee, FeeDiscount, Hook, LockBoost, RateLimit, RewardAsset, TokenCaps,
+    },
+};
 
 #[cw_serde]
+#[allow(clippy::large_enum_variant)]
 pub enum ExecuteMsg {
     Receive(Cw20ReceiveMsg),
     UpdateConfig {
@@ -13,22 +21,116 @@ pub enum ExecuteMsg {
         window: Option<Uint128>,
         unbonding_period: Option<Uint128>,
         price_age: Option<Uint128>,
+        protocol_fee_share: Option<Decimal>,
+        treasury: Option<String>,
+        dynamic_fee: Option<DynamicFee>,
+        max_referral_commission_rate: Option<Decimal>,
+        circuit_breaker: Option<CircuitBreaker>,
+        rate_limit: Option<RateLimit>,
+        epoch: Option<Uint128>,
+        keeper_tip_rate: Option<Decimal>,
     },
     UpdateToken {
         token_addr: String,
         symbol: String,
         price_feed_id_str: String,
     },
+    UpdateTokenFee {
+        token_addr: String,
+        swap_fee_rate: Option<Decimal>,
+    },
+    UpdateTokenCaps {
+        token_addr: String,
+        token_caps: Option<TokenCaps>,
+    },
+    UpdatePairFee {
+        token_in_addr: String,
+        token_out_addr: String,
+        swap_fee_rate: Option<Decimal>,
+    },
+    ResetHalt {
+        token_addr: String,
+    },
+    UpdateFeeDiscounts {
+        fee_discount_list: Vec<FeeDiscount>,
+    },
+    UpdateLockBoosts {
+        lock_boost_list: Vec<LockBoost>,
+    },
+    UpdateHook {
+        contract_addr: String,
+        hook: Option<Hook>,
+    },
     Unbond {
         token_addr: String,
         amount: Uint128,
+        provider_addr: Option<String>,
     },
     Withdraw {
         token_addr: String,
         amount: Uint128,
     },
-    Claim {},
+    CreateIncentive {
+        token_addr_target: String,
+        reward_asset: RewardAsset,
+        start: Timestamp,
+        end: Timestamp,
+    },
+    CloseIncentive {
+        token_addr_target: String,
+        id: u64,
+    },
+    CancelOrder {
+        id: u64,
+    },
+    ExecuteOrders {
+        id_list: Vec<u64>,
+    },
+    CancelDca {
+        id: u64,
+    },
+    ExecuteDca {
+        id_list: Vec<u64>,
+    },
+    Claim {
+        provider_addr: Option<String>,
+        recipient: Option<String>,
+        token_addr_list: Option<Vec<String>>,
+    },
+    BatchSwap {
+        swap_list: Vec<SwapLeg>,
+        referral: Option<Referral>,
+    },
     SwapAndClaim {
         token_out_addr: String,
+        provider_addr: Option<String>,
+        recipient: Option<String>,
+        token_addr_list: Option<Vec<String>>,
+    },
+    Compound {
+        token_out_addr: Option<String>,
+        provider_addr: Option<String>,
     },
+    SetAutoCompound {
+        is_enabled: bool,
+    },
+    ClaimProtocolFees {},
+    TransferPosition {
+        recipient: String,
+    },
+    SetOperator {
+        operator: String,
+    },
+    RevokeOperator {
+        operator: String,
+    },
+}
+
+// amount_in is transferred from sender cw20 allowance, native funds are rejected
+#[cw_serde]
+pub struct SwapLeg {
+    pub token_in_addr: String,
+    pub amount_in: Uint128,
+    pub token_out_addr: String,
+    pub min_amount_out: Uint128,
 }
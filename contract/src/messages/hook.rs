@@ -0,0 +1,51 @@
+use cosmwasm_schema::cw_serde;
+
+use cosmwasm_std::{to_binary, Addr, StdResult, Uint128, WasmMsg};
+
+use crate::state::Asset;
+
+// notification sent to hook contracts after swap or provider liquidity change
+#[cw_serde]
+pub enum IonFluxHookMsg {
+    Swap {
+        user: Addr,
+        token_in_addr: Addr,
+        token_out_addr: Addr,
+        amount_in: Uint128,
+        fee: Uint128,
+        amount_out: Uint128,
+    },
+    LiquidityChanged {
+        action: LiquidityAction,
+        provider: Addr,
+        amount: Uint128,
+        asset: Asset, // provider asset balances after update
+    },
+}
+
+#[cw_serde]
+pub enum LiquidityAction {
+    Deposit,
+    Unbond,
+    Withdraw,
+    Claim,
+    Compound,
+    TransferOut,
+    TransferIn,
+}
+
+// hook contract must handle {"ion_flux_hook": {...}} execute message
+#[cw_serde]
+pub enum IonFluxExecuteMsg {
+    IonFluxHook(IonFluxHookMsg),
+}
+
+impl IonFluxHookMsg {
+    pub fn into_wasm_msg(self, contract_addr: &Addr) -> StdResult<WasmMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_binary(&IonFluxExecuteMsg::IonFluxHook(self))?,
+            funds: vec![],
+        })
+    }
+}
@@ -1,4 +1,13 @@
 use cosmwasm_schema::cw_serde;
 
+use cosmwasm_std::{Decimal, Uint128};
+
+// config values replacing stored or default ones
 #[cw_serde]
-pub enum MigrateMsg {}
+pub struct MigrateMsg {
+    pub protocol_fee_share: Option<Decimal>,
+    pub treasury: Option<String>,
+    pub max_referral_commission_rate: Option<Decimal>,
+    pub epoch: Option<Uint128>,
+    pub keeper_tip_rate: Option<Decimal>,
+}
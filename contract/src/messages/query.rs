@@ -1,12 +1,17 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 
 #[allow(unused_imports)] // preventing optimizer warning message
-use cosmwasm_std::{Addr, Decimal, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
 
 #[allow(unused_imports)] // preventing optimizer warning message
 use crate::{
-    messages::response::Balance,
-    state::{Asset, Config, Token},
+    messages::response::{
+        Balance, Fees, Page, PoolStats, ProviderApr, ProviderSummary, SwapAllowance,
+    },
+    state::{
+        Asset, Config, DcaSchedule, FeeDiscount, Hook, Incentive, LimitOrder, LockBoost,
+        RewardAsset, Snapshot, Token, TokenCaps,
+    },
 };
 
 #[cw_serde]
@@ -14,18 +19,140 @@ use crate::{
 pub enum QueryMsg {
     #[returns(Config)]
     QueryConfig {},
+    #[returns(Page<(Addr, Decimal)>)]
+    QueryTokensWeight {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(Vec<(Addr, Decimal)>)]
-    QueryTokensWeight { address_list: Vec<String> },
-    #[returns(Vec<(Addr, Uint128)>)]
-    QueryLiquidity { address_list: Vec<String> },
-    #[returns(Vec<(Addr, Vec<Asset>)>)]
-    QueryProviders { address_list: Vec<String> },
-    #[returns(Vec<(Addr, Token)>)]
-    QueryTokens { address_list: Vec<String> },
-    #[returns(Vec<Balance>)]
-    QueryBalances { address_list: Vec<String> },
-    #[returns(Vec<(Addr, Decimal)>)]
-    QueryPrices { address_list: Vec<String> },
+    QueryTokenApr {},
+    #[returns(ProviderApr)]
+    QueryProviderApr { address: String },
+    #[returns(PoolStats)]
+    QueryPoolStats {},
+    #[returns(Vec<(Timestamp, Snapshot)>)]
+    QueryHistory {
+        token_addr: String,
+        from: Timestamp,
+        to: Timestamp,
+    },
+    #[returns(Page<(Addr, Uint128)>)]
+    QueryLiquidity {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(Addr, Vec<Asset>)>)]
+    QueryProviders {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<Asset>)]
+    QueryProvider { address: String },
+    #[returns(ProviderSummary)]
+    QueryProviderSummary { address: String },
+    #[returns(Vec<Addr>)]
+    QueryOperators { address: String },
+    #[returns(bool)]
+    QueryAutoCompound { address: String },
+    #[returns(Page<(Addr, Token)>)]
+    QueryTokens {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Token)]
+    QueryToken { token_addr: String },
+    #[returns(Page<Balance>)]
+    QueryBalances {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Fees)]
+    QueryFees {},
+    #[returns(Decimal)]
+    QuerySwapFeeRate {
+        token_in_addr: String,
+        token_out_addr: String,
+    },
+    #[returns(Page<SwapAllowance>)]
+    QuerySwapAllowances {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<Addr>)]
+    QueryHaltedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(Addr, Decimal)>)]
+    QueryPriceAverages {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(Addr, TokenCaps)>)]
+    QueryTokenCaps {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<FeeDiscount>)]
+    QueryFeeDiscounts {},
+    #[returns(Vec<LockBoost>)]
+    QueryLockBoosts {},
+    #[returns(Page<(u64, Incentive), u64>)]
+    QueryIncentives {
+        token_addr_target: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(u64, RewardAsset), u64>)]
+    QueryIncentiveRewards {
+        address: String,
+        token_addr_target: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(u64, LimitOrder), u64>)]
+    QueryLimitOrdersByOwner {
+        owner: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(u64, LimitOrder), u64>)]
+    QueryLimitOrdersByPair {
+        token_in_addr: String,
+        token_out_addr: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(u64, DcaSchedule), u64>)]
+    QueryDcaSchedules {
+        owner: Option<String>,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(Addr, Hook)>)]
+    QueryHooks {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Decimal)]
+    QueryFeeMultiplier { address: String },
+    #[returns(Page<(Addr, Uint128)>)]
+    QueryProtocolFees {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(Page<(Addr, Decimal)>)]
+    QueryPrices {
+        address_list: Vec<String>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
     #[returns(Vec<(Addr, Decimal)>)]
     QueryPricesMocked { address_list: Vec<String> },
 }
@@ -1,7 +1,37 @@
 use cosmwasm_schema::cw_serde;
 
+use cosmwasm_std::{Decimal, Timestamp, Uint128};
+
 #[cw_serde]
 pub enum ReceiveMsg {
-    Deposit {},
-    Swap { token_out_addr: String },
+    Deposit {
+        lock_duration: Option<Uint128>,
+    },
+    Swap {
+        token_out_addr: String,
+        referral: Option<Referral>,
+    },
+    SwapExactOut {
+        token_out_addr: String,
+        amount_out: Uint128,
+        referral: Option<Referral>,
+    },
+    PlaceLimitOrder {
+        token_out_addr: String,
+        min_price: Decimal,
+        expiry: Timestamp,
+    },
+    CreateDca {
+        token_out_addr: String,
+        amount_per_interval: Uint128,
+        interval: Uint128,
+        slippage: Decimal,
+    },
+}
+
+// part of swap input sent to frontend/aggregator routing the swap
+#[cw_serde]
+pub struct Referral {
+    pub address: String,
+    pub commission_rate: Decimal,
 }
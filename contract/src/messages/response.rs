@@ -1,9 +1,104 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+
+use crate::state::Lock;
 
 #[cw_serde]
 pub struct Balance {
     pub token_addr: Addr,
     pub amount: Uint128,
 }
+
+#[cw_serde]
+pub struct Fees {
+    pub swap_fee_rate: Decimal,
+    pub token_fee_list: Vec<(Addr, Decimal)>,
+    pub pair_fee_list: Vec<(Addr, Addr, Decimal)>,
+}
+
+// remaining swap value at oracle prices, not limited if None
+#[cw_serde]
+pub struct SwapAllowance {
+    pub address: Addr,
+    pub address_allowance: Option<Uint128>,
+    pub block_allowance: Option<Uint128>,
+}
+
+// cursor is the last item key if the page is full, it's used as start_after to get the next page
+#[cw_serde]
+pub struct Page<T, C = Addr> {
+    pub list: Vec<T>,
+    pub cursor: Option<C>,
+}
+
+// provider asset state at current time, values are amounts multiplied by oracle price
+#[cw_serde]
+pub struct AssetSummary {
+    pub token_addr: Addr,
+    pub price: Decimal,
+    pub bonded: Uint128,
+    pub bonded_value: Decimal,
+    pub requested: Uint128,
+    pub requested_value: Decimal,
+    pub unlock_time: Option<Timestamp>, // requested will be unbonded at this time
+    pub unbonded: Uint128,              // includes matured requested
+    pub unbonded_value: Decimal,
+    pub rewards: Uint128,
+    pub rewards_value: Decimal,
+    pub lock: Option<Lock>, // active lock only
+}
+
+#[cw_serde]
+pub struct ProviderSummary {
+    pub address: Addr,
+    pub asset_list: Vec<AssetSummary>,
+    pub total_value: Decimal,
+}
+
+// apr is average of asset aprs weighted by bonded values
+#[cw_serde]
+pub struct ProviderApr {
+    pub address: Addr,
+    pub asset_apr_list: Vec<(Addr, Decimal)>,
+    pub apr: Decimal,
+}
+
+// token amounts and their values at oracle prices, swap counters are lifetime totals
+#[cw_serde]
+pub struct TokenStats {
+    pub token_addr: Addr,
+    pub price: Decimal,
+    pub liquidity: Uint128,
+    pub liquidity_value: Decimal,
+    pub bonded: Uint128,
+    pub bonded_value: Decimal,
+    pub requested: Uint128,
+    pub requested_value: Decimal,
+    pub unbonded: Uint128,
+    pub unbonded_value: Decimal,
+    pub rewards: Uint128,
+    pub rewards_value: Decimal,
+    pub provider_count: u32, // providers having any token position
+    pub swapped_in_total: Uint128,
+    pub swapped_in_total_value: Decimal,
+    pub swapped_out_total: Uint128,
+    pub swapped_out_total_value: Decimal,
+    pub fees_total: Uint128,
+    pub fees_total_value: Decimal,
+}
+
+// totals are sums of token values
+#[cw_serde]
+pub struct PoolStats {
+    pub token_stats_list: Vec<TokenStats>,
+    pub provider_count: u32,
+    pub liquidity_value: Decimal,
+    pub bonded_value: Decimal,
+    pub requested_value: Decimal,
+    pub unbonded_value: Decimal,
+    pub rewards_value: Decimal,
+    pub swapped_in_total_value: Decimal,
+    pub swapped_out_total_value: Decimal,
+    pub fees_total_value: Decimal,
+}
@@ -1,10 +1,14 @@
+use std::{collections::BTreeMap, ops::Bound};
+
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{Addr, Decimal, Empty, Order, Record, Storage, Timestamp, Uint128};
 use cw_storage_plus::{Item, Map};
 
 use crate::actions::math::str_to_dec;
 
 pub const CHAIN_ID_MOCKED: &str = "cw_multi_test";
+pub const MAX_PRICE_DEVIATION: &str = "0.1";
+pub const KEEPER_TIP_RATE: &str = "0.001";
 
 pub const CONFIG: Item<Config> = Item::new("config");
 
@@ -15,6 +19,14 @@ pub struct Config {
     pub window: Uint128,
     pub unbonding_period: Uint128,
     pub price_age: Uint128,
+    pub protocol_fee_share: Decimal,
+    pub treasury: Addr,
+    pub dynamic_fee: DynamicFee,
+    pub max_referral_commission_rate: Decimal,
+    pub circuit_breaker: CircuitBreaker,
+    pub rate_limit: RateLimit,
+    pub epoch: Uint128,           // snapshot period
+    pub keeper_tip_rate: Decimal, // part of limit order amount_in paid to executor
     chain_id_mocked: String,
 }
 
@@ -25,6 +37,7 @@ impl Config {
         window: u128,
         unbonding_period: u128,
         price_age: u128,
+        epoch: u128,
     ) -> Self {
         Config {
             admin: admin.to_owned(),
@@ -32,6 +45,24 @@ impl Config {
             window: Uint128::from(window),
             unbonding_period: Uint128::from(unbonding_period),
             price_age: Uint128::from(price_age),
+            protocol_fee_share: Decimal::zero(),
+            treasury: admin.to_owned(),
+            dynamic_fee: DynamicFee {
+                is_enabled: false,
+                min_fee_rate: str_to_dec(swap_fee_rate),
+                max_fee_rate: str_to_dec(swap_fee_rate),
+            },
+            max_referral_commission_rate: Decimal::zero(),
+            circuit_breaker: CircuitBreaker {
+                is_enabled: false,
+                max_price_deviation: str_to_dec(MAX_PRICE_DEVIATION),
+            },
+            rate_limit: RateLimit {
+                max_address_volume: None,
+                max_block_volume: None,
+            },
+            epoch: Uint128::from(epoch),
+            keeper_tip_rate: str_to_dec(KEEPER_TIP_RATE),
             chain_id_mocked: String::from(CHAIN_ID_MOCKED),
         }
     }
@@ -41,6 +72,28 @@ impl Config {
     }
 }
 
+// swap fee rate is scaled by volume ratios of swapped tokens if enabled
+#[cw_serde]
+pub struct DynamicFee {
+    pub is_enabled: bool,
+    pub min_fee_rate: Decimal,
+    pub max_fee_rate: Decimal,
+}
+
+// swaps in token are halted if its price deviates from price sma too much
+#[cw_serde]
+pub struct CircuitBreaker {
+    pub is_enabled: bool,
+    pub max_price_deviation: Decimal,
+}
+
+// limits of swapped value at oracle prices, not applied if not specified
+#[cw_serde]
+pub struct RateLimit {
+    pub max_address_volume: Option<Uint128>, // per address during window
+    pub max_block_volume: Option<Uint128>,   // for all addresses per block height
+}
+
 // key - token_addr: &Addr
 pub const TOKENS: Map<&Addr, Token> = Map::new("tokens");
 
@@ -53,6 +106,14 @@ pub struct Token {
     pub requested: (Vec<Sample>, Uint128),
     pub swapped_in: (Vec<Sample>, Uint128),
     pub swapped_out: (Vec<Sample>, Uint128),
+    pub swap_fee_rate: Option<Decimal>, // overrides config swap_fee_rate if specified
+    // lifetime counters, default is used for tokens stored before the counters were added
+    #[serde(default)]
+    pub swapped_in_total: Uint128, // including fee
+    #[serde(default)]
+    pub swapped_out_total: Uint128,
+    #[serde(default)]
+    pub fees_total: Uint128, // including protocol fee
 }
 
 impl Token {
@@ -66,10 +127,56 @@ impl Token {
             requested: (vec![], zero),
             swapped_in: (vec![], zero),
             swapped_out: (vec![], zero),
+            swap_fee_rate: None,
+            swapped_in_total: zero,
+            swapped_out_total: zero,
+            fees_total: zero,
         }
     }
 }
 
+// key - token_addr: &Addr
+pub const TOKEN_CAPS: Map<&Addr, TokenCaps> = Map::new("token_caps");
+
+// limits protecting token liquidity, not applied if not specified
+#[cw_serde]
+pub struct TokenCaps {
+    pub max_swap_out_ratio: Option<Decimal>, // max amount_out of single swap as part of token liquidity
+    pub max_window_outflow: Option<Uint128>, // max swapped_out - swapped_in during window
+    pub max_deposit: Option<Uint128>,        // max sum of providers bonded
+}
+
+// key - (token_in_addr: &Addr, token_out_addr: &Addr), value - swap fee rate for the pair
+// pair fee is applied as is, dynamic fee adjusts only config and token fees
+pub const PAIR_FEES: Map<(&Addr, &Addr), Decimal> = Map::new("pair_fees");
+
+pub const FEE_DISCOUNTS: Item<Vec<FeeDiscount>> = Item::new("fee_discounts");
+
+// swap fee is multiplied by fee_multiplier if user holds at least min_balance of token_addr
+#[cw_serde]
+pub struct FeeDiscount {
+    pub token_addr: Addr,
+    pub min_balance: Uint128,
+    pub fee_multiplier: Decimal,
+}
+
+pub const LOCK_BOOSTS: Item<Vec<LockBoost>> = Item::new("lock_boosts");
+
+// bonded amount locked for duration is weighted by multiplier in rewards allocation
+#[cw_serde]
+pub struct LockBoost {
+    pub duration: Uint128,
+    pub multiplier: Decimal,
+}
+
+// part of bonded amount which can't be unbonded until end
+#[cw_serde]
+pub struct Lock {
+    pub amount: Uint128,
+    pub end: Timestamp,
+    pub multiplier: Decimal,
+}
+
 // key - address: &Addr
 pub const PROVIDERS: Map<&Addr, Vec<Asset>> = Map::new("providers");
 
@@ -82,6 +189,8 @@ pub struct Asset {
     pub requested: Uint128, // providing liquidity +, fee-sharing - | will be unbonded when time >= counter
     pub counter: Timestamp,
     pub rewards: Uint128,
+    #[serde(default)]
+    pub lock: Option<Lock>,
 }
 
 impl Asset {
@@ -95,10 +204,181 @@ impl Asset {
             requested: zero,
             counter: timestamp.to_owned(),
             rewards: zero,
+            lock: None,
         }
     }
 }
 
+// key - provider_addr: &Addr, value - addresses allowed to unbond/claim on behalf of provider
+pub const OPERATORS: Map<&Addr, Vec<Addr>> = Map::new("operators");
+
+// key - provider_addr: &Addr, value - bond rewards instead of accumulating them on each swap
+pub const AUTO_COMPOUND: Map<&Addr, bool> = Map::new("auto_compound");
+
+// key - token_addr: &Addr, value - protocol part of swap fees claimable by treasury
+pub const PROTOCOL_FEES: Map<&Addr, Uint128> = Map::new("protocol_fees");
+
+// key - token_addr: &Addr, value - time series/sma of price atomics
+pub const PRICES: Map<&Addr, (Vec<Sample>, Uint128)> = Map::new("prices");
+
+// key - token_addr: &Addr, value - tokens halted by circuit breaker
+pub const HALTED_TOKENS: Map<&Addr, bool> = Map::new("halted_tokens");
+
+// key - address: &Addr, value - swapped values during window
+pub const SWAP_VOLUMES: Map<&Addr, Vec<Sample>> = Map::new("swap_volumes");
+
+// key - (last swap timestamp nanos: u64, address: &Addr), used to drop expired swap volumes
+pub const SWAP_VOLUMES_BY_TIME: Map<(u64, &Addr), Empty> = Map::new("swap_volumes_by_time");
+
+// max amount of expired swap volumes dropped on each swap
+pub const SWAP_VOLUMES_PRUNE_LIMIT: usize = 10;
+
+// key - token_addr: &Addr, value - (swapped_in, swapped_out) amounts of swaps during window
+pub const TOKEN_SWAP_VOLUMES: Map<&Addr, (Vec<Sample>, Vec<Sample>)> =
+    Map::new("token_swap_volumes");
+
+// (block height, swapped value)
+pub const BLOCK_SWAP_VOLUME: Item<(u64, Uint128)> = Item::new("block_swap_volume");
+
+// key - (token_addr: &Addr, epoch start: u64), epoch start is timestamp in nanos rounded down to config.epoch
+pub const SNAPSHOTS: Map<(&Addr, u64), Snapshot> = Map::new("snapshots");
+
+// start of last epoch having any action, its snapshots are written on first action of next epoch
+pub const LAST_EPOCH: Item<u64> = Item::new("last_epoch");
+
+// token state at the end of epoch, swap amounts are accumulated during epoch
+#[cw_serde]
+pub struct Snapshot {
+    pub swapped_in: Uint128,
+    pub swapped_out: Uint128,
+    pub fees: Uint128,
+    pub bonded: Uint128,
+    // first price observed after epoch end, None if oracle was unavailable
+    pub price: Option<Decimal>,
+    // lifetime counters at the end of epoch
+    pub swapped_in_total: Uint128,
+    pub swapped_out_total: Uint128,
+    pub fees_total: Uint128,
+}
+
+// key - order id: u64
+pub const LIMIT_ORDERS: Map<u64, LimitOrder> = Map::new("limit_orders");
+
+pub const LIMIT_ORDER_COUNTER: Item<u64> = Item::new("limit_order_counter");
+
+// key - (owner: &Addr, order id: u64), index of LIMIT_ORDERS
+pub const LIMIT_ORDERS_BY_OWNER: Map<(&Addr, u64), Empty> = Map::new("limit_orders_by_owner");
+
+// key - (token_in_addr: &Addr, token_out_addr: &Addr, order id: u64), index of LIMIT_ORDERS
+pub const LIMIT_ORDERS_BY_PAIR: Map<(&Addr, &Addr, u64), Empty> = Map::new("limit_orders_by_pair");
+
+// key - token_addr: &Addr, value - escrowed amount_in of limit orders and dca schedules excluded from liquidity
+pub const ESCROWED: Map<&Addr, Uint128> = Map::new("escrowed");
+
+// amount_in is swapped when token_in oracle price in token_out units is at least min_price
+#[cw_serde]
+pub struct LimitOrder {
+    pub owner: Addr,
+    pub token_in_addr: Addr,
+    pub token_out_addr: Addr,
+    pub amount_in: Uint128,
+    pub min_price: Decimal,
+    pub expiry: Timestamp,
+}
+
+// key - schedule id: u64
+pub const DCA_SCHEDULES: Map<u64, DcaSchedule> = Map::new("dca_schedules");
+
+pub const DCA_COUNTER: Item<u64> = Item::new("dca_counter");
+
+// key - (owner: &Addr, schedule id: u64), index of DCA_SCHEDULES
+pub const DCA_SCHEDULES_BY_OWNER: Map<(&Addr, u64), Empty> = Map::new("dca_schedules_by_owner");
+
+// amount_per_interval of balance is swapped once per interval until balance is spent
+// slippage is max output value loss relative to oracle quote, fee included
+#[cw_serde]
+pub struct DcaSchedule {
+    pub owner: Addr,
+    pub token_in_addr: Addr,
+    pub token_out_addr: Addr,
+    pub amount_per_interval: Uint128,
+    pub interval: Uint128,
+    pub slippage: Decimal,
+    pub balance: Uint128,
+    pub next_execution: Timestamp,
+    pub executed_count: u32,
+    pub amount_in_total: Uint128,
+    pub amount_out_total: Uint128,
+}
+
+// key - (token_addr_target: &Addr, incentive id: u64)
+pub const INCENTIVES: Map<(&Addr, u64), Incentive> = Map::new("incentives");
+
+pub const INCENTIVE_COUNTER: Item<u64> = Item::new("incentive_counter");
+
+// incentives of target token which can be created by anyone, admin can exceed the limit
+pub const MAX_INCENTIVES_PER_TOKEN: usize = 10;
+
+// key - token_addr: &Addr, sum of bonded amounts of all providers
+pub const BONDED_TOTALS: Map<&Addr, Uint128> = Map::new("bonded_totals");
+
+// key - (provider_addr: &Addr, incentive id: u64)
+// entries of removed incentives are removed when rewards are claimed
+pub const INCENTIVE_REWARDS: Map<(&Addr, u64), IncentiveReward> = Map::new("incentive_rewards");
+
+// reward amount is streamed linearly during [start, end] to bonded balances of target token
+#[cw_serde]
+pub struct Incentive {
+    pub creator: Addr,
+    pub token_addr_target: Addr,
+    pub reward_asset: RewardAsset,
+    pub start: Timestamp,
+    pub end: Timestamp,
+    pub reward_per_token: Decimal, // accumulated rewards per bonded token
+    pub last_update: Timestamp,
+    pub distributed: Uint128, // streamed while target token had bonded liquidity
+    // closed incentive is kept until every bonded provider settles its rewards
+    #[serde(default)]
+    pub is_closed: bool,
+    #[serde(default)]
+    pub bonded_unsettled: Uint128, // bonded amount of providers which didn't settle after close
+}
+
+#[cw_serde]
+pub struct IncentiveReward {
+    pub token_addr_target: Addr,
+    pub reward_token: RewardToken,
+    pub reward_per_token_paid: Decimal,
+    pub amount: Uint128, // pending rewards
+    #[serde(default)]
+    pub is_settled: bool, // provider settled rewards of closed incentive
+}
+
+#[cw_serde]
+pub struct RewardAsset {
+    pub token: RewardToken,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub enum RewardToken {
+    Cw20(Addr),
+    Native(String),
+}
+
+pub const HOOK_REPLY_ID: u64 = 1;
+
+// key - contract_addr: &Addr
+pub const HOOKS: Map<&Addr, Hook> = Map::new("hooks");
+
+// notifications subscribed by external contract, action is reverted on failed notification if it's required
+#[cw_serde]
+pub struct Hook {
+    pub on_swap: bool,
+    pub on_liquidity: bool, // deposit, unbond, withdraw, claim
+    pub is_required: bool,
+}
+
 pub const PYTH: Item<Pyth> = Item::new("pyth");
 
 #[cw_serde]
@@ -117,3 +397,83 @@ impl Sample {
         Sample { value, timestamp }
     }
 }
+
+// buffers writes over storage, they are applied by commit or dropped with the cache
+// it allows to skip failed action without reverting whole transaction
+pub struct StorageCache<'a> {
+    storage: &'a dyn Storage,
+    change_map: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+}
+
+impl<'a> StorageCache<'a> {
+    pub fn new(storage: &'a dyn Storage) -> Self {
+        StorageCache {
+            storage,
+            change_map: BTreeMap::new(),
+        }
+    }
+
+    // returns writes to apply by commit
+    pub fn into_changes(self) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+        self.change_map
+    }
+
+    pub fn commit(storage: &mut dyn Storage, change_map: BTreeMap<Vec<u8>, Option<Vec<u8>>>) {
+        for (key, value) in change_map {
+            match value {
+                Some(x) => storage.set(&key, &x),
+                None => storage.remove(&key),
+            }
+        }
+    }
+}
+
+impl Storage for StorageCache<'_> {
+    fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        match self.change_map.get(key) {
+            Some(value) => value.clone(),
+            None => self.storage.get(key),
+        }
+    }
+
+    fn range<'b>(
+        &'b self,
+        start: Option<&[u8]>,
+        end: Option<&[u8]>,
+        order: Order,
+    ) -> Box<dyn Iterator<Item = Record> + 'b> {
+        if let (Some(start), Some(end)) = (start, end) {
+            if start >= end {
+                return Box::new(std::iter::empty());
+            }
+        }
+
+        let mut record_map: BTreeMap<Vec<u8>, Vec<u8>> =
+            self.storage.range(start, end, Order::Ascending).collect();
+
+        let bounds = (
+            start.map_or(Bound::Unbounded, |x| Bound::Included(x.to_vec())),
+            end.map_or(Bound::Unbounded, |x| Bound::Excluded(x.to_vec())),
+        );
+
+        for (key, value) in self.change_map.range(bounds) {
+            match value {
+                Some(x) => record_map.insert(key.to_owned(), x.to_owned()),
+                None => record_map.remove(key),
+            };
+        }
+
+        match order {
+            Order::Ascending => Box::new(record_map.into_iter()),
+            Order::Descending => Box::new(record_map.into_iter().rev()),
+        }
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) {
+        self.change_map.insert(key.to_vec(), Some(value.to_vec()));
+    }
+
+    fn remove(&mut self, key: &[u8]) {
+        self.change_map.insert(key.to_vec(), None);
+    }
+}
@@ -1,13 +1,34 @@
-use cosmwasm_std::{to_binary, Addr, Decimal, Empty, StdResult, Uint128};
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{
+    coins, to_binary, Addr, Binary, Coin, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, StdError, StdResult, Timestamp, Uint128,
+};
 
 use cw20::Cw20Coin;
 
 use cw_multi_test::{App, AppResponse, ContractWrapper, Executor};
 
+use cw_storage_plus::{Item, Map};
+
 use crate::{
-    contract::{execute, instantiate, query},
-    messages::{execute::ExecuteMsg, query::QueryMsg, receive::ReceiveMsg, response::Balance},
-    state::{Asset, Config, Token, CHAIN_ID_MOCKED},
+    actions::{
+        instantiate::{PRICE_AGE, SWAP_FEE_RATE, UNBONDING_PERIOD, WINDOW},
+        math::str_to_dec,
+    },
+    contract::{execute, instantiate, migrate, query, reply},
+    messages::{
+        execute::{ExecuteMsg, SwapLeg},
+        hook::{IonFluxExecuteMsg, IonFluxHookMsg},
+        migrate::MigrateMsg,
+        query::QueryMsg,
+        receive::{ReceiveMsg, Referral},
+        response::{Balance, Fees, Page, PoolStats, ProviderApr, ProviderSummary, SwapAllowance},
+    },
+    state::{
+        Asset, CircuitBreaker, Config, DcaSchedule, DynamicFee, FeeDiscount, Hook, Incentive,
+        LimitOrder, LockBoost, RateLimit, RewardAsset, Sample, Snapshot, Token, TokenCaps,
+        BONDED_TOTALS, CHAIN_ID_MOCKED,
+    },
 };
 
 pub const CHAIN_ID_TESTNET: &str = "injective-888";
@@ -65,7 +86,9 @@ impl Project {
     }
 
     fn store_code(app: &mut App) -> u64 {
-        let contract = ContractWrapper::new(execute, instantiate, query);
+        let contract = ContractWrapper::new(execute, instantiate, query)
+            .with_reply(reply)
+            .with_migrate(migrate);
         app.store_code(Box::new(contract))
     }
 
@@ -77,6 +100,11 @@ impl Project {
         });
     }
 
+    #[track_caller]
+    pub fn get_timestamp(&self) -> Timestamp {
+        self.app.block_info().time
+    }
+
     #[track_caller]
     fn instantiate(app: &mut App, id: u64) -> Addr {
         app.instantiate_contract(
@@ -121,6 +149,78 @@ impl Project {
             .unwrap()
     }
 
+    // instantiates contract storing state in 0.1.0 layout with specified cw2 info
+    #[track_caller]
+    pub fn new_legacy(contract_name: &str, version: &str) -> Self {
+        let mut app = Self::create_app();
+        app.update_block(|block| block.chain_id = String::from(CHAIN_ID_MOCKED));
+
+        let contract = ContractWrapper::new(legacy_execute, legacy_instantiate, legacy_query);
+        let id = app.store_code(Box::new(contract));
+
+        let address = app
+            .instantiate_contract(
+                id,
+                Addr::unchecked(ADDR_ADMIN_INJ),
+                &LegacyInstantiateMsg {
+                    contract_name: contract_name.to_string(),
+                    version: version.to_string(),
+                },
+                &[],
+                "Project",
+                Some(ADDR_ADMIN_INJ.to_string()),
+            )
+            .unwrap();
+
+        Self { address, app }
+    }
+
+    #[track_caller]
+    pub fn migrate(&mut self, msg: &MigrateMsg) -> StdResult<AppResponse> {
+        let id = Self::store_code(&mut self.app);
+
+        self.app
+            .migrate_contract(
+                Addr::unchecked(ADDR_ADMIN_INJ),
+                self.address.clone(),
+                msg,
+                id,
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn query_bonded_total(&self, token_addr: &Addr) -> StdResult<Option<Uint128>> {
+        BONDED_TOTALS.query(&self.app.wrap(), self.address.clone(), token_addr)
+    }
+
+    #[track_caller]
+    pub fn query_contract_version(&self) -> StdResult<cw2::ContractVersion> {
+        cw2::query_contract_info(&self.app.wrap(), self.address.clone())
+    }
+
+    #[track_caller]
+    pub fn create_hook_contract(&mut self, is_failing: bool) -> Addr {
+        let contract = ContractWrapper::new(hook_execute, hook_instantiate, hook_query);
+        let id = self.app.store_code(Box::new(contract));
+
+        self.app
+            .instantiate_contract(
+                id,
+                Addr::unchecked(ADDR_ADMIN_INJ),
+                &HookInstantiateMsg { is_failing },
+                &[],
+                "Hook",
+                None,
+            )
+            .unwrap()
+    }
+
+    #[track_caller]
+    pub fn query_hook_msgs(&self, contract_addr: &Addr) -> StdResult<Vec<IonFluxHookMsg>> {
+        self.app.wrap().query_wasm_smart(contract_addr, &Empty {})
+    }
+
     #[track_caller]
     pub fn get_cw20_balance<T: Into<String>, U: Into<String>>(
         &mut self,
@@ -141,6 +241,51 @@ impl Project {
     }
 
     #[track_caller]
+    pub fn increase_allowance(
+        &mut self,
+        sender: &str,
+        token_addr: &Addr,
+        spender: &Addr,
+        amount: Uint128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_addr.to_owned(),
+                &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+                    spender: spender.to_string(),
+                    amount,
+                    expires: None,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn mint_native(&mut self, address: &str, denom: &str, amount: Uint128) {
+        self.app
+            .init_modules(|router, _, storage| {
+                router.bank.init_balance(
+                    storage,
+                    &Addr::unchecked(address),
+                    coins(amount.u128(), denom),
+                )
+            })
+            .unwrap();
+    }
+
+    #[track_caller]
+    pub fn get_native_balance(&self, address: &str, denom: &str) -> Uint128 {
+        self.app
+            .wrap()
+            .query_balance(address, denom)
+            .unwrap()
+            .amount
+    }
+
+    #[track_caller]
+    #[allow(clippy::too_many_arguments)]
     pub fn update_config(
         &mut self,
         sender: &str,
@@ -149,6 +294,14 @@ impl Project {
         window: Option<Uint128>,
         unbonding_period: Option<Uint128>,
         price_age: Option<Uint128>,
+        protocol_fee_share: Option<Decimal>,
+        treasury: Option<String>,
+        dynamic_fee: Option<DynamicFee>,
+        max_referral_commission_rate: Option<Decimal>,
+        circuit_breaker: Option<CircuitBreaker>,
+        rate_limit: Option<RateLimit>,
+        epoch: Option<Uint128>,
+        keeper_tip_rate: Option<Decimal>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
@@ -160,6 +313,14 @@ impl Project {
                     window,
                     unbonding_period,
                     price_age,
+                    protocol_fee_share,
+                    treasury,
+                    dynamic_fee,
+                    max_referral_commission_rate,
+                    circuit_breaker,
+                    rate_limit,
+                    epoch,
+                    keeper_tip_rate,
                 },
                 &[],
             )
@@ -189,19 +350,19 @@ impl Project {
     }
 
     #[track_caller]
-    pub fn unbond(
+    pub fn update_token_fee(
         &mut self,
         sender: &str,
         token_addr: &Addr,
-        amount: Uint128,
+        swap_fee_rate: Option<Decimal>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
                 self.address.clone(),
-                &ExecuteMsg::Unbond {
+                &ExecuteMsg::UpdateTokenFee {
                     token_addr: token_addr.to_string(),
-                    amount,
+                    swap_fee_rate,
                 },
                 &[],
             )
@@ -209,19 +370,41 @@ impl Project {
     }
 
     #[track_caller]
-    pub fn withdraw(
+    pub fn update_token_caps(
         &mut self,
         sender: &str,
         token_addr: &Addr,
-        amount: Uint128,
+        token_caps: Option<TokenCaps>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
                 self.address.clone(),
-                &ExecuteMsg::Withdraw {
+                &ExecuteMsg::UpdateTokenCaps {
                     token_addr: token_addr.to_string(),
-                    amount,
+                    token_caps,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn update_pair_fee(
+        &mut self,
+        sender: &str,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+        swap_fee_rate: Option<Decimal>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::UpdatePairFee {
+                    token_in_addr: token_in_addr.to_string(),
+                    token_out_addr: token_out_addr.to_string(),
+                    swap_fee_rate,
                 },
                 &[],
             )
@@ -229,148 +412,1063 @@ impl Project {
     }
 
     #[track_caller]
-    pub fn claim(&mut self, sender: &str) -> StdResult<AppResponse> {
+    pub fn reset_halt(&mut self, sender: &str, token_addr: &Addr) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
                 self.address.clone(),
-                &ExecuteMsg::Claim {},
+                &ExecuteMsg::ResetHalt {
+                    token_addr: token_addr.to_string(),
+                },
                 &[],
             )
             .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn swap_and_claim(
+    pub fn update_fee_discounts(
         &mut self,
         sender: &str,
-        token_out_addr: &Addr,
+        fee_discount_list: Vec<FeeDiscount>,
     ) -> StdResult<AppResponse> {
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
                 self.address.clone(),
-                &ExecuteMsg::SwapAndClaim {
-                    token_out_addr: token_out_addr.to_string(),
-                },
+                &ExecuteMsg::UpdateFeeDiscounts { fee_discount_list },
                 &[],
             )
             .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn deposit(
+    pub fn update_lock_boosts(
         &mut self,
         sender: &str,
-        token_addr: &Addr,
-        amount: Uint128,
+        lock_boost_list: Vec<LockBoost>,
     ) -> StdResult<AppResponse> {
-        let msg = cw20::Cw20ExecuteMsg::Send {
-            contract: self.address.to_string(),
-            amount,
-            msg: to_binary(&ReceiveMsg::Deposit {})?,
-        };
-
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
-                token_addr.to_owned(),
-                &msg,
+                self.address.clone(),
+                &ExecuteMsg::UpdateLockBoosts { lock_boost_list },
                 &[],
             )
             .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn swap(
+    pub fn update_hook(
         &mut self,
         sender: &str,
-        amount_in: Uint128,
-        token_in_addr: &Addr,
-        token_out_addr: &Addr,
+        contract_addr: &Addr,
+        hook: Option<Hook>,
     ) -> StdResult<AppResponse> {
-        let msg = cw20::Cw20ExecuteMsg::Send {
-            contract: self.address.to_string(),
-            amount: amount_in,
-            msg: to_binary(&ReceiveMsg::Swap {
-                token_out_addr: token_out_addr.to_string(),
-            })?,
-        };
-
         self.app
             .execute_contract(
                 Addr::unchecked(sender.to_string()),
-                token_in_addr.to_owned(),
-                &msg,
+                self.address.clone(),
+                &ExecuteMsg::UpdateHook {
+                    contract_addr: contract_addr.to_string(),
+                    hook,
+                },
                 &[],
             )
             .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_config(&self) -> StdResult<Config> {
+    pub fn unbond(
+        &mut self,
+        sender: &str,
+        token_addr: &Addr,
+        amount: Uint128,
+        provider_addr: Option<&str>,
+    ) -> StdResult<AppResponse> {
         self.app
-            .wrap()
-            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryConfig {})
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::Unbond {
+                    token_addr: token_addr.to_string(),
+                    amount,
+                    provider_addr: provider_addr.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_tokens_weight(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Decimal)>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryTokensWeight {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn withdraw(
+        &mut self,
+        sender: &str,
+        token_addr: &Addr,
+        amount: Uint128,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::Withdraw {
+                    token_addr: token_addr.to_string(),
+                    amount,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_liquidity(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Uint128)>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryLiquidity {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn create_incentive(
+        &mut self,
+        sender: &str,
+        token_addr_target: &Addr,
+        reward_asset: RewardAsset,
+        start: Timestamp,
+        end: Timestamp,
+        funds: &[Coin],
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::CreateIncentive {
+                    token_addr_target: token_addr_target.to_string(),
+                    reward_asset,
+                    start,
+                    end,
+                },
+                funds,
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_providers(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Vec<Asset>)>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryProviders {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn close_incentive(
+        &mut self,
+        sender: &str,
+        token_addr_target: &Addr,
+        id: u64,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::CloseIncentive {
+                    token_addr_target: token_addr_target.to_string(),
+                    id,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_tokens(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Token)>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryTokens {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn cancel_order(&mut self, sender: &str, id: u64) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::CancelOrder { id },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_balances(&self, address_list: Vec<&str>) -> StdResult<Vec<Balance>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryBalances {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn execute_orders(&mut self, sender: &str, id_list: Vec<u64>) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::ExecuteOrders { id_list },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
     }
 
     #[track_caller]
-    pub fn query_prices_mocked(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Decimal)>> {
-        self.app.wrap().query_wasm_smart(
-            self.address.clone(),
-            &QueryMsg::QueryPricesMocked {
-                address_list: address_list.iter().map(|x| x.to_string()).collect(),
-            },
-        )
+    pub fn cancel_dca(&mut self, sender: &str, id: u64) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::CancelDca { id },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn execute_dca(&mut self, sender: &str, id_list: Vec<u64>) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::ExecuteDca { id_list },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn claim(
+        &mut self,
+        sender: &str,
+        provider_addr: Option<&str>,
+        recipient: Option<&str>,
+        token_addr_list: Option<Vec<&Addr>>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::Claim {
+                    provider_addr: provider_addr.map(|x| x.to_string()),
+                    recipient: recipient.map(|x| x.to_string()),
+                    token_addr_list: token_addr_list
+                        .map(|x| x.iter().map(|y| y.to_string()).collect()),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn batch_swap(&mut self, sender: &str, swap_list: Vec<SwapLeg>) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::BatchSwap {
+                    swap_list,
+                    referral: None,
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn swap_and_claim(
+        &mut self,
+        sender: &str,
+        token_out_addr: &Addr,
+        provider_addr: Option<&str>,
+        recipient: Option<&str>,
+        token_addr_list: Option<Vec<&Addr>>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::SwapAndClaim {
+                    token_out_addr: token_out_addr.to_string(),
+                    provider_addr: provider_addr.map(|x| x.to_string()),
+                    recipient: recipient.map(|x| x.to_string()),
+                    token_addr_list: token_addr_list
+                        .map(|x| x.iter().map(|y| y.to_string()).collect()),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn compound(
+        &mut self,
+        sender: &str,
+        token_out_addr: Option<&Addr>,
+        provider_addr: Option<&str>,
+    ) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::Compound {
+                    token_out_addr: token_out_addr.map(|x| x.to_string()),
+                    provider_addr: provider_addr.map(|x| x.to_string()),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn set_auto_compound(&mut self, sender: &str, is_enabled: bool) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::SetAutoCompound { is_enabled },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn claim_protocol_fees(&mut self, sender: &str) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::ClaimProtocolFees {},
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn transfer_position(&mut self, sender: &str, recipient: &str) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::TransferPosition {
+                    recipient: recipient.to_string(),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn set_operator(&mut self, sender: &str, operator: &str) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::SetOperator {
+                    operator: operator.to_string(),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn revoke_operator(&mut self, sender: &str, operator: &str) -> StdResult<AppResponse> {
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                self.address.clone(),
+                &ExecuteMsg::RevokeOperator {
+                    operator: operator.to_string(),
+                },
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn deposit(
+        &mut self,
+        sender: &str,
+        token_addr: &Addr,
+        amount: Uint128,
+    ) -> StdResult<AppResponse> {
+        self.deposit_locked(sender, token_addr, amount, None)
+    }
+
+    #[track_caller]
+    pub fn deposit_locked(
+        &mut self,
+        sender: &str,
+        token_addr: &Addr,
+        amount: Uint128,
+        lock_duration: Option<u64>,
+    ) -> StdResult<AppResponse> {
+        let msg = cw20::Cw20ExecuteMsg::Send {
+            contract: self.address.to_string(),
+            amount,
+            msg: to_binary(&ReceiveMsg::Deposit {
+                lock_duration: lock_duration.map(|x| Uint128::from(x as u128)),
+            })?,
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_addr.to_owned(),
+                &msg,
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn swap(
+        &mut self,
+        sender: &str,
+        amount_in: Uint128,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+        referral: Option<Referral>,
+    ) -> StdResult<AppResponse> {
+        let msg = cw20::Cw20ExecuteMsg::Send {
+            contract: self.address.to_string(),
+            amount: amount_in,
+            msg: to_binary(&ReceiveMsg::Swap {
+                token_out_addr: token_out_addr.to_string(),
+                referral,
+            })?,
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_in_addr.to_owned(),
+                &msg,
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn swap_exact_out(
+        &mut self,
+        sender: &str,
+        amount_in_max: Uint128,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+        amount_out: Uint128,
+        referral: Option<Referral>,
+    ) -> StdResult<AppResponse> {
+        let msg = cw20::Cw20ExecuteMsg::Send {
+            contract: self.address.to_string(),
+            amount: amount_in_max,
+            msg: to_binary(&ReceiveMsg::SwapExactOut {
+                token_out_addr: token_out_addr.to_string(),
+                amount_out,
+                referral,
+            })?,
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_in_addr.to_owned(),
+                &msg,
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn place_limit_order(
+        &mut self,
+        sender: &str,
+        amount_in: Uint128,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+        min_price: Decimal,
+        expiry: Timestamp,
+    ) -> StdResult<AppResponse> {
+        let msg = cw20::Cw20ExecuteMsg::Send {
+            contract: self.address.to_string(),
+            amount: amount_in,
+            msg: to_binary(&ReceiveMsg::PlaceLimitOrder {
+                token_out_addr: token_out_addr.to_string(),
+                min_price,
+                expiry,
+            })?,
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_in_addr.to_owned(),
+                &msg,
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_dca(
+        &mut self,
+        sender: &str,
+        amount_in: Uint128,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+        amount_per_interval: Uint128,
+        interval: u64,
+        slippage: Decimal,
+    ) -> StdResult<AppResponse> {
+        let msg = cw20::Cw20ExecuteMsg::Send {
+            contract: self.address.to_string(),
+            amount: amount_in,
+            msg: to_binary(&ReceiveMsg::CreateDca {
+                token_out_addr: token_out_addr.to_string(),
+                amount_per_interval,
+                interval: Uint128::from(interval),
+                slippage,
+            })?,
+        };
+
+        self.app
+            .execute_contract(
+                Addr::unchecked(sender.to_string()),
+                token_in_addr.to_owned(),
+                &msg,
+                &[],
+            )
+            .map_err(|err| err.downcast().unwrap())
+    }
+
+    #[track_caller]
+    pub fn query_config(&self) -> StdResult<Config> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryConfig {})
+    }
+
+    #[track_caller]
+    pub fn query_tokens_weight(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Decimal)>> {
+        let page: Page<(Addr, Decimal)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryTokensWeight {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_liquidity(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Uint128)>> {
+        let page: Page<(Addr, Uint128)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryLiquidity {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_providers(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Vec<Asset>)>> {
+        let page: Page<(Addr, Vec<Asset>)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProviders {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_providers_page(
+        &self,
+        address_list: Vec<&str>,
+        start_after: Option<&Addr>,
+        limit: Option<u32>,
+    ) -> StdResult<Page<(Addr, Vec<Asset>)>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProviders {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: start_after.map(|x| x.to_string()),
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_provider(&self, address: &str) -> StdResult<Vec<Asset>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProvider {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_provider_summary(&self, address: &str) -> StdResult<ProviderSummary> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProviderSummary {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_token_apr(&self) -> StdResult<Vec<(Addr, Decimal)>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryTokenApr {})
+    }
+
+    #[track_caller]
+    pub fn query_provider_apr(&self, address: &str) -> StdResult<ProviderApr> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProviderApr {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_pool_stats(&self) -> StdResult<PoolStats> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryPoolStats {})
+    }
+
+    #[track_caller]
+    pub fn query_history(
+        &self,
+        token_addr: &Addr,
+        from: Timestamp,
+        to: Timestamp,
+    ) -> StdResult<Vec<(Timestamp, Snapshot)>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryHistory {
+                token_addr: token_addr.to_string(),
+                from,
+                to,
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_operators(&self, address: &str) -> StdResult<Vec<Addr>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryOperators {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_auto_compound(&self, address: &str) -> StdResult<bool> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryAutoCompound {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_tokens(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Token)>> {
+        let page: Page<(Addr, Token)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryTokens {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_token(&self, token_addr: &Addr) -> StdResult<Token> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryToken {
+                token_addr: token_addr.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_balances(&self, address_list: Vec<&str>) -> StdResult<Vec<Balance>> {
+        let page: Page<Balance> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryBalances {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_fees(&self) -> StdResult<Fees> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryFees {})
+    }
+
+    #[track_caller]
+    pub fn query_swap_allowances(&self, address_list: Vec<&str>) -> StdResult<Vec<SwapAllowance>> {
+        let page: Page<SwapAllowance> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QuerySwapAllowances {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
     }
+
+    #[track_caller]
+    pub fn query_halted_tokens(&self) -> StdResult<Vec<Addr>> {
+        let page: Page<Addr> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryHaltedTokens {
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_price_averages(&self) -> StdResult<Vec<(Addr, Decimal)>> {
+        let page: Page<(Addr, Decimal)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryPriceAverages {
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_fee_multiplier(&self, address: &str) -> StdResult<Decimal> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryFeeMultiplier {
+                address: address.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_swap_fee_rate(
+        &self,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+    ) -> StdResult<Decimal> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QuerySwapFeeRate {
+                token_in_addr: token_in_addr.to_string(),
+                token_out_addr: token_out_addr.to_string(),
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_protocol_fees(&self) -> StdResult<Vec<(Addr, Uint128)>> {
+        let page: Page<(Addr, Uint128)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryProtocolFees {
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_hooks(&self) -> StdResult<Vec<(Addr, Hook)>> {
+        let page: Page<(Addr, Hook)> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryHooks {
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_lock_boosts(&self) -> StdResult<Vec<LockBoost>> {
+        self.app
+            .wrap()
+            .query_wasm_smart(self.address.clone(), &QueryMsg::QueryLockBoosts {})
+    }
+
+    #[track_caller]
+    pub fn query_limit_orders_by_owner(&self, owner: &str) -> StdResult<Vec<(u64, LimitOrder)>> {
+        Ok(self
+            .query_limit_orders_by_owner_page(owner, None, None)?
+            .list)
+    }
+
+    #[track_caller]
+    pub fn query_limit_orders_by_owner_page(
+        &self,
+        owner: &str,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Page<(u64, LimitOrder), u64>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryLimitOrdersByOwner {
+                owner: owner.to_string(),
+                start_after,
+                limit,
+            },
+        )
+    }
+
+    #[track_caller]
+    pub fn query_dca_schedules(&self, owner: Option<&str>) -> StdResult<Vec<(u64, DcaSchedule)>> {
+        let page: Page<(u64, DcaSchedule), u64> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryDcaSchedules {
+                owner: owner.map(|x| x.to_string()),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_limit_orders_by_pair(
+        &self,
+        token_in_addr: &Addr,
+        token_out_addr: &Addr,
+    ) -> StdResult<Vec<(u64, LimitOrder)>> {
+        let page: Page<(u64, LimitOrder), u64> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryLimitOrdersByPair {
+                token_in_addr: token_in_addr.to_string(),
+                token_out_addr: token_out_addr.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_incentives(&self, token_addr_target: &Addr) -> StdResult<Vec<(u64, Incentive)>> {
+        let page: Page<(u64, Incentive), u64> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryIncentives {
+                token_addr_target: token_addr_target.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_incentive_rewards(
+        &self,
+        address: &str,
+        token_addr_target: &Addr,
+    ) -> StdResult<Vec<(u64, RewardAsset)>> {
+        let page: Page<(u64, RewardAsset), u64> = self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryIncentiveRewards {
+                address: address.to_string(),
+                token_addr_target: token_addr_target.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )?;
+
+        Ok(page.list)
+    }
+
+    #[track_caller]
+    pub fn query_prices_mocked(&self, address_list: Vec<&str>) -> StdResult<Vec<(Addr, Decimal)>> {
+        self.app.wrap().query_wasm_smart(
+            self.address.clone(),
+            &QueryMsg::QueryPricesMocked {
+                address_list: address_list.iter().map(|x| x.to_string()).collect(),
+            },
+        )
+    }
+}
+
+// hook receiver storing notifications, it rejects all of them if is_failing is set
+#[cw_serde]
+pub struct HookInstantiateMsg {
+    pub is_failing: bool,
+}
+
+const HOOK_IS_FAILING: Item<bool> = Item::new("is_failing");
+const HOOK_MSGS: Item<Vec<IonFluxHookMsg>> = Item::new("hook_msgs");
+
+fn hook_instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: HookInstantiateMsg,
+) -> StdResult<Response> {
+    HOOK_IS_FAILING.save(deps.storage, &msg.is_failing)?;
+    HOOK_MSGS.save(deps.storage, &vec![])?;
+
+    Ok(Response::new())
+}
+
+fn hook_execute(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: IonFluxExecuteMsg,
+) -> StdResult<Response> {
+    if HOOK_IS_FAILING.load(deps.storage)? {
+        Err(StdError::generic_err("hook is failed"))?;
+    }
+
+    let IonFluxExecuteMsg::IonFluxHook(hook_msg) = msg;
+
+    HOOK_MSGS.update(deps.storage, |mut x| -> StdResult<_> {
+        x.push(hook_msg);
+        Ok(x)
+    })?;
+
+    Ok(Response::new())
+}
+
+fn hook_query(deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_binary(&HOOK_MSGS.load(deps.storage)?)
+}
+
+// state of 0.1.0 layout
+#[cw_serde]
+pub struct LegacyInstantiateMsg {
+    pub contract_name: String,
+    pub version: String,
+}
+
+#[cw_serde]
+struct LegacyConfig {
+    admin: Addr,
+    swap_fee_rate: Decimal,
+    window: Uint128,
+    unbonding_period: Uint128,
+    price_age: Uint128,
+    chain_id_mocked: String,
+}
+
+#[cw_serde]
+struct LegacyToken {
+    symbol: String,
+    price_feed_id_str: String,
+    bonded: (Vec<Sample>, Uint128),
+    requested: (Vec<Sample>, Uint128),
+    swapped_in: (Vec<Sample>, Uint128),
+    swapped_out: (Vec<Sample>, Uint128),
+}
+
+#[cw_serde]
+struct LegacyAsset {
+    token_addr: Addr,
+    bonded: Uint128,
+    unbonded: Uint128,
+    requested: Uint128,
+    counter: Timestamp,
+    rewards: Uint128,
+}
+
+const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+const LEGACY_TOKENS: Map<&Addr, LegacyToken> = Map::new("tokens");
+const LEGACY_PROVIDERS: Map<&Addr, Vec<LegacyAsset>> = Map::new("providers");
+
+fn legacy_instantiate(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: LegacyInstantiateMsg,
+) -> StdResult<Response> {
+    let token_addr = Addr::unchecked(TOKEN_ADDR_ATOM);
+    let bonded = Uint128::from(1_000u128);
+
+    LEGACY_CONFIG.save(
+        deps.storage,
+        &LegacyConfig {
+            admin: info.sender,
+            swap_fee_rate: str_to_dec(SWAP_FEE_RATE),
+            window: Uint128::from(WINDOW),
+            unbonding_period: Uint128::from(UNBONDING_PERIOD),
+            price_age: Uint128::from(PRICE_AGE),
+            chain_id_mocked: CHAIN_ID_MOCKED.to_string(),
+        },
+    )?;
+
+    LEGACY_TOKENS.save(
+        deps.storage,
+        &token_addr,
+        &LegacyToken {
+            symbol: SYMBOL_ATOM.to_string(),
+            price_feed_id_str: PRICE_FEED_ID_STR_ATOM.to_string(),
+            bonded: (vec![Sample::new(bonded, env.block.time)], bonded),
+            requested: (vec![], Uint128::zero()),
+            swapped_in: (vec![], Uint128::zero()),
+            swapped_out: (vec![], Uint128::zero()),
+        },
+    )?;
+
+    LEGACY_PROVIDERS.save(
+        deps.storage,
+        &Addr::unchecked(ADDR_ALICE_INJ),
+        &vec![LegacyAsset {
+            token_addr,
+            bonded,
+            unbonded: Uint128::zero(),
+            requested: Uint128::zero(),
+            counter: env.block.time,
+            rewards: Uint128::zero(),
+        }],
+    )?;
+
+    LEGACY_PROVIDERS.save(
+        deps.storage,
+        &Addr::unchecked(ADDR_BOB_INJ),
+        &vec![LegacyAsset {
+            token_addr: Addr::unchecked(TOKEN_ADDR_ATOM),
+            bonded: Uint128::from(500u128),
+            unbonded: Uint128::from(200u128),
+            requested: Uint128::zero(),
+            counter: env.block.time,
+            rewards: Uint128::zero(),
+        }],
+    )?;
+
+    cw2::set_contract_version(deps.storage, msg.contract_name, msg.version)?;
+
+    Ok(Response::new())
+}
+
+fn legacy_execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> StdResult<Response> {
+    Ok(Response::new())
+}
+
+fn legacy_query(_deps: Deps, _env: Env, _msg: Empty) -> StdResult<Binary> {
+    to_binary(&Empty {})
 }
@@ -1,17 +1,31 @@
-use cosmwasm_std::{Addr, Decimal, Timestamp, Uint128};
+use cosmwasm_std::{testing::mock_dependencies, Addr, Decimal, Timestamp, Uint128};
 
 use cw20::Cw20Coin;
 
+use cw_multi_test::AppResponse;
+
 use crate::{
     actions::{
-        instantiate::{PRICE_AGE, SWAP_FEE_RATE, UNBONDING_PERIOD, WINDOW},
+        instantiate::{EPOCH, PRICE_AGE, SWAP_FEE_RATE, UNBONDING_PERIOD, WINDOW},
         math::{str_to_dec, u128_to_dec},
+        receive::{check_circuit_breaker, check_price_deviation},
+    },
+    messages::{
+        execute::SwapLeg,
+        hook::{IonFluxHookMsg, LiquidityAction},
+        migrate::MigrateMsg,
+        receive::Referral,
+        response::{AssetSummary, Balance, Fees, SwapAllowance},
+    },
+    state::{
+        Asset, CircuitBreaker, Config, DynamicFee, FeeDiscount, Hook, Lock, LockBoost, RateLimit,
+        RewardAsset, RewardToken, Sample, Snapshot, Token, TokenCaps, CHAIN_ID_MOCKED,
+        KEEPER_TIP_RATE,
     },
-    messages::response::Balance,
-    state::{Asset, Config, Sample, Token},
     tests::helpers::{
         Project, ADDR_ADMIN_INJ, ADDR_ALICE_INJ, ADDR_BOB_INJ, CHAIN_ID_TESTNET,
-        PRICE_FEED_ID_STR_ATOM, PRICE_FEED_ID_STR_LUNA, SYMBOL_ATOM, SYMBOL_LUNA,
+        PRICE_FEED_ID_STR_ATOM, PRICE_FEED_ID_STR_LUNA, SYMBOL_ATOM, SYMBOL_LUNA, TOKEN_ADDR_ATOM,
+        TOKEN_ADDR_LUNA,
     },
 };
 
@@ -60,6 +74,14 @@ fn update_config_default() {
         None,
         None,
         Some(Uint128::from(2 * PRICE_AGE)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 
@@ -70,7 +92,8 @@ fn update_config_default() {
             SWAP_FEE_RATE,
             WINDOW,
             UNBONDING_PERIOD,
-            2 * PRICE_AGE
+            2 * PRICE_AGE,
+            EPOCH
         )
     );
 }
@@ -87,6 +110,14 @@ fn update_config_unauthorized() {
         None,
         None,
         Some(Uint128::from(2 * PRICE_AGE)),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
     )
     .unwrap();
 }
@@ -127,7 +158,7 @@ fn withdraw_without_unbond() {
 fn withdraw_with_unbond_but_too_early() {
     let (mut prj, token, mint_amount) = default_init();
 
-    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount)
+    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount, None)
         .unwrap();
 
     prj.withdraw(ADDR_ALICE_INJ, &token, mint_amount.amount)
@@ -138,7 +169,7 @@ fn withdraw_with_unbond_but_too_early() {
 fn withdraw() {
     let (mut prj, token, mint_amount) = default_init();
 
-    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount)
+    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount, None)
         .unwrap();
 
     prj.wait(UNBONDING_PERIOD as u64);
@@ -175,7 +206,7 @@ fn deposit_unbond_withdraw_loop() {
         .unwrap();
 
     // unbond 3 ATOM
-    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(3u128))
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(3u128), None)
         .unwrap();
 
     // wait UNBONDING_PERIOD
@@ -186,7 +217,7 @@ fn deposit_unbond_withdraw_loop() {
         .unwrap();
 
     // unbond 1 ATOM
-    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(1u128))
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(1u128), None)
         .unwrap();
 
     assert_eq!(
@@ -200,6 +231,7 @@ fn deposit_unbond_withdraw_loop() {
                 requested: Uint128::from(1u128),
                 counter: Timestamp::from_nanos(1571804619879305533u64),
                 rewards: Uint128::from(0u128),
+                lock: None,
             }]
         )
     );
@@ -208,7 +240,7 @@ fn deposit_unbond_withdraw_loop() {
     prj.wait((UNBONDING_PERIOD / 2) as u64);
 
     // unbond 5 ATOM
-    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(5u128))
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(5u128), None)
         .unwrap();
 
     assert_eq!(
@@ -222,6 +254,7 @@ fn deposit_unbond_withdraw_loop() {
                 requested: Uint128::from(6u128),
                 counter: Timestamp::from_nanos(1571806419879305533u64),
                 rewards: Uint128::from(0u128),
+                lock: None,
             }]
         )
     );
@@ -242,6 +275,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(6u128),
                     counter: Timestamp::from_nanos(1571806419879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -250,6 +284,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::from_nanos(1571802819879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -259,7 +294,7 @@ fn deposit_unbond_withdraw_loop() {
     prj.wait((UNBONDING_PERIOD / 2) as u64);
 
     // unbond 3 LUNA
-    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128))
+    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128), None)
         .unwrap();
 
     assert_eq!(
@@ -274,6 +309,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(6u128),
                     counter: Timestamp::from_nanos(1571806419879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -282,6 +318,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(3u128),
                     counter: Timestamp::from_nanos(1571808219879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -291,7 +328,7 @@ fn deposit_unbond_withdraw_loop() {
     prj.wait((UNBONDING_PERIOD / 2) as u64);
 
     // unbond 3 LUNA
-    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128))
+    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128), None)
         .unwrap();
 
     assert_eq!(
@@ -306,6 +343,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(0u128),
                     counter: Timestamp::from_nanos(1571806419879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -314,6 +352,7 @@ fn deposit_unbond_withdraw_loop() {
                     requested: Uint128::from(6u128),
                     counter: Timestamp::from_nanos(1571810019879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -334,12 +373,13 @@ fn deposit_unbond_withdraw_loop() {
                 requested: Uint128::from(6u128),
                 counter: Timestamp::from_nanos(1571810019879305533u64),
                 rewards: Uint128::from(0u128),
+                lock: None,
             },]
         )
     );
 
     // unbond 3 LUNA
-    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128))
+    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(3u128), None)
         .unwrap();
 
     // wait UNBONDING_PERIOD
@@ -382,9 +422,9 @@ fn deposit_2_providers() {
     prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
         .unwrap();
 
-    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount)
+    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount, None)
         .unwrap();
-    prj.unbond(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+    prj.unbond(ADDR_ALICE_INJ, &token2, mint_amount.amount, None)
         .unwrap();
 
     assert_eq!(
@@ -399,6 +439,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(5u128),
                     counter: Timestamp::from_nanos(1571801019879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -407,6 +448,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(5u128),
                     counter: Timestamp::from_nanos(1571801019879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -419,9 +461,9 @@ fn deposit_2_providers() {
     prj.deposit(ADDR_BOB_INJ, &token2, mint_amount2.amount)
         .unwrap();
 
-    prj.unbond(ADDR_BOB_INJ, &token, mint_amount2.amount)
+    prj.unbond(ADDR_BOB_INJ, &token, mint_amount2.amount, None)
         .unwrap();
-    prj.unbond(ADDR_BOB_INJ, &token2, mint_amount2.amount)
+    prj.unbond(ADDR_BOB_INJ, &token2, mint_amount2.amount, None)
         .unwrap();
 
     assert_eq!(
@@ -436,6 +478,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(5u128),
                     counter: Timestamp::from_nanos(1571801019879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -444,6 +487,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(5u128),
                     counter: Timestamp::from_nanos(1571801019879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -460,6 +504,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(50u128),
                     counter: Timestamp::from_nanos(1571802819879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
                 Asset {
                     token_addr: token2.clone(),
@@ -468,6 +513,7 @@ fn deposit_2_providers() {
                     requested: Uint128::from(50u128),
                     counter: Timestamp::from_nanos(1571802819879305533u64),
                     rewards: Uint128::from(0u128),
+                    lock: None,
                 },
             ]
         )
@@ -498,6 +544,7 @@ fn deposit_2_providers() {
                         requested: Uint128::from(50u128),
                         counter: Timestamp::from_nanos(1571802819879305533u64),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                     Asset {
                         token_addr: Addr::unchecked("contract2"),
@@ -506,6 +553,7 @@ fn deposit_2_providers() {
                         requested: Uint128::from(50u128),
                         counter: Timestamp::from_nanos(1571802819879305533u64),
                         rewards: Uint128::from(0u128),
+                        lock: None,
                     },
                 ]
             ),
@@ -586,6 +634,10 @@ fn query_tokens() {
                     requested: (vec![], Uint128::from(0u128)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
             (
@@ -609,6 +661,10 @@ fn query_tokens() {
                     requested: (vec![], Uint128::from(0u128)),
                     swapped_in: (vec![], Uint128::from(0u128)),
                     swapped_out: (vec![], Uint128::from(0u128)),
+                    swap_fee_rate: None,
+                    swapped_in_total: Uint128::zero(),
+                    swapped_out_total: Uint128::zero(),
+                    fees_total: Uint128::zero(),
                 },
             ),
         ]
@@ -668,7 +724,7 @@ fn swap_default() {
             / token_out_price)
             .to_uint_floor();
 
-    prj.swap(ADDR_ADMIN_INJ, amount_in, &token, &token2)
+    prj.swap(ADDR_ADMIN_INJ, amount_in, &token, &token2, None)
         .unwrap();
 
     assert_eq!(
@@ -709,7 +765,8 @@ fn swap_same_tokens() {
 
     let amount_in = mint_amount.amount / Uint128::from(10u128);
 
-    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token).unwrap();
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token, None)
+        .unwrap();
 }
 
 #[test]
@@ -762,6 +819,7 @@ fn swap_updates_unbonded() {
         ADDR_BOB_INJ,
         &token,
         mint_amount2.amount / Uint128::from(2u128),
+        None,
     )
     .unwrap();
     prj.wait(UNBONDING_PERIOD as u64);
@@ -775,6 +833,7 @@ fn swap_updates_unbonded() {
         mint_amount2.amount / Uint128::from(20u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
 
@@ -818,9 +877,10 @@ fn claim_default() {
         / token_out_price)
         .to_uint_floor();
 
-    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2).unwrap();
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
 
-    prj.claim(ADDR_ALICE_INJ).unwrap();
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
 
     assert_eq!(
         prj.get_cw20_balance(token, ADDR_ALICE_INJ),
@@ -854,7 +914,7 @@ fn claim_no_rewards() {
     prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
         .unwrap();
 
-    prj.claim(ADDR_ALICE_INJ).unwrap();
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
 }
 
 #[test]
@@ -893,9 +953,11 @@ fn swap_and_claim_default() {
     ) * token_out_price)
         .to_uint_floor();
 
-    prj.swap(ADDR_BOB_INJ, amount_in, &token2, &token).unwrap();
+    prj.swap(ADDR_BOB_INJ, amount_in, &token2, &token, None)
+        .unwrap();
 
-    prj.swap_and_claim(ADDR_ALICE_INJ, &token).unwrap();
+    prj.swap_and_claim(ADDR_ALICE_INJ, &token, None, None, None)
+        .unwrap();
 
     assert_eq!(prj.get_cw20_balance(token, ADDR_ALICE_INJ), rewards);
 }
@@ -926,7 +988,8 @@ fn swap_and_claim_no_rewards() {
     prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
         .unwrap();
 
-    prj.swap_and_claim(ADDR_ALICE_INJ, &token2).unwrap();
+    prj.swap_and_claim(ADDR_ALICE_INJ, &token2, None, None, None)
+        .unwrap();
 }
 
 #[test]
@@ -940,7 +1003,8 @@ fn query_config_default() {
             SWAP_FEE_RATE,
             WINDOW,
             UNBONDING_PERIOD,
-            PRICE_AGE
+            PRICE_AGE,
+            EPOCH
         )
     );
 }
@@ -991,6 +1055,7 @@ fn tokens_weight_decreases_on_swap_in() {
         mint_amount2.amount / Uint128::from(2u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1037,6 +1102,7 @@ fn tokens_weight_increases_on_swap_out() {
         mint_amount2.amount / Uint128::from(4u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1047,6 +1113,7 @@ fn tokens_weight_increases_on_swap_out() {
         mint_amount2.amount / Uint128::from(2u128),
         &token2,
         &token,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1092,6 +1159,7 @@ fn tokens_weight_decreases_on_deposit() {
         mint_amount2.amount / Uint128::from(20u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1102,6 +1170,7 @@ fn tokens_weight_decreases_on_deposit() {
         mint_amount2.amount / Uint128::from(20u128),
         &token2,
         &token,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1169,6 +1238,7 @@ fn tokens_weight_increases_on_unbond() {
         mint_amount2.amount / Uint128::from(20u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1179,6 +1249,7 @@ fn tokens_weight_increases_on_unbond() {
         mint_amount2.amount / Uint128::from(20u128),
         &token2,
         &token,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1188,6 +1259,7 @@ fn tokens_weight_increases_on_unbond() {
         ADDR_BOB_INJ,
         &token,
         mint_amount2.amount / Uint128::from(2u128),
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1242,6 +1314,7 @@ fn tokens_weight_doesnt_change_on_withdraw() {
         mint_amount2.amount / Uint128::from(20u128),
         &token,
         &token2,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1252,6 +1325,7 @@ fn tokens_weight_doesnt_change_on_withdraw() {
         mint_amount2.amount / Uint128::from(20u128),
         &token2,
         &token,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1261,6 +1335,7 @@ fn tokens_weight_doesnt_change_on_withdraw() {
         ADDR_BOB_INJ,
         &token,
         mint_amount2.amount / Uint128::from(2u128),
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD) as u64);
@@ -1290,6 +1365,7 @@ fn query_liquidity_default() {
         ADDR_ALICE_INJ,
         &token,
         mint_amount.amount / Uint128::from(5u128),
+        None,
     )
     .unwrap();
 
@@ -1299,6 +1375,7 @@ fn query_liquidity_default() {
         ADDR_ALICE_INJ,
         &token,
         mint_amount.amount / Uint128::from(2u128),
+        None,
     )
     .unwrap();
 
@@ -1351,6 +1428,7 @@ fn liquidity_manipulations() {
         mint_amount2.amount / Uint128::from(2u128),
         &token2,
         &token,
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD / 100) as u64);
@@ -1360,6 +1438,7 @@ fn liquidity_manipulations() {
         ADDR_BOB_INJ,
         &token,
         mint_amount2.amount / Uint128::from(2u128),
+        None,
     )
     .unwrap();
     prj.wait((UNBONDING_PERIOD) as u64);
@@ -1383,7 +1462,7 @@ fn liquidity_manipulations() {
     prj.wait((UNBONDING_PERIOD / 100) as u64);
 
     // unbond x of ATOM total liquidity
-    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount)
+    prj.unbond(ADDR_ALICE_INJ, &token, mint_amount.amount, None)
         .unwrap();
     prj.wait((UNBONDING_PERIOD) as u64);
 
@@ -1528,3 +1607,3762 @@ fn query_prices_mocked_real_network() {
     prj.query_prices_mocked(vec![token.as_str(), token2.as_str()])
         .unwrap();
 }
+
+#[test]
+fn transfer_position_default() {
+    let (mut prj, token, mint_amount) = default_init();
+
+    prj.unbond(
+        ADDR_ALICE_INJ,
+        &token,
+        mint_amount.amount / Uint128::from(5u128),
+        None,
+    )
+    .unwrap();
+
+    let provider = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0]
+        .1
+        .clone();
+
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    assert_eq!(prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap(), vec![]);
+    assert_eq!(
+        prj.query_providers(vec![ADDR_BOB_INJ]).unwrap(),
+        vec![(Addr::unchecked(ADDR_BOB_INJ), provider)]
+    );
+}
+
+#[test]
+fn transfer_position_clears_settings() {
+    let (mut prj, ..) = default_init();
+
+    prj.set_operator(ADDR_ALICE_INJ, ADDR_ADMIN_INJ).unwrap();
+    prj.set_auto_compound(ADDR_ALICE_INJ, true).unwrap();
+    prj.set_auto_compound(ADDR_BOB_INJ, true).unwrap();
+
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    // sender settings are removed, recipient ones are kept
+    assert!(prj.query_operators(ADDR_ALICE_INJ).unwrap().is_empty());
+    assert!(!prj.query_auto_compound(ADDR_ALICE_INJ).unwrap());
+    assert!(prj.query_operators(ADDR_BOB_INJ).unwrap().is_empty());
+    assert!(prj.query_auto_compound(ADDR_BOB_INJ).unwrap());
+}
+
+#[test]
+fn transfer_position_merges_assets() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(20u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token, mint_amount2.amount)
+        .unwrap();
+
+    prj.unbond(ADDR_BOB_INJ, &token, Uint128::from(4u128), None)
+        .unwrap();
+    prj.wait((UNBONDING_PERIOD / 2) as u64);
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(2u128), None)
+        .unwrap();
+
+    let counter_alice = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[0].counter;
+
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    assert_eq!(
+        prj.query_providers(vec![ADDR_BOB_INJ]).unwrap()[0],
+        (
+            Addr::unchecked(ADDR_BOB_INJ),
+            vec![Asset {
+                token_addr: token,
+                bonded: Uint128::from(24u128),
+                unbonded: Uint128::from(0u128),
+                requested: Uint128::from(6u128),
+                counter: counter_alice,
+                rewards: Uint128::from(0u128),
+                lock: None,
+            }]
+        )
+    );
+}
+
+#[test]
+#[should_panic(expected = "Can not transfer position to the same address!")]
+fn transfer_position_to_itself() {
+    let (mut prj, ..) = default_init();
+
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_ALICE_INJ)
+        .unwrap();
+}
+
+#[test]
+fn operator_unbond_and_claim() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    prj.set_operator(ADDR_ALICE_INJ, ADDR_ADMIN_INJ).unwrap();
+
+    assert_eq!(
+        prj.query_operators(ADDR_ALICE_INJ).unwrap(),
+        vec![Addr::unchecked(ADDR_ADMIN_INJ)]
+    );
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    let rewards = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].rewards;
+
+    prj.claim(ADDR_ADMIN_INJ, Some(ADDR_ALICE_INJ), None, None)
+        .unwrap();
+    prj.unbond(
+        ADDR_ADMIN_INJ,
+        &token2,
+        mint_amount.amount,
+        Some(ADDR_ALICE_INJ),
+    )
+    .unwrap();
+
+    // rewards must be sent to provider instead of operator
+    assert_eq!(
+        prj.get_cw20_balance(token.clone(), ADDR_ALICE_INJ),
+        mint_amount.amount + rewards
+    );
+    assert_eq!(prj.get_cw20_balance(token, ADDR_ADMIN_INJ), Uint128::zero());
+    assert_eq!(
+        prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[0].requested,
+        mint_amount.amount
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn operator_revoked() {
+    let (mut prj, token, mint_amount) = default_init();
+
+    prj.set_operator(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+    prj.revoke_operator(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    assert_eq!(
+        prj.query_operators(ADDR_ALICE_INJ).unwrap(),
+        Vec::<Addr>::new()
+    );
+
+    prj.unbond(
+        ADDR_BOB_INJ,
+        &token,
+        mint_amount.amount,
+        Some(ADDR_ALICE_INJ),
+    )
+    .unwrap();
+}
+
+#[test]
+fn compound_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    let rewards = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].rewards;
+
+    prj.compound(ADDR_ALICE_INJ, None, None).unwrap();
+
+    let asset = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].clone();
+
+    assert_eq!(asset.token_addr, token);
+    assert_eq!(asset.bonded, rewards);
+    assert_eq!(asset.rewards, Uint128::zero());
+    assert_eq!(
+        prj.query_tokens(vec![token.as_str()]).unwrap()[0]
+            .1
+            .bonded
+            .0
+            .last()
+            .unwrap()
+            .value,
+        rewards
+    );
+}
+
+#[test]
+fn compound_to_single_token() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_in_price, token_out_price) = (price_list[0].1, price_list[1].1);
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    let rewards = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].rewards;
+
+    prj.compound(ADDR_ALICE_INJ, Some(&token2), None).unwrap();
+
+    let asset_list = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0]
+        .1
+        .clone();
+
+    assert_eq!(
+        asset_list[0].bonded,
+        mint_amount.amount
+            + (u128_to_dec(rewards) * token_in_price / token_out_price).to_uint_floor()
+    );
+    assert_eq!(asset_list[1].rewards, Uint128::zero());
+}
+
+#[test]
+#[should_panic(expected = "There is nothing to claim!")]
+fn compound_no_rewards() {
+    let (mut prj, ..) = default_init();
+
+    prj.compound(ADDR_ALICE_INJ, None, None).unwrap();
+}
+
+#[test]
+fn auto_compound_on_swap() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    prj.set_auto_compound(ADDR_ALICE_INJ, true).unwrap();
+
+    assert!(prj.query_auto_compound(ADDR_ALICE_INJ).unwrap());
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    let asset_alice = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].clone();
+    let asset_bob = prj.query_providers(vec![ADDR_BOB_INJ]).unwrap()[0].1[1].clone();
+
+    // rewards are bonded for alice and accumulated for bob
+    assert_eq!(asset_alice.bonded, asset_bob.rewards);
+    assert_eq!(asset_alice.rewards, Uint128::zero());
+    assert_eq!(asset_bob.bonded, Uint128::zero());
+}
+
+#[test]
+fn claim_to_recipient_selected_tokens() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+    prj.swap(ADDR_BOB_INJ, amount_in, &token2, &token, None)
+        .unwrap();
+
+    let asset_list = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0]
+        .1
+        .clone();
+    let (rewards_atom, rewards_luna) = (asset_list[0].rewards, asset_list[1].rewards);
+
+    prj.claim(
+        ADDR_ALICE_INJ,
+        None,
+        Some(ADDR_ADMIN_INJ),
+        Some(vec![&token]),
+    )
+    .unwrap();
+
+    let asset_list = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0]
+        .1
+        .clone();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ), rewards_atom);
+    assert_eq!(
+        prj.get_cw20_balance(&token2, ADDR_ADMIN_INJ),
+        Uint128::zero()
+    );
+    assert_eq!(asset_list[0].rewards, Uint128::zero());
+    assert_eq!(asset_list[1].rewards, rewards_luna);
+}
+
+#[test]
+#[should_panic(expected = "There is nothing to claim!")]
+fn swap_and_claim_selected_tokens_no_rewards() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+
+    // rewards are accumulated in ATOM only
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    prj.swap_and_claim(ADDR_ALICE_INJ, &token2, None, None, Some(vec![&token2]))
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn claim_operator_with_recipient() {
+    let (mut prj, ..) = default_init();
+
+    prj.set_operator(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    prj.claim(ADDR_BOB_INJ, Some(ADDR_ALICE_INJ), Some(ADDR_BOB_INJ), None)
+        .unwrap();
+}
+
+#[test]
+fn protocol_fees_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(str_to_dec("0.2")),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let amount_in = mint_amount.amount / Uint128::from(10u128);
+    let protocol_fee =
+        (str_to_dec("0.2") * str_to_dec(SWAP_FEE_RATE) * u128_to_dec(amount_in)).to_uint_floor();
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    assert_eq!(
+        prj.query_protocol_fees().unwrap(),
+        vec![(token.clone(), protocol_fee)]
+    );
+
+    let rewards = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].1[1].rewards;
+    let balance = prj.get_cw20_balance(&token, prj.address.clone());
+
+    assert_eq!(
+        prj.query_liquidity(vec![token.as_str()]).unwrap()[0].1,
+        balance - rewards - protocol_fee
+    );
+
+    prj.claim_protocol_fees(ADDR_ADMIN_INJ).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ), protocol_fee);
+    assert_eq!(prj.query_protocol_fees().unwrap(), vec![]);
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn protocol_fees_claim_unauthorized() {
+    let (mut prj, ..) = default_init();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(ADDR_BOB_INJ.to_string()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.claim_protocol_fees(ADDR_ADMIN_INJ).unwrap();
+}
+
+#[test]
+fn dynamic_fee_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    let min_fee_rate = str_to_dec("0.001");
+    let max_fee_rate = str_to_dec("0.01");
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(DynamicFee {
+            is_enabled: true,
+            min_fee_rate,
+            max_fee_rate,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    // there is no outflow pressure yet
+    assert_eq!(
+        prj.query_swap_fee_rate(&token, &token2).unwrap(),
+        str_to_dec(SWAP_FEE_RATE)
+    );
+
+    // unbond creates outflow pressure on LUNA
+    prj.wait((UNBONDING_PERIOD / 100) as u64);
+    prj.unbond(
+        ADDR_ALICE_INJ,
+        &token2,
+        mint_amount.amount / Uint128::from(2u128),
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_swap_fee_rate(&token, &token2).unwrap(),
+        max_fee_rate
+    );
+    assert_eq!(
+        prj.query_swap_fee_rate(&token2, &token).unwrap(),
+        min_fee_rate
+    );
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_in_price, token_out_price) = (price_list[0].1, price_list[1].1);
+
+    let amount_in = mint_amount2.amount / Uint128::from(10u128);
+    let amount_out = ((Decimal::one() - max_fee_rate) * u128_to_dec(amount_in) * token_in_price
+        / token_out_price)
+        .to_uint_floor();
+
+    let res = prj
+        .swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    assert!(res.events.iter().any(|x| x
+        .attributes
+        .iter()
+        .any(|y| y.key == "swap_fee_rate" && y.value == max_fee_rate.to_string())));
+    assert_eq!(
+        prj.get_cw20_balance(&token2, ADDR_BOB_INJ),
+        mint_amount2.amount + amount_out
+    );
+}
+
+#[test]
+fn dynamic_fee_with_pair_fee() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    let min_fee_rate = str_to_dec("0.001");
+    let max_fee_rate = str_to_dec("0.01");
+    let pair_fee_rate = str_to_dec("0.0005");
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(DynamicFee {
+            is_enabled: true,
+            min_fee_rate,
+            max_fee_rate,
+        }),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    prj.update_pair_fee(ADDR_ADMIN_INJ, &token, &token2, Some(pair_fee_rate))
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    // unbond creates outflow pressure on LUNA
+    prj.wait((UNBONDING_PERIOD / 100) as u64);
+    prj.unbond(
+        ADDR_ALICE_INJ,
+        &token2,
+        mint_amount.amount / Uint128::from(2u128),
+        None,
+    )
+    .unwrap();
+
+    // pair fee isn't clamped to dynamic fee range, reverse pair is still dynamic
+    assert_eq!(
+        prj.query_swap_fee_rate(&token, &token2).unwrap(),
+        pair_fee_rate
+    );
+    assert_eq!(
+        prj.query_swap_fee_rate(&token2, &token).unwrap(),
+        min_fee_rate
+    );
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_in_price, token_out_price) = (price_list[0].1, price_list[1].1);
+
+    let amount_in = mint_amount2.amount / Uint128::from(10u128);
+    let amount_out = ((Decimal::one() - pair_fee_rate) * u128_to_dec(amount_in) * token_in_price
+        / token_out_price)
+        .to_uint_floor();
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token, &token2, None)
+        .unwrap();
+
+    assert_eq!(
+        prj.get_cw20_balance(&token2, ADDR_BOB_INJ),
+        mint_amount2.amount + amount_out
+    );
+}
+
+#[test]
+fn swap_fee_overrides() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    let token_fee_rate = str_to_dec("0.001");
+    let pair_fee_rate = str_to_dec("0.0005");
+
+    prj.update_token_fee(ADDR_ADMIN_INJ, &token, Some(token_fee_rate))
+        .unwrap();
+    prj.update_pair_fee(ADDR_ADMIN_INJ, &token2, &token, Some(pair_fee_rate))
+        .unwrap();
+
+    assert_eq!(
+        prj.query_fees().unwrap(),
+        Fees {
+            swap_fee_rate: str_to_dec(SWAP_FEE_RATE),
+            token_fee_list: vec![(token.clone(), token_fee_rate)],
+            pair_fee_list: vec![(token2.clone(), token.clone(), pair_fee_rate)],
+        }
+    );
+
+    // pair fee has priority over token fee
+    assert_eq!(
+        prj.query_swap_fee_rate(&token2, &token).unwrap(),
+        pair_fee_rate
+    );
+    assert_eq!(
+        prj.query_swap_fee_rate(&token, &token2).unwrap(),
+        token_fee_rate
+    );
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_price, token2_price) = (price_list[0].1, price_list[1].1);
+
+    let amount_in = mint_amount2.amount / Uint128::from(10u128);
+    let amount_out = ((Decimal::one() - pair_fee_rate) * u128_to_dec(amount_in) * token2_price
+        / token_price)
+        .to_uint_floor();
+
+    prj.swap(ADDR_BOB_INJ, amount_in, &token2, &token, None)
+        .unwrap();
+
+    assert_eq!(
+        prj.get_cw20_balance(&token, ADDR_BOB_INJ),
+        mint_amount2.amount + amount_out
+    );
+
+    // removed overrides fall back to config fee
+    prj.update_token_fee(ADDR_ADMIN_INJ, &token, None).unwrap();
+    prj.update_pair_fee(ADDR_ADMIN_INJ, &token2, &token, None)
+        .unwrap();
+
+    assert_eq!(
+        prj.query_swap_fee_rate(&token2, &token).unwrap(),
+        str_to_dec(SWAP_FEE_RATE)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Fee rate is out of range!")]
+fn swap_fee_overrides_out_of_range() {
+    let (mut prj, token, _) = default_init();
+
+    prj.update_token_fee(ADDR_ADMIN_INJ, &token, Some(Decimal::one()))
+        .unwrap();
+}
+
+#[test]
+fn swap_with_referral() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount3 = Cw20Coin {
+        address: ADDR_ADMIN_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount3.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount3.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(str_to_dec("0.01")),
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_in_price, token_out_price) = (price_list[0].1, price_list[1].1);
+
+    let commission_rate = str_to_dec("0.005");
+    let amount_in = mint_amount3.amount / Uint128::from(10u128);
+    let commission = (commission_rate * u128_to_dec(amount_in)).to_uint_floor();
+    let amount_out = ((Decimal::one() - str_to_dec(SWAP_FEE_RATE))
+        * u128_to_dec(amount_in - commission)
+        * token_in_price
+        / token_out_price)
+        .to_uint_floor();
+
+    let res = prj
+        .swap(
+            ADDR_ADMIN_INJ,
+            amount_in,
+            &token,
+            &token2,
+            Some(Referral {
+                address: ADDR_BOB_INJ.to_string(),
+                commission_rate,
+            }),
+        )
+        .unwrap();
+
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "referral_commission" && a.value == commission.to_string())));
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_BOB_INJ), commission);
+    assert_eq!(
+        prj.get_cw20_balance(&token, ADDR_ADMIN_INJ),
+        mint_amount3.amount - amount_in
+    );
+    assert_eq!(
+        prj.get_cw20_balance(&token2, ADDR_ADMIN_INJ),
+        mint_amount3.amount + amount_out
+    );
+}
+
+#[test]
+#[should_panic(expected = "Referral commission rate exceeds maximum!")]
+fn swap_with_referral_commission_too_high() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(
+        ADDR_ALICE_INJ,
+        &token2,
+        mint_amount.amount / Uint128::from(2u128),
+    )
+    .unwrap();
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        Some(Referral {
+            address: ADDR_BOB_INJ.to_string(),
+            commission_rate: str_to_dec("0.01"),
+        }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn fee_discount_tiers() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token_gov = prj.create_cw20(
+        "GOV",
+        vec![
+            Cw20Coin {
+                address: ADDR_ALICE_INJ.to_string(),
+                amount: Uint128::from(999u128),
+            },
+            Cw20Coin {
+                address: ADDR_BOB_INJ.to_string(),
+                amount: Uint128::from(1_000u128),
+            },
+            Cw20Coin {
+                address: ADDR_ADMIN_INJ.to_string(),
+                amount: Uint128::from(10_000u128),
+            },
+        ],
+    );
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_fee_discounts(
+        ADDR_ADMIN_INJ,
+        vec![
+            FeeDiscount {
+                token_addr: token_gov.clone(),
+                min_balance: Uint128::from(1_000u128),
+                fee_multiplier: str_to_dec("0.5"),
+            },
+            FeeDiscount {
+                token_addr: token_gov,
+                min_balance: Uint128::from(10_000u128),
+                fee_multiplier: str_to_dec("0.25"),
+            },
+        ],
+    )
+    .unwrap();
+
+    // tier boundaries
+    assert_eq!(
+        prj.query_fee_multiplier(ADDR_ALICE_INJ).unwrap(),
+        Decimal::one()
+    );
+    assert_eq!(
+        prj.query_fee_multiplier(ADDR_BOB_INJ).unwrap(),
+        str_to_dec("0.5")
+    );
+    assert_eq!(
+        prj.query_fee_multiplier(ADDR_ADMIN_INJ).unwrap(),
+        str_to_dec("0.25")
+    );
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+
+    let price_list = prj
+        .query_prices_mocked(vec![token.as_str(), token2.as_str()])
+        .unwrap();
+    let (token_price, token2_price) = (price_list[0].1, price_list[1].1);
+
+    let amount_in = mint_amount2.amount / Uint128::from(10u128);
+    let swap_fee_rate = str_to_dec(SWAP_FEE_RATE) * str_to_dec("0.5");
+    let amount_out = ((Decimal::one() - swap_fee_rate) * u128_to_dec(amount_in) * token2_price
+        / token_price)
+        .to_uint_floor();
+
+    let res = prj
+        .swap(ADDR_BOB_INJ, amount_in, &token2, &token, None)
+        .unwrap();
+
+    assert!(res.events.iter().any(|e| e
+        .attributes
+        .iter()
+        .any(|a| a.key == "swap_fee_rate" && a.value == swap_fee_rate.to_string())));
+    assert_eq!(
+        prj.get_cw20_balance(&token, ADDR_BOB_INJ),
+        mint_amount2.amount + amount_out
+    );
+}
+
+#[test]
+#[should_panic(expected = "Fee rate is out of range!")]
+fn fee_discount_tiers_out_of_range() {
+    let (mut prj, token, _) = default_init();
+
+    prj.update_fee_discounts(
+        ADDR_ADMIN_INJ,
+        vec![FeeDiscount {
+            token_addr: token,
+            min_balance: Uint128::from(1u128),
+            fee_multiplier: str_to_dec("1.5"),
+        }],
+    )
+    .unwrap();
+}
+
+fn token_caps_init(token_caps: TokenCaps) -> (Project, Addr, Addr) {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_token_caps(ADDR_ADMIN_INJ, &token2, Some(token_caps))
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+
+    (prj, token, token2)
+}
+
+#[test]
+#[should_panic(expected = "Swap amount exceeds max single swap amount 9003 of token liquidity!")]
+fn token_caps_swap_out_ratio() {
+    let (mut prj, token, token2) = token_caps_init(TokenCaps {
+        max_swap_out_ratio: Some(str_to_dec("0.1")),
+        max_window_outflow: None,
+        max_deposit: None,
+    });
+
+    // amount_out = 0.997 * 20_000 * 1 / 2 = 9_970
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // amount_out = 0.997 * 30_000 * 1 / 2 = 14_955, liquidity = 100_000 - 9_970
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(30_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn token_caps_window_outflow() {
+    let (mut prj, token, token2) = token_caps_init(TokenCaps {
+        max_swap_out_ratio: None,
+        max_window_outflow: Some(Uint128::from(15_000u128)),
+        max_deposit: None,
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // inflow of token2 offsets outflow
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(5_000u128),
+        &token2,
+        &token,
+        None,
+    )
+    .unwrap();
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(10_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // outflow is not limited after window is passed
+    prj.wait(WINDOW as u64 + 1);
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Swap amount exceeds max token outflow 15000 per window!")]
+fn token_caps_window_outflow_exceeded() {
+    let (mut prj, token, token2) = token_caps_init(TokenCaps {
+        max_swap_out_ratio: None,
+        max_window_outflow: Some(Uint128::from(15_000u128)),
+        max_deposit: None,
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Deposit amount exceeds max token total deposit 150000!")]
+fn token_caps_deposit() {
+    let (mut prj, _token, token2) = token_caps_init(TokenCaps {
+        max_swap_out_ratio: None,
+        max_window_outflow: None,
+        max_deposit: Some(Uint128::from(150_000u128)),
+    });
+
+    prj.deposit(ADDR_BOB_INJ, &token2, Uint128::from(50_000u128))
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token2, Uint128::from(1u128))
+        .unwrap();
+}
+
+#[test]
+fn token_caps_deposit_compound() {
+    let token_caps = TokenCaps {
+        max_swap_out_ratio: None,
+        max_window_outflow: None,
+        max_deposit: Some(Uint128::from(100_010u128)),
+    };
+    let (mut prj, token, token2) = token_caps_init(token_caps.clone());
+
+    prj.deposit(ADDR_BOB_INJ, &token, Uint128::from(100_000u128))
+        .unwrap();
+    prj.set_auto_compound(ADDR_ALICE_INJ, true).unwrap();
+
+    // 30 of rewards are auto-compounded up to deposit cap, excess is kept
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(20_000u128),
+        &token2,
+        &token,
+        None,
+    )
+    .unwrap();
+
+    let asset = prj.query_provider(ADDR_ALICE_INJ).unwrap()[0].clone();
+    assert_eq!(asset.bonded.u128(), 100_010);
+    assert_eq!(asset.rewards.u128(), 20);
+
+    // the same is applied to manual compound
+    prj.update_token_caps(
+        ADDR_ADMIN_INJ,
+        &token2,
+        Some(TokenCaps {
+            max_deposit: Some(Uint128::from(100_015u128)),
+            ..token_caps
+        }),
+    )
+    .unwrap();
+    prj.compound(ADDR_ALICE_INJ, None, None).unwrap();
+
+    let asset = prj.query_provider(ADDR_ALICE_INJ).unwrap()[0].clone();
+    assert_eq!(asset.bonded.u128(), 100_015);
+    assert_eq!(asset.rewards.u128(), 15);
+}
+
+#[test]
+fn circuit_breaker_records_prices() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(CircuitBreaker {
+            is_enabled: true,
+            max_price_deviation: str_to_dec("0.1"),
+        }),
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.deposit(
+        ADDR_ALICE_INJ,
+        &token2,
+        mint_amount.amount / Uint128::from(2u128),
+    )
+    .unwrap();
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.wait(1_000);
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // stable prices don't trip circuit breaker
+    assert_eq!(
+        prj.query_price_averages().unwrap(),
+        vec![(token, u128_to_dec(1u128)), (token2, u128_to_dec(2u128))]
+    );
+    assert_eq!(prj.query_halted_tokens().unwrap(), Vec::<Addr>::new());
+}
+
+#[test]
+fn circuit_breaker_trips_and_settles() {
+    let mut deps = mock_dependencies();
+    let token_addr = Addr::unchecked(TOKEN_ADDR_ATOM);
+    let window = Uint128::from(WINDOW);
+    let circuit_breaker = CircuitBreaker {
+        is_enabled: true,
+        max_price_deviation: str_to_dec("0.1"),
+    };
+    let mut timestamp = Timestamp::from_seconds(1_000_000);
+
+    let mut check = |price: &str| {
+        timestamp = timestamp.plus_seconds(1);
+
+        check_price_deviation(
+            &mut deps.storage,
+            timestamp,
+            window,
+            &circuit_breaker,
+            &token_addr,
+            str_to_dec(price),
+        )
+    };
+
+    assert_eq!(check("10").unwrap(), None);
+    assert_eq!(check("10.5").unwrap(), None);
+
+    // price jump trips circuit breaker
+    let event = check("12").unwrap().unwrap();
+    assert_eq!(event.ty, "ionflux-circuit_breaker");
+
+    // token stays halted while price deviates
+    assert_eq!(
+        check("12").unwrap_err().to_string(),
+        "Token is halted by circuit breaker!"
+    );
+
+    // token is released when price settles
+    assert_eq!(check("10.2").unwrap(), None);
+    assert_eq!(check("10.3").unwrap(), None);
+}
+
+#[test]
+fn circuit_breaker_checks_token_list() {
+    let mut deps = mock_dependencies();
+    let token_addr = Addr::unchecked(TOKEN_ADDR_ATOM);
+    let token_addr2 = Addr::unchecked(TOKEN_ADDR_LUNA);
+    let window = Uint128::from(WINDOW);
+    let circuit_breaker = CircuitBreaker {
+        is_enabled: true,
+        max_price_deviation: str_to_dec("0.1"),
+    };
+    let mut timestamp = Timestamp::from_seconds(1_000_000);
+
+    let mut check = |price: &str, price2: &str| {
+        timestamp = timestamp.plus_seconds(1);
+
+        // duplicated token is checked once
+        check_circuit_breaker(
+            &mut deps.storage,
+            timestamp,
+            window,
+            &circuit_breaker,
+            &[
+                (token_addr.clone(), str_to_dec(price)),
+                (token_addr2.clone(), str_to_dec(price2)),
+                (token_addr.clone(), str_to_dec(price)),
+            ],
+        )
+    };
+
+    assert!(check("10", "5").unwrap().is_empty());
+
+    // single deviated token trips circuit breaker
+    let event_list = check("12", "5").unwrap();
+    assert_eq!(event_list.len(), 1);
+    assert_eq!(event_list[0].ty, "ionflux-circuit_breaker");
+
+    assert_eq!(
+        check("12", "5").unwrap_err().to_string(),
+        "Token is halted by circuit breaker!"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn circuit_breaker_reset_halt_unauthorized() {
+    let (mut prj, token, _) = default_init();
+
+    prj.reset_halt(ADDR_ALICE_INJ, &token).unwrap();
+}
+
+fn rate_limit_init(rate_limit: RateLimit) -> (Project, Addr, Addr) {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone(), mint_amount2.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount2]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(rate_limit),
+        None,
+        None,
+    )
+    .unwrap();
+
+    prj.deposit(
+        ADDR_ALICE_INJ,
+        &token2,
+        mint_amount.amount / Uint128::from(2u128),
+    )
+    .unwrap();
+
+    (prj, token, token2)
+}
+
+#[test]
+fn rate_limit_address_volume() {
+    let (mut prj, token, token2) = rate_limit_init(RateLimit {
+        max_address_volume: Some(Uint128::from(5_000u128)),
+        max_block_volume: None,
+    });
+
+    // swapped value = amount_in * token_in_price = 3_000 * 1
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_swap_allowances(vec![]).unwrap(),
+        vec![SwapAllowance {
+            address: Addr::unchecked(ADDR_BOB_INJ),
+            address_allowance: Some(Uint128::from(2_000u128)),
+            block_allowance: None,
+        }]
+    );
+
+    prj.wait(WINDOW as u64 / 2);
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(2_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_swap_allowances(vec![ADDR_BOB_INJ, ADDR_ALICE_INJ])
+            .unwrap()
+            .iter()
+            .map(|x| x.address_allowance)
+            .collect::<Vec<Option<Uint128>>>(),
+        vec![Some(Uint128::zero()), Some(Uint128::from(5_000u128))]
+    );
+
+    // first swap counter is expired
+    prj.wait(WINDOW as u64 / 2 + 1);
+
+    assert_eq!(
+        prj.query_swap_allowances(vec![ADDR_BOB_INJ]).unwrap()[0].address_allowance,
+        Some(Uint128::from(3_000u128))
+    );
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn rate_limit_address_volume_expired() {
+    let (mut prj, token, token2) = rate_limit_init(RateLimit {
+        max_address_volume: Some(Uint128::from(5_000u128)),
+        max_block_volume: None,
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // volume of address which didn't swap during window is dropped on next swap
+    prj.wait(WINDOW as u64 + 1);
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_swap_allowances(vec![]).unwrap(),
+        vec![SwapAllowance {
+            address: Addr::unchecked(ADDR_ALICE_INJ),
+            address_allowance: Some(Uint128::from(4_000u128)),
+            block_allowance: None,
+        }]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Swap value exceeds max address volume 5000 per window!")]
+fn rate_limit_address_volume_exceeded() {
+    let (mut prj, token, token2) = rate_limit_init(RateLimit {
+        max_address_volume: Some(Uint128::from(5_000u128)),
+        max_block_volume: None,
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.wait(WINDOW as u64 / 2);
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(2_001u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn rate_limit_block_volume() {
+    let (mut prj, token, token2) = rate_limit_init(RateLimit {
+        max_address_volume: None,
+        max_block_volume: Some(Uint128::from(4_000u128)),
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_swap_allowances(vec![ADDR_ALICE_INJ]).unwrap(),
+        vec![SwapAllowance {
+            address: Addr::unchecked(ADDR_ALICE_INJ),
+            address_allowance: None,
+            block_allowance: Some(Uint128::from(1_000u128)),
+        }]
+    );
+
+    // block counter is reset on next block
+    prj.wait(5_000_000_000);
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Swap value exceeds max volume 4000 per block!")]
+fn rate_limit_block_volume_exceeded() {
+    let (mut prj, token, token2) = rate_limit_init(RateLimit {
+        max_address_volume: None,
+        max_block_volume: Some(Uint128::from(4_000u128)),
+    });
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(3_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn query_providers_pagination() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(5u128),
+    };
+
+    let mint_amount2 = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(5u128),
+    };
+
+    let mint_amount3 = Cw20Coin {
+        address: ADDR_ADMIN_INJ.to_string(),
+        amount: Uint128::from(5u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount, mint_amount2, mint_amount3]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+
+    for address in [ADDR_ALICE_INJ, ADDR_BOB_INJ, ADDR_ADMIN_INJ] {
+        prj.deposit(address, &token, Uint128::from(5u128)).unwrap();
+    }
+
+    // providers are sorted by address: admin, alice, bob
+    let page = prj.query_providers_page(vec![], None, Some(2)).unwrap();
+
+    assert_eq!(
+        page.list
+            .iter()
+            .map(|(addr, _)| addr.to_string())
+            .collect::<Vec<String>>(),
+        vec![ADDR_ADMIN_INJ, ADDR_ALICE_INJ]
+    );
+    assert_eq!(page.cursor, Some(Addr::unchecked(ADDR_ALICE_INJ)));
+
+    let page = prj
+        .query_providers_page(vec![], page.cursor.as_ref(), Some(2))
+        .unwrap();
+
+    assert_eq!(
+        page.list,
+        vec![(
+            Addr::unchecked(ADDR_BOB_INJ),
+            prj.query_provider(ADDR_BOB_INJ).unwrap()
+        )]
+    );
+    assert_eq!(page.cursor, None);
+}
+
+#[test]
+fn query_providers_default_limit() {
+    let address_list: Vec<String> = (0..12).map(|i| format!("provider{:02}", i)).collect();
+    let mint_amount_list = address_list
+        .iter()
+        .map(|address| Cw20Coin {
+            address: address.to_string(),
+            amount: Uint128::from(5u128),
+        })
+        .collect();
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, mint_amount_list);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+
+    for address in &address_list {
+        prj.deposit(address, &token, Uint128::from(5u128)).unwrap();
+    }
+
+    // default limit is applied without pagination args
+    let page = prj.query_providers_page(vec![], None, None).unwrap();
+    assert_eq!(page.list.len(), 10);
+
+    let page = prj
+        .query_providers_page(vec![], page.cursor.as_ref(), None)
+        .unwrap();
+    assert_eq!(page.list.len(), 2);
+    assert_eq!(page.cursor, None);
+}
+
+#[test]
+#[should_panic(expected = "Address list can not be combined with pagination!")]
+fn query_providers_address_list_paginated() {
+    let prj = Project::new(None);
+
+    prj.query_providers_page(vec![ADDR_ALICE_INJ], None, Some(10))
+        .unwrap();
+}
+
+#[test]
+fn query_token_default() {
+    let (prj, token, _) = default_init();
+
+    assert_eq!(
+        prj.query_token(&token).unwrap(),
+        prj.query_tokens(vec![token.as_str()]).unwrap()[0].1
+    );
+}
+
+#[test]
+#[should_panic(expected = "not found")]
+fn query_provider_direct_not_found() {
+    let (prj, ..) = default_init();
+
+    prj.query_provider(ADDR_BOB_INJ).unwrap();
+}
+
+#[test]
+fn query_provider_summary_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(1_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, mint_amount.amount)
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, mint_amount.amount)
+        .unwrap();
+    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(400u128), None)
+        .unwrap();
+
+    let counter = prj.query_provider(ADDR_ALICE_INJ).unwrap()[1].counter;
+
+    // token price = 1, token2 price = 2
+    let summary = prj.query_provider_summary(ADDR_ALICE_INJ).unwrap();
+
+    assert_eq!(
+        summary.asset_list[1],
+        AssetSummary {
+            token_addr: token2.clone(),
+            price: u128_to_dec(2u128),
+            bonded: Uint128::from(600u128),
+            bonded_value: u128_to_dec(1_200u128),
+            requested: Uint128::from(400u128),
+            requested_value: u128_to_dec(800u128),
+            unlock_time: Some(counter),
+            unbonded: Uint128::zero(),
+            unbonded_value: Decimal::zero(),
+            rewards: Uint128::zero(),
+            rewards_value: Decimal::zero(),
+            lock: None,
+        }
+    );
+    assert_eq!(summary.total_value, u128_to_dec(3_000u128));
+
+    // matured request is shown as unbonded while raw state isn't updated yet
+    prj.wait(UNBONDING_PERIOD as u64);
+
+    let summary = prj.query_provider_summary(ADDR_ALICE_INJ).unwrap();
+
+    assert_eq!(
+        prj.query_provider(ADDR_ALICE_INJ).unwrap()[1].requested,
+        Uint128::from(400u128)
+    );
+    assert_eq!(summary.asset_list[1].requested, Uint128::zero());
+    assert_eq!(summary.asset_list[1].unlock_time, None);
+    assert_eq!(summary.asset_list[1].unbonded, Uint128::from(400u128));
+    assert_eq!(summary.asset_list[1].unbonded_value, u128_to_dec(800u128));
+    assert_eq!(summary.total_value, u128_to_dec(3_000u128));
+}
+
+#[test]
+fn query_apr_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_bob = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(
+        SYMBOL_ATOM,
+        vec![mint_amount.clone(), mint_amount_bob.clone()],
+    );
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount_bob]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+
+    // no volume - no yield
+    let token_apr_list = prj.query_token_apr().unwrap();
+    assert!(token_apr_list.iter().all(|(_, apr)| apr.is_zero()));
+
+    prj.wait(1_000_000_000);
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.wait(1_000_000_000);
+
+    let token_apr_list = prj.query_token_apr().unwrap();
+    assert!(token_apr_list.iter().all(|(_, apr)| !apr.is_zero()));
+
+    // single asset provider earns apr of the asset
+    let alice_apr = prj.query_provider_apr(ADDR_ALICE_INJ).unwrap();
+    let bob_apr = prj.query_provider_apr(ADDR_BOB_INJ).unwrap();
+    let get_token_apr = |token_addr: &Addr| {
+        token_apr_list
+            .iter()
+            .find(|(addr, _)| addr == token_addr)
+            .map(|(_, apr)| *apr)
+            .unwrap()
+    };
+
+    assert_eq!(
+        alice_apr.asset_apr_list,
+        vec![(token.clone(), get_token_apr(&token))]
+    );
+    assert_eq!(alice_apr.apr, get_token_apr(&token));
+    assert_eq!(bob_apr.apr, get_token_apr(&token2));
+
+    // volume is summed over window, equal swap increases yield
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.wait(1_000_000_000);
+
+    let token_apr_list_next = prj.query_token_apr().unwrap();
+    assert_eq!(
+        token_apr_list_next,
+        token_apr_list
+            .iter()
+            .map(|(addr, apr)| (addr.to_owned(), apr + apr))
+            .collect::<Vec<(Addr, Decimal)>>()
+    );
+}
+
+#[test]
+fn query_pool_stats_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_bob = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(
+        SYMBOL_ATOM,
+        vec![mint_amount.clone(), mint_amount_bob.clone()],
+    );
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone(), mint_amount_bob]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+    prj.unbond(ADDR_BOB_INJ, &token2, Uint128::from(1_000u128), None)
+        .unwrap();
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // token price = 1, token2 price = 2
+    // amount_out = (1_000 - 3) * 1 / 2 = 498
+    let pool_stats = prj.query_pool_stats().unwrap();
+    let token_stats = &pool_stats.token_stats_list[0];
+    let token2_stats = &pool_stats.token_stats_list[1];
+
+    assert_eq!(pool_stats.provider_count, 2);
+
+    // bob has token rewards
+    assert_eq!(token_stats.token_addr, token);
+    assert_eq!(token_stats.provider_count, 2);
+    assert_eq!(token_stats.rewards, Uint128::from(2u128));
+    assert_eq!(token_stats.bonded, Uint128::from(5_000u128));
+    assert_eq!(token_stats.swapped_in_total, Uint128::from(1_000u128));
+    assert_eq!(token_stats.swapped_in_total_value, u128_to_dec(1_000u128));
+    assert_eq!(token_stats.fees_total, Uint128::from(3u128));
+    assert_eq!(token_stats.swapped_out_total, Uint128::zero());
+
+    assert_eq!(token2_stats.token_addr, token2);
+    assert_eq!(token2_stats.provider_count, 1);
+    assert_eq!(token2_stats.requested, Uint128::from(1_000u128));
+    assert_eq!(token2_stats.requested_value, u128_to_dec(2_000u128));
+    assert_eq!(token2_stats.swapped_out_total, Uint128::from(498u128));
+    assert_eq!(token2_stats.swapped_out_total_value, u128_to_dec(996u128));
+
+    // totals are sums of token values
+    let liquidity_list = prj.query_liquidity(vec![]).unwrap();
+
+    assert_eq!(token_stats.liquidity, liquidity_list[0].1);
+    assert_eq!(
+        pool_stats.liquidity_value,
+        token_stats.liquidity_value + token2_stats.liquidity_value
+    );
+    assert_eq!(pool_stats.fees_total_value, u128_to_dec(3u128));
+    assert_eq!(pool_stats.swapped_out_total_value, u128_to_dec(996u128));
+}
+
+#[test]
+fn query_history_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+
+    let timestamp = prj.get_timestamp().nanos();
+    let epoch_first = Timestamp::from_nanos(timestamp - timestamp % EPOCH as u64);
+    let (from, to) = (Timestamp::from_nanos(0), Timestamp::from_nanos(u64::MAX));
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // snapshots are written on first action of next epoch
+    assert_eq!(prj.query_history(&token, from, to).unwrap(), vec![]);
+
+    prj.wait(EPOCH as u64);
+
+    // admin messages don't write snapshots
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+    assert_eq!(prj.query_history(&token, from, to).unwrap(), vec![]);
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(2_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.query_history(&token, from, to).unwrap(),
+        vec![(
+            epoch_first,
+            Snapshot {
+                swapped_in: Uint128::from(1_000u128),
+                swapped_out: Uint128::zero(),
+                fees: Uint128::from(3u128),
+                bonded: Uint128::from(5_000u128),
+                price: Some(u128_to_dec(1u128)),
+                swapped_in_total: Uint128::from(1_000u128),
+                swapped_out_total: Uint128::zero(),
+                fees_total: Uint128::from(3u128),
+            }
+        )]
+    );
+
+    // swap amounts are accumulated during epoch only
+    prj.wait(EPOCH as u64);
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+
+    let history = prj
+        .query_history(&token2, epoch_first, epoch_first.plus_nanos(EPOCH as u64))
+        .unwrap();
+
+    assert_eq!(history.len(), 2);
+    assert_eq!(history[0].1.swapped_out, Uint128::from(498u128));
+    assert_eq!(history[1].0, epoch_first.plus_nanos(EPOCH as u64));
+    assert_eq!(history[1].1.swapped_out, Uint128::from(997u128));
+    assert_eq!(history[1].1.swapped_out_total, Uint128::from(1_495u128));
+    assert_eq!(history[1].1.price, Some(u128_to_dec(2u128)));
+}
+
+#[test]
+fn query_history_epoch_change() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, Uint128::from(50_000u128))
+        .unwrap();
+
+    let swap_and_wait = |prj: &mut Project, period: u64| {
+        prj.swap(
+            ADDR_ALICE_INJ,
+            Uint128::from(1_000u128),
+            &token,
+            &token2,
+            None,
+        )
+        .unwrap();
+        prj.wait(period);
+    };
+
+    let update_epoch = |prj: &mut Project, epoch: u128| {
+        prj.update_config(
+            ADDR_ADMIN_INJ,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Uint128::from(epoch)),
+            None,
+        )
+        .unwrap();
+    };
+
+    // increased and decreased epoch
+    for _ in 0..3 {
+        swap_and_wait(&mut prj, EPOCH as u64);
+    }
+
+    update_epoch(&mut prj, 7 * EPOCH);
+
+    for _ in 0..3 {
+        swap_and_wait(&mut prj, 7 * EPOCH as u64);
+    }
+
+    update_epoch(&mut prj, EPOCH);
+
+    for _ in 0..3 {
+        swap_and_wait(&mut prj, EPOCH as u64);
+    }
+
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+
+    let history = prj
+        .query_history(
+            &token,
+            Timestamp::from_nanos(0),
+            Timestamp::from_nanos(u64::MAX),
+        )
+        .unwrap();
+
+    // periods don't overlap and every swap is counted once
+    assert!(history.windows(2).all(|x| x[0].0 < x[1].0));
+    assert_eq!(
+        history
+            .iter()
+            .fold(Uint128::zero(), |acc, (_, x)| acc + x.swapped_in),
+        Uint128::from(9_000u128)
+    );
+    assert_eq!(
+        history.last().unwrap().1.swapped_in_total,
+        Uint128::from(9_000u128)
+    );
+}
+
+#[test]
+#[should_panic(expected = "Epoch is out of range!")]
+fn update_config_zero_epoch() {
+    let mut prj = Project::new(None);
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Uint128::zero()),
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn events_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+
+    // returns attribute value of event with specified type
+    let get_attr = |res: &AppResponse, ty: &str, key: &str| -> String {
+        res.events
+            .iter()
+            .find(|x| x.ty == ty)
+            .and_then(|x| x.attributes.iter().find(|y| y.key == key))
+            .map(|x| x.value.clone())
+            .unwrap()
+    };
+
+    let res = prj
+        .update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    assert_eq!(
+        get_attr(&res, "wasm-ionflux-update_token", "is_new"),
+        "true"
+    );
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    let res = prj
+        .deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    assert_eq!(get_attr(&res, "wasm-ionflux-deposit", "bonded"), "5000");
+    prj.deposit(ADDR_ALICE_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+
+    let res = prj
+        .unbond(ADDR_ALICE_INJ, &token2, Uint128::from(1_000u128), None)
+        .unwrap();
+    assert_eq!(get_attr(&res, "wasm-ionflux-unbond", "bonded"), "4000");
+    assert_eq!(get_attr(&res, "wasm-ionflux-unbond", "requested"), "1000");
+    assert_eq!(
+        get_attr(&res, "wasm-ionflux-unbond", "unlock_time"),
+        prj.get_timestamp()
+            .plus_nanos(UNBONDING_PERIOD as u64)
+            .nanos()
+            .to_string()
+    );
+
+    // token price = 1, token2 price = 2
+    let res = prj
+        .swap(
+            ADDR_ALICE_INJ,
+            Uint128::from(1_000u128),
+            &token,
+            &token2,
+            None,
+        )
+        .unwrap();
+    assert_eq!(get_attr(&res, "wasm-ionflux-swap", "amount_in"), "1000");
+    assert_eq!(get_attr(&res, "wasm-ionflux-swap", "fee"), "3");
+    assert_eq!(get_attr(&res, "wasm-ionflux-swap", "amount_out"), "498");
+    assert_eq!(get_attr(&res, "wasm-ionflux-swap", "token_out_price"), "2");
+    assert_eq!(
+        get_attr(&res, "wasm-ionflux-rewards_distributed", "provider_count"),
+        "1"
+    );
+    assert_eq!(
+        get_attr(&res, "wasm-ionflux-rewards_distributed", "rewards"),
+        "2"
+    );
+
+    let res = prj
+        .update_config(
+            ADDR_ADMIN_INJ,
+            None,
+            Some(str_to_dec("0.005")),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+    assert!(get_attr(&res, "wasm-ionflux-update_config", "config_old")
+        .contains("\"swap_fee_rate\":\"0.003\""));
+    assert!(get_attr(&res, "wasm-ionflux-update_config", "config_new")
+        .contains("\"swap_fee_rate\":\"0.005\""));
+
+    // snapshot events are added to first action of new epoch
+    prj.wait(EPOCH as u64);
+    let res = prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+    assert_eq!(get_attr(&res, "wasm-ionflux-claim", "amount"), "2");
+    assert_eq!(get_attr(&res, "wasm-ionflux-claim", "rewards"), "0");
+    assert_eq!(
+        res.events
+            .iter()
+            .filter(|x| x.ty == "wasm-ionflux-snapshot")
+            .count(),
+        2
+    );
+}
+
+#[test]
+fn hooks_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+    let swap_hook = prj.create_hook_contract(false);
+    let liquidity_hook = prj.create_hook_contract(false);
+    let failing_hook = prj.create_hook_contract(true);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    for (contract_addr, on_swap) in [(&swap_hook, true), (&liquidity_hook, false)] {
+        prj.update_hook(
+            ADDR_ADMIN_INJ,
+            contract_addr,
+            Some(Hook {
+                on_swap,
+                on_liquidity: !on_swap,
+                is_required: true,
+            }),
+        )
+        .unwrap();
+    }
+
+    // best-effort notification failure doesn't revert action
+    prj.update_hook(
+        ADDR_ADMIN_INJ,
+        &failing_hook,
+        Some(Hook {
+            on_swap: true,
+            on_liquidity: true,
+            is_required: false,
+        }),
+    )
+    .unwrap();
+
+    assert_eq!(prj.query_hooks().unwrap().len(), 3);
+
+    let res = prj
+        .deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    assert!(res
+        .events
+        .iter()
+        .any(|x| x.ty == "wasm-ionflux-hook_failed"));
+
+    prj.deposit(ADDR_ALICE_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.unbond(ADDR_ALICE_INJ, &token2, Uint128::from(1_000u128), None)
+        .unwrap();
+
+    assert_eq!(
+        prj.query_hook_msgs(&swap_hook).unwrap(),
+        vec![IonFluxHookMsg::Swap {
+            user: Addr::unchecked(ADDR_ALICE_INJ),
+            token_in_addr: token.clone(),
+            token_out_addr: token2.clone(),
+            amount_in: Uint128::from(1_000u128),
+            fee: Uint128::from(3u128),
+            amount_out: Uint128::from(498u128),
+        }]
+    );
+
+    let liquidity_hook_msgs = prj.query_hook_msgs(&liquidity_hook).unwrap();
+    assert_eq!(liquidity_hook_msgs.len(), 3);
+
+    match &liquidity_hook_msgs[2] {
+        IonFluxHookMsg::LiquidityChanged {
+            action,
+            provider,
+            amount,
+            asset,
+        } => {
+            assert_eq!(action, &LiquidityAction::Unbond);
+            assert_eq!(provider, &Addr::unchecked(ADDR_ALICE_INJ));
+            assert_eq!(amount, &Uint128::from(1_000u128));
+            assert_eq!(asset.bonded, Uint128::from(4_000u128));
+            assert_eq!(asset.requested, Uint128::from(1_000u128));
+        }
+        _ => panic!("unexpected hook message"),
+    }
+
+    assert_eq!(prj.query_hook_msgs(&failing_hook).unwrap(), vec![]);
+
+    // removed hook isn't notified
+    prj.update_hook(ADDR_ADMIN_INJ, &swap_hook, None).unwrap();
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(prj.query_hook_msgs(&swap_hook).unwrap().len(), 1);
+    assert_eq!(prj.query_hooks().unwrap().len(), 2);
+}
+
+#[test]
+fn hooks_compound_and_transfer() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount.clone()]);
+    let liquidity_hook = prj.create_hook_contract(false);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+    prj.update_hook(
+        ADDR_ADMIN_INJ,
+        &liquidity_hook,
+        Some(Hook {
+            on_swap: false,
+            on_liquidity: true,
+            is_required: true,
+        }),
+    )
+    .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token2, Uint128::from(5_000u128))
+        .unwrap();
+
+    // auto-compounded swap rewards
+    prj.set_auto_compound(ADDR_ALICE_INJ, true).unwrap();
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    // manually compounded swap rewards
+    prj.set_auto_compound(ADDR_ALICE_INJ, false).unwrap();
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.compound(ADDR_ALICE_INJ, None, None).unwrap();
+
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+
+    let msg_list: Vec<(LiquidityAction, Addr, Uint128, Uint128)> = prj
+        .query_hook_msgs(&liquidity_hook)
+        .unwrap()
+        .into_iter()
+        .map(|msg| match msg {
+            IonFluxHookMsg::LiquidityChanged {
+                action,
+                provider,
+                amount,
+                asset,
+            } => (action, provider, amount, asset.bonded),
+            _ => panic!("unexpected hook message"),
+        })
+        .collect();
+
+    let alice = Addr::unchecked(ADDR_ALICE_INJ);
+    let bob = Addr::unchecked(ADDR_BOB_INJ);
+    let amount = |x: u128| Uint128::from(x);
+
+    // deposits are followed by compounded rewards of both swaps and moved positions
+    assert_eq!(
+        msg_list[2..].to_vec(),
+        vec![
+            (
+                LiquidityAction::Compound,
+                alice.clone(),
+                amount(3),
+                amount(5_003)
+            ),
+            (
+                LiquidityAction::Compound,
+                alice.clone(),
+                amount(2),
+                amount(5_005)
+            ),
+            (
+                LiquidityAction::TransferOut,
+                alice.clone(),
+                amount(5_005),
+                amount(0)
+            ),
+            (
+                LiquidityAction::TransferIn,
+                bob.clone(),
+                amount(5_005),
+                amount(5_005)
+            ),
+            (
+                LiquidityAction::TransferOut,
+                alice,
+                amount(5_000),
+                amount(0)
+            ),
+            (
+                LiquidityAction::TransferIn,
+                bob,
+                amount(5_000),
+                amount(5_000)
+            ),
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "hook is failed")]
+fn hooks_required_failed() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount.clone()]);
+    let failing_hook = prj.create_hook_contract(true);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_hook(
+        ADDR_ADMIN_INJ,
+        &failing_hook,
+        Some(Hook {
+            on_swap: false,
+            on_liquidity: true,
+            is_required: true,
+        }),
+    )
+    .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn update_hook_unauthorized() {
+    let mut prj = Project::new(None);
+
+    let hook = prj.create_hook_contract(false);
+
+    prj.update_hook(
+        ADDR_ALICE_INJ,
+        &hook,
+        Some(Hook {
+            on_swap: true,
+            on_liquidity: true,
+            is_required: false,
+        }),
+    )
+    .unwrap();
+}
+
+#[test]
+fn incentives_default() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_bob = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_admin = Cw20Coin {
+        address: ADDR_ADMIN_INJ.to_string(),
+        amount: Uint128::from(4_000u128),
+    };
+    let period = 1_000_000_000_000u64;
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount, mint_amount_bob]);
+    let reward_token = prj.create_cw20("INCT", vec![mint_amount_admin]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(3_000u128))
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+
+    let contract_addr = prj.address.clone();
+    prj.increase_allowance(
+        ADDR_ADMIN_INJ,
+        &reward_token,
+        &contract_addr,
+        Uint128::from(4_000u128),
+    )
+    .unwrap();
+
+    let start = prj.get_timestamp();
+    prj.create_incentive(
+        ADDR_ADMIN_INJ,
+        &token,
+        RewardAsset {
+            token: RewardToken::Cw20(reward_token.clone()),
+            amount: Uint128::from(4_000u128),
+        },
+        start,
+        start.plus_nanos(period),
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(
+        prj.get_cw20_balance(&reward_token, &contract_addr).u128(),
+        4_000
+    );
+    assert_eq!(prj.query_incentives(&token).unwrap().len(), 1);
+
+    // rewards are streamed proportionally to bonded amounts
+    prj.wait(period / 2);
+
+    let reward_list = prj.query_incentive_rewards(ADDR_ALICE_INJ, &token).unwrap();
+    assert_eq!(reward_list[0].1.amount.u128(), 1_500);
+
+    let reward_list = prj.query_incentive_rewards(ADDR_BOB_INJ, &token).unwrap();
+    assert_eq!(reward_list[0].1.amount.u128(), 500);
+
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+    assert_eq!(
+        prj.get_cw20_balance(&reward_token, ADDR_ALICE_INJ).u128(),
+        1_500
+    );
+    assert_eq!(
+        prj.query_incentive_rewards(ADDR_ALICE_INJ, &token).unwrap(),
+        vec![]
+    );
+
+    // bob's share grows after his deposit
+    prj.deposit(ADDR_BOB_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+    prj.wait(period);
+
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+    prj.claim(ADDR_BOB_INJ, None, None, None).unwrap();
+    assert_eq!(
+        prj.get_cw20_balance(&reward_token, ADDR_ALICE_INJ).u128(),
+        2_700
+    );
+    assert_eq!(
+        prj.get_cw20_balance(&reward_token, ADDR_BOB_INJ).u128(),
+        1_300
+    );
+
+    // everything is streamed to bonded liquidity, nothing to refund
+    prj.close_incentive(ADDR_BOB_INJ, &token, 1).unwrap();
+    assert_eq!(prj.query_incentives(&token).unwrap(), vec![]);
+    assert_eq!(
+        prj.get_cw20_balance(&reward_token, ADDR_ADMIN_INJ).u128(),
+        0
+    );
+}
+
+#[test]
+fn incentives_native() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let denom = "inj";
+    let period = 1_000_000_000_000u64;
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.mint_native(ADDR_ADMIN_INJ, denom, Uint128::from(1_000u128));
+
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+
+    let start = prj.get_timestamp();
+    prj.create_incentive(
+        ADDR_ADMIN_INJ,
+        &token,
+        RewardAsset {
+            token: RewardToken::Native(denom.to_string()),
+            amount: Uint128::from(1_000u128),
+        },
+        start,
+        start.plus_nanos(period),
+        &cosmwasm_std::coins(1_000, denom),
+    )
+    .unwrap();
+
+    // position is transferred with pending incentive rewards
+    prj.wait(period / 2);
+    prj.transfer_position(ADDR_ALICE_INJ, ADDR_BOB_INJ).unwrap();
+    prj.wait(period / 2);
+
+    prj.claim(ADDR_BOB_INJ, None, None, None).unwrap();
+    assert_eq!(prj.get_native_balance(ADDR_BOB_INJ, denom).u128(), 1_000);
+    assert_eq!(prj.get_native_balance(ADDR_ALICE_INJ, denom).u128(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Incentive period is out of range!")]
+fn create_incentive_wrong_period() {
+    let (mut prj, token, _) = default_init();
+
+    let start = prj.get_timestamp();
+    prj.create_incentive(
+        ADDR_ADMIN_INJ,
+        &token,
+        RewardAsset {
+            token: RewardToken::Native("inj".to_string()),
+            amount: Uint128::from(1_000u128),
+        },
+        start,
+        start,
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn close_incentive_refund() {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let denom = "inj";
+    let period = 1_000_000_000_000u64;
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.mint_native(ADDR_ADMIN_INJ, denom, Uint128::from(1_000u128));
+
+    let start = prj.get_timestamp();
+    prj.create_incentive(
+        ADDR_ADMIN_INJ,
+        &token,
+        RewardAsset {
+            token: RewardToken::Native(denom.to_string()),
+            amount: Uint128::from(1_000u128),
+        },
+        start,
+        start.plus_nanos(period),
+        &cosmwasm_std::coins(1_000, denom),
+    )
+    .unwrap();
+
+    // nothing is streamed before first deposit
+    prj.wait(period / 2);
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+    prj.wait(period);
+
+    // anyone can close finished incentive, rewards of providers are kept
+    prj.close_incentive(ADDR_BOB_INJ, &token, 1).unwrap();
+    assert_eq!(prj.get_native_balance(ADDR_ADMIN_INJ, denom).u128(), 500);
+    assert_eq!(prj.query_incentives(&token).unwrap(), vec![]);
+
+    // final rewards are settled before bonded amount is changed
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+
+    prj.claim(ADDR_ALICE_INJ, None, None, None).unwrap();
+    assert_eq!(prj.get_native_balance(ADDR_ALICE_INJ, denom).u128(), 500);
+    assert_eq!(
+        prj.query_incentive_rewards(ADDR_ALICE_INJ, &token).unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Incentive is not finished yet!")]
+fn close_incentive_active() {
+    let (mut prj, token, _) = default_init();
+    prj.mint_native(ADDR_ADMIN_INJ, "inj", Uint128::from(1_000u128));
+
+    let start = prj.get_timestamp();
+    prj.create_incentive(
+        ADDR_ADMIN_INJ,
+        &token,
+        RewardAsset {
+            token: RewardToken::Native("inj".to_string()),
+            amount: Uint128::from(1_000u128),
+        },
+        start,
+        start.plus_nanos(1_000),
+        &cosmwasm_std::coins(1_000, "inj"),
+    )
+    .unwrap();
+
+    prj.close_incentive(ADDR_ADMIN_INJ, &token, 1).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Incentives limit of target token is reached!")]
+fn create_incentive_limit_is_reached() {
+    let (mut prj, token, _) = default_init();
+    prj.mint_native(ADDR_BOB_INJ, "inj", Uint128::from(11u128));
+    prj.mint_native(ADDR_ADMIN_INJ, "inj", Uint128::from(1u128));
+
+    let start = prj.get_timestamp();
+    let mut create = |sender: &str| {
+        prj.create_incentive(
+            sender,
+            &token,
+            RewardAsset {
+                token: RewardToken::Native("inj".to_string()),
+                amount: Uint128::one(),
+            },
+            start,
+            start.plus_nanos(1_000),
+            &cosmwasm_std::coins(1, "inj"),
+        )
+    };
+
+    // anyone can fund incentives up to the limit, admin can exceed it
+    for _ in 0..10 {
+        create(ADDR_BOB_INJ).unwrap();
+    }
+    create(ADDR_ADMIN_INJ).unwrap();
+
+    create(ADDR_BOB_INJ).unwrap();
+}
+
+fn lock_boosts_init() -> (Project, Addr) {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(5u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+
+    (prj, token)
+}
+
+#[test]
+fn lock_boosts_default() {
+    let week = 7 * 24 * 3_600 * 1_000_000_000u64;
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_bob = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(200_000u128),
+    };
+    let mint_amount_admin = Cw20Coin {
+        address: ADDR_ADMIN_INJ.to_string(),
+        amount: Uint128::from(100_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount, mint_amount_bob]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount_admin]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    let lock_boost_list = vec![LockBoost {
+        duration: Uint128::from(week as u128),
+        multiplier: u128_to_dec(2u128),
+    }];
+    prj.update_lock_boosts(ADDR_ADMIN_INJ, lock_boost_list.clone())
+        .unwrap();
+    assert_eq!(prj.query_lock_boosts().unwrap(), lock_boost_list);
+
+    // alice's locked deposit has double allocation weight compared to bob's one
+    prj.deposit_locked(ADDR_ALICE_INJ, &token, Uint128::from(1_000u128), Some(week))
+        .unwrap();
+    prj.deposit(ADDR_BOB_INJ, &token, Uint128::from(1_000u128))
+        .unwrap();
+    prj.deposit(ADDR_ADMIN_INJ, &token2, Uint128::from(100_000u128))
+        .unwrap();
+
+    let lock = Lock {
+        amount: Uint128::from(1_000u128),
+        end: prj.get_timestamp().plus_nanos(week),
+        multiplier: u128_to_dec(2u128),
+    };
+    assert_eq!(
+        prj.query_provider(ADDR_ALICE_INJ).unwrap()[0].lock,
+        Some(lock.clone())
+    );
+    assert_eq!(
+        prj.query_provider_summary(ADDR_ALICE_INJ)
+            .unwrap()
+            .asset_list[0]
+            .lock,
+        Some(lock)
+    );
+
+    prj.swap(
+        ADDR_BOB_INJ,
+        Uint128::from(100_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+
+    let alice_rewards = prj.query_provider(ADDR_ALICE_INJ).unwrap()[0].rewards;
+    let bob_rewards = prj.query_provider(ADDR_BOB_INJ).unwrap()[0].rewards;
+    // 2/3 and 1/3 of providers fee for token instead of equal shares
+    assert_eq!(alice_rewards.u128(), 99);
+    assert_eq!(bob_rewards.u128(), 49);
+
+    // unlocked amount is unbonded after lock expiration
+    prj.wait(week);
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(1_000u128), None)
+        .unwrap();
+    assert_eq!(prj.query_provider(ADDR_ALICE_INJ).unwrap()[0].lock, None);
+}
+
+#[test]
+#[should_panic(expected = "Bonded amount is locked until")]
+fn unbond_locked() {
+    let week = 7 * 24 * 3_600 * 1_000_000_000u64;
+    let (mut prj, token) = lock_boosts_init();
+
+    prj.update_lock_boosts(
+        ADDR_ADMIN_INJ,
+        vec![LockBoost {
+            duration: Uint128::from(week as u128),
+            multiplier: u128_to_dec(2u128),
+        }],
+    )
+    .unwrap();
+
+    prj.deposit_locked(ADDR_ALICE_INJ, &token, Uint128::from(3u128), Some(week))
+        .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(2u128))
+        .unwrap();
+
+    // only unlocked part can be unbonded
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(2u128), None)
+        .unwrap();
+    prj.unbond(ADDR_ALICE_INJ, &token, Uint128::from(1u128), None)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Lock duration is not supported!")]
+fn deposit_locked_unsupported_duration() {
+    let (mut prj, token) = lock_boosts_init();
+
+    prj.deposit_locked(ADDR_ALICE_INJ, &token, Uint128::from(5u128), Some(1_000))
+        .unwrap();
+}
+
+fn limit_orders_init() -> (Project, Addr, Addr) {
+    let mint_amount = Cw20Coin {
+        address: ADDR_ALICE_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+    let mint_amount_bob = Cw20Coin {
+        address: ADDR_BOB_INJ.to_string(),
+        amount: Uint128::from(10_000u128),
+    };
+
+    let mut prj = Project::new(None);
+
+    let token = prj.create_cw20(SYMBOL_ATOM, vec![mint_amount]);
+    let token2 = prj.create_cw20(SYMBOL_LUNA, vec![mint_amount_bob]);
+
+    prj.update_token(ADDR_ADMIN_INJ, &token, SYMBOL_ATOM, PRICE_FEED_ID_STR_ATOM)
+        .unwrap();
+    prj.update_token(ADDR_ADMIN_INJ, &token2, SYMBOL_LUNA, PRICE_FEED_ID_STR_LUNA)
+        .unwrap();
+
+    prj.deposit(ADDR_BOB_INJ, &token2, Uint128::from(10_000u128))
+        .unwrap();
+
+    (prj, token, token2)
+}
+
+#[test]
+fn limit_orders_default() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    // mocked token price in token2 units is 0.5
+    for min_price in ["0.4", "0.6"] {
+        prj.place_limit_order(
+            ADDR_ALICE_INJ,
+            Uint128::from(1_000u128),
+            &token,
+            &token2,
+            str_to_dec(min_price),
+            expiry,
+        )
+        .unwrap();
+    }
+
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_ALICE_INJ)
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_BOB_INJ).unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        prj.query_limit_orders_by_pair(&token, &token2)
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        prj.query_limit_orders_by_pair(&token2, &token).unwrap(),
+        vec![]
+    );
+
+    // escrowed funds aren't liquidity
+    assert_eq!(
+        prj.query_liquidity(vec![token.as_str()]).unwrap(),
+        vec![(token.clone(), Uint128::zero())]
+    );
+
+    // only the first order is filled, keeper gets 0.1% of amount_in
+    prj.execute_orders(ADDR_ADMIN_INJ, vec![1, 2]).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 1);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 498);
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_ALICE_INJ).unwrap()[0].0,
+        2
+    );
+
+    prj.cancel_order(ADDR_ALICE_INJ, 2).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 9_000);
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_ALICE_INJ).unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+fn limit_orders_failed_is_skipped() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(RateLimit {
+            max_address_volume: Some(Uint128::from(1_500u128)),
+            max_block_volume: None,
+        }),
+        None,
+        None,
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        prj.place_limit_order(
+            ADDR_ALICE_INJ,
+            Uint128::from(1_000u128),
+            &token,
+            &token2,
+            str_to_dec("0.4"),
+            expiry,
+        )
+        .unwrap();
+    }
+
+    // the second order exceeds owner volume, it's skipped and stays active
+    let res = prj.execute_orders(ADDR_ADMIN_INJ, vec![1, 2]).unwrap();
+
+    assert!(res.events.iter().any(|x| x.ty == "wasm-ionflux-skip_order"
+        && x.attributes.iter().any(|y| y.key == "id" && y.value == "2")));
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 1);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 498);
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_ALICE_INJ)
+            .unwrap()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<u64>>(),
+        vec![2]
+    );
+
+    prj.cancel_order(ADDR_ALICE_INJ, 2).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 9_000);
+}
+
+#[test]
+fn limit_orders_pagination() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    for _ in 0..3 {
+        prj.place_limit_order(
+            ADDR_ALICE_INJ,
+            Uint128::from(1_000u128),
+            &token,
+            &token2,
+            str_to_dec("0.6"),
+            expiry,
+        )
+        .unwrap();
+    }
+
+    let page = prj
+        .query_limit_orders_by_owner_page(ADDR_ALICE_INJ, None, Some(2))
+        .unwrap();
+
+    assert_eq!(
+        page.list.iter().map(|(id, _)| *id).collect::<Vec<u64>>(),
+        vec![1, 2]
+    );
+    assert_eq!(page.cursor, Some(2));
+
+    let page = prj
+        .query_limit_orders_by_owner_page(ADDR_ALICE_INJ, page.cursor, Some(2))
+        .unwrap();
+
+    assert_eq!(
+        page.list.iter().map(|(id, _)| *id).collect::<Vec<u64>>(),
+        vec![3]
+    );
+    assert_eq!(page.cursor, None);
+
+    // index entries are removed with the order
+    prj.cancel_order(ADDR_ALICE_INJ, 1).unwrap();
+
+    assert_eq!(
+        prj.query_limit_orders_by_pair(&token, &token2)
+            .unwrap()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<u64>>(),
+        vec![2, 3]
+    );
+}
+
+#[test]
+fn limit_orders_expired() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    prj.place_limit_order(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        str_to_dec("0.6"),
+        expiry,
+    )
+    .unwrap();
+
+    // expired order is refunded without keeper tip
+    prj.wait(3_600 * 1_000_000_000);
+    prj.execute_orders(ADDR_ADMIN_INJ, vec![1]).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 10_000);
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 0);
+    assert_eq!(
+        prj.query_limit_orders_by_owner(ADDR_ALICE_INJ).unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+#[should_panic(expected = "There are no orders to execute!")]
+fn limit_orders_price_condition() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    prj.place_limit_order(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        str_to_dec("0.6"),
+        expiry,
+    )
+    .unwrap();
+
+    prj.execute_orders(ADDR_ADMIN_INJ, vec![1]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn cancel_order_unauthorized() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let expiry = prj.get_timestamp().plus_seconds(3_600);
+
+    prj.place_limit_order(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        str_to_dec("0.6"),
+        expiry,
+    )
+    .unwrap();
+
+    prj.cancel_order(ADDR_BOB_INJ, 1).unwrap();
+}
+
+#[test]
+fn dca_default() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let interval: u64 = 3_600 * 1_000_000_000;
+
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(2_500u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        interval,
+        str_to_dec("0.01"),
+    )
+    .unwrap();
+
+    // escrowed funds aren't liquidity
+    assert_eq!(
+        prj.query_liquidity(vec![token.as_str()]).unwrap(),
+        vec![(token.clone(), Uint128::zero())]
+    );
+
+    // first swap is due immediately, keeper gets 0.1% of swapped amount
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 1);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 498);
+
+    prj.wait(interval);
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 2);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 996);
+
+    let (id, schedule) = prj.query_dca_schedules(Some(ADDR_ALICE_INJ)).unwrap()[0].clone();
+    assert_eq!(id, 1);
+    assert_eq!(schedule.balance, Uint128::from(500u128));
+    assert_eq!(schedule.executed_count, 2);
+    assert_eq!(schedule.amount_in_total, Uint128::from(2_000u128));
+    assert_eq!(schedule.amount_out_total, Uint128::from(996u128));
+    assert_eq!(prj.query_dca_schedules(Some(ADDR_BOB_INJ)).unwrap(), vec![]);
+
+    // remaining balance is refunded
+    prj.cancel_dca(ADDR_ALICE_INJ, 1).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 8_000);
+    assert_eq!(prj.query_dca_schedules(None).unwrap(), vec![]);
+}
+
+#[test]
+fn dca_completed() {
+    let (mut prj, token, token2) = limit_orders_init();
+    let interval: u64 = 3_600 * 1_000_000_000;
+
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_500u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        interval,
+        str_to_dec("0.01"),
+    )
+    .unwrap();
+
+    // last swap spends the rest of balance and removes schedule
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+    prj.wait(interval);
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 8_500);
+    assert_eq!(prj.query_dca_schedules(None).unwrap(), vec![]);
+    assert_eq!(
+        prj.query_liquidity(vec![token.as_str()]).unwrap(),
+        vec![(token.clone(), Uint128::from(1_498u128))]
+    );
+}
+
+#[test]
+#[should_panic(expected = "There are no orders to execute!")]
+fn dca_not_due() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(2_000u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        3_600 * 1_000_000_000,
+        str_to_dec("0.01"),
+    )
+    .unwrap();
+
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "There are no orders to execute!")]
+fn dca_slippage() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    // swap fee rate 0.3% exceeds slippage
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        3_600 * 1_000_000_000,
+        str_to_dec("0.001"),
+    )
+    .unwrap();
+
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "There are no orders to execute!")]
+fn dca_slippage_keeper_tip() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    // swap fee rate 0.3% is within slippage, but 0.1% keeper tip exceeds it
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        3_600 * 1_000_000_000,
+        str_to_dec("0.0035"),
+    )
+    .unwrap();
+
+    prj.execute_dca(ADDR_ADMIN_INJ, vec![1]).unwrap();
+}
+
+#[test]
+fn dca_failed_is_skipped() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    prj.update_config(
+        ADDR_ADMIN_INJ,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(RateLimit {
+            max_address_volume: Some(Uint128::from(1_500u128)),
+            max_block_volume: None,
+        }),
+        None,
+        None,
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        prj.create_dca(
+            ADDR_ALICE_INJ,
+            Uint128::from(2_000u128),
+            &token,
+            &token2,
+            Uint128::from(1_000u128),
+            3_600 * 1_000_000_000,
+            str_to_dec("0.01"),
+        )
+        .unwrap();
+    }
+
+    // the second run exceeds owner volume, it's skipped and schedule is untouched
+    let res = prj.execute_dca(ADDR_ADMIN_INJ, vec![1, 2]).unwrap();
+
+    assert!(res.events.iter().any(|x| x.ty == "wasm-ionflux-skip_dca"
+        && x.attributes.iter().any(|y| y.key == "id" && y.value == "2")));
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ADMIN_INJ).u128(), 1);
+
+    let schedule_list = prj.query_dca_schedules(Some(ADDR_ALICE_INJ)).unwrap();
+    assert_eq!(schedule_list[0].1.balance.u128(), 1_000);
+    assert_eq!(schedule_list[1].1.balance.u128(), 2_000);
+    assert_eq!(schedule_list[1].1.executed_count, 0);
+
+    prj.cancel_dca(ADDR_ALICE_INJ, 2).unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 8_000);
+}
+
+#[test]
+#[should_panic(expected = "Sender does not have access permissions!")]
+fn cancel_dca_unauthorized() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    prj.create_dca(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        Uint128::from(1_000u128),
+        3_600 * 1_000_000_000,
+        str_to_dec("0.01"),
+    )
+    .unwrap();
+
+    prj.cancel_dca(ADDR_BOB_INJ, 1).unwrap();
+}
+
+fn batch_swap_init() -> (Project, Addr, Addr) {
+    let (mut prj, token, token2) = limit_orders_init();
+    let contract_address = prj.address.clone();
+
+    prj.increase_allowance(
+        ADDR_ALICE_INJ,
+        &token,
+        &contract_address,
+        Uint128::from(2_000u128),
+    )
+    .unwrap();
+
+    (prj, token, token2)
+}
+
+#[test]
+fn batch_swap_default() {
+    let (mut prj, token, token2) = batch_swap_init();
+
+    // mocked token price in token2 units is 0.5
+    let swap_leg = SwapLeg {
+        token_in_addr: token.to_string(),
+        amount_in: Uint128::from(1_000u128),
+        token_out_addr: token2.to_string(),
+        min_amount_out: Uint128::from(498u128),
+    };
+
+    prj.batch_swap(ADDR_ALICE_INJ, vec![swap_leg.clone(), swap_leg])
+        .unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 8_000);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 996);
+    // protocol fees aren't liquidity
+    assert_eq!(
+        prj.query_liquidity(vec![token.as_str()]).unwrap(),
+        vec![(token.clone(), Uint128::from(1_998u128))]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Swap amount out 498 is less than min amount out 499!")]
+fn batch_swap_min_amount_out() {
+    let (mut prj, token, token2) = batch_swap_init();
+
+    prj.batch_swap(
+        ADDR_ALICE_INJ,
+        vec![
+            SwapLeg {
+                token_in_addr: token.to_string(),
+                amount_in: Uint128::from(1_000u128),
+                token_out_addr: token2.to_string(),
+                min_amount_out: Uint128::from(498u128),
+            },
+            SwapLeg {
+                token_in_addr: token.to_string(),
+                amount_in: Uint128::from(1_000u128),
+                token_out_addr: token2.to_string(),
+                min_amount_out: Uint128::from(499u128),
+            },
+        ],
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Allowance of contract1 is less than swap amount in 2500!")]
+fn batch_swap_allowance_is_not_enough() {
+    let (mut prj, token, token2) = batch_swap_init();
+
+    let swap_leg = SwapLeg {
+        token_in_addr: token.to_string(),
+        amount_in: Uint128::from(1_250u128),
+        token_out_addr: token2.to_string(),
+        min_amount_out: Uint128::zero(),
+    };
+
+    prj.batch_swap(ADDR_ALICE_INJ, vec![swap_leg.clone(), swap_leg])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Swap list is empty!")]
+fn batch_swap_empty() {
+    let (mut prj, _, _) = batch_swap_init();
+
+    prj.batch_swap(ADDR_ALICE_INJ, vec![]).unwrap();
+}
+
+#[test]
+fn swap_exact_out_default() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    // 999 * (1 - 0.003) * 0.5 = 498, excess of max amount_in is refunded
+    prj.swap_exact_out(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_100u128),
+        &token,
+        &token2,
+        Uint128::from(498u128),
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 9_001);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 498);
+}
+
+#[test]
+fn swap_exact_out_rounding_excess() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    prj.swap(
+        ADDR_ALICE_INJ,
+        Uint128::from(1_000u128),
+        &token,
+        &token2,
+        None,
+    )
+    .unwrap();
+    prj.deposit(ADDR_ALICE_INJ, &token, Uint128::from(5_000u128))
+        .unwrap();
+
+    // 451 * (1 - 0.003) * 2 = 899, but exactly requested 898 is sent
+    prj.swap_exact_out(
+        ADDR_ALICE_INJ,
+        Uint128::from(498u128),
+        &token2,
+        &token,
+        Uint128::from(898u128),
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(prj.get_cw20_balance(&token, ADDR_ALICE_INJ).u128(), 4_898);
+    assert_eq!(prj.get_cw20_balance(&token2, ADDR_ALICE_INJ).u128(), 47);
+}
+
+#[test]
+#[should_panic(expected = "Swap amount in 999 exceeds max amount in 998!")]
+fn swap_exact_out_max_amount_in() {
+    let (mut prj, token, token2) = limit_orders_init();
+
+    prj.swap_exact_out(
+        ADDR_ALICE_INJ,
+        Uint128::from(998u128),
+        &token,
+        &token2,
+        Uint128::from(498u128),
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn migrate_legacy_layout() {
+    let mut prj = Project::new_legacy("crates.io:ion-flux", "0.1.0");
+
+    prj.migrate(&MigrateMsg {
+        protocol_fee_share: None,
+        treasury: None,
+        max_referral_commission_rate: None,
+        epoch: None,
+        keeper_tip_rate: Some(str_to_dec("0.002")),
+    })
+    .unwrap();
+
+    // missing config fields get defaults
+    let config = prj.query_config().unwrap();
+    assert_eq!(config.admin, Addr::unchecked(ADDR_ADMIN_INJ));
+    assert_eq!(config.swap_fee_rate, str_to_dec(SWAP_FEE_RATE));
+    assert_eq!(config.treasury, Addr::unchecked(ADDR_ADMIN_INJ));
+    assert_eq!(config.epoch, Uint128::from(EPOCH));
+    assert_eq!(config.keeper_tip_rate, str_to_dec("0.002"));
+    assert_eq!(config.get_chain_id(), CHAIN_ID_MOCKED);
+
+    // tokens and assets are loaded with default values of new fields
+    let (_, token) = prj.query_tokens(vec![]).unwrap()[0].clone();
+    assert_eq!(token.symbol, SYMBOL_ATOM);
+    assert_eq!(token.swap_fee_rate, None);
+    assert_eq!(token.fees_total, Uint128::zero());
+
+    let (_, asset_list) = prj.query_providers(vec![ADDR_ALICE_INJ]).unwrap()[0].clone();
+    assert_eq!(asset_list[0].bonded, Uint128::from(1_000u128));
+    assert_eq!(asset_list[0].lock, None);
+
+    let contract_version = prj.query_contract_version().unwrap();
+    assert_eq!(contract_version.contract, "crates.io:ion-flux");
+    assert_eq!(contract_version.version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn migrate_legacy_providers() {
+    let mut prj = Project::new_legacy("crates.io:ion-flux", "0.1.1-rc.1");
+    let token_addr = Addr::unchecked(TOKEN_ADDR_ATOM);
+
+    assert_eq!(prj.query_bonded_total(&token_addr).unwrap(), None);
+
+    prj.migrate(&MigrateMsg {
+        protocol_fee_share: None,
+        treasury: None,
+        max_referral_commission_rate: None,
+        epoch: None,
+        keeper_tip_rate: None,
+    })
+    .unwrap();
+
+    // pre-release of 0.1 layout is transformed, bonded total is derived from providers
+    assert_eq!(
+        prj.query_bonded_total(&token_addr).unwrap(),
+        Some(Uint128::from(1_500u128))
+    );
+    assert_eq!(prj.query_config().unwrap().epoch, Uint128::from(EPOCH));
+
+    let (_, asset_list) = prj.query_providers(vec![ADDR_BOB_INJ]).unwrap()[0].clone();
+    assert_eq!(asset_list[0].bonded, Uint128::from(500u128));
+    assert_eq!(asset_list[0].unbonded, Uint128::from(200u128));
+    assert_eq!(asset_list[0].lock, None);
+
+    let (_, token) = prj.query_tokens(vec![]).unwrap()[0].clone();
+    assert_eq!(token.bonded.1, Uint128::from(1_000u128));
+    assert_eq!(token.swapped_in_total, Uint128::zero());
+}
+
+#[test]
+fn migrate_current_layout() {
+    let mut prj = Project::new(None);
+
+    prj.migrate(&MigrateMsg {
+        protocol_fee_share: Some(str_to_dec("0.2")),
+        treasury: Some(ADDR_BOB_INJ.to_string()),
+        max_referral_commission_rate: None,
+        epoch: None,
+        keeper_tip_rate: None,
+    })
+    .unwrap();
+
+    let config = prj.query_config().unwrap();
+    assert_eq!(config.protocol_fee_share, str_to_dec("0.2"));
+    assert_eq!(config.treasury, Addr::unchecked(ADDR_BOB_INJ));
+    assert_eq!(config.keeper_tip_rate, str_to_dec(KEEPER_TIP_RATE));
+}
+
+#[test]
+#[should_panic(expected = "Contract crates.io:cw20-base can not be migrated!")]
+fn migrate_wrong_contract_name() {
+    let mut prj = Project::new_legacy("crates.io:cw20-base", "0.1.0");
+
+    prj.migrate(&MigrateMsg {
+        protocol_fee_share: None,
+        treasury: None,
+        max_referral_commission_rate: None,
+        epoch: None,
+        keeper_tip_rate: None,
+    })
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "Contract version 99.0.0 is newer than current one!")]
+fn migrate_downgrade() {
+    let mut prj = Project::new_legacy("crates.io:ion-flux", "99.0.0");
+
+    prj.migrate(&MigrateMsg {
+        protocol_fee_share: None,
+        treasury: None,
+        max_referral_commission_rate: None,
+        epoch: None,
+        keeper_tip_rate: None,
+    })
+    .unwrap();
+}